@@ -1,7 +1,7 @@
 use candid::CandidType;
 use serde::Deserialize;
 
-use crate::types::AccessRight;
+use crate::types::{AccessRight, Role, RoleAssignment};
 
 #[derive(CandidType, Deserialize, Debug)]
 pub struct Args {}
@@ -16,4 +16,7 @@ pub enum GetAccessRightsResponse {
 #[derive(CandidType, Deserialize, Debug)]
 pub struct GetAccessRightsResult {
     pub access_rights: Vec<AccessRight>,
+    pub roles: Vec<Role>,
+    /// All the role assignments including the expired ones.
+    pub role_assignments: Vec<RoleAssignment>,
 }
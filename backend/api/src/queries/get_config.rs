@@ -1,7 +1,7 @@
 use candid::CandidType;
 use serde::Deserialize;
 
-use crate::types::Config;
+use crate::types::{Config, ConfigVersion};
 
 #[derive(CandidType, Deserialize, Debug)]
 pub struct Args {}
@@ -16,4 +16,5 @@ pub enum GetConfigResponse {
 #[derive(CandidType, Deserialize, Debug)]
 pub struct GetConfigResult {
     pub config: Config,
+    pub config_version: ConfigVersion,
 }
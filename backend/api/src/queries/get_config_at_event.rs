@@ -0,0 +1,38 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::Config;
+
+pub type Args = GetConfigAtEventArgs;
+pub type Response = GetConfigAtEventResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetConfigAtEventArgs {
+    pub event_id: u64,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetConfigAtEventResponse {
+    Ok(GetConfigAtEventResult),
+    Err(GetConfigAtEventError),
+}
+
+/// The config right after the hub event.
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetConfigAtEventResult {
+    pub config: Config,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetConfigAtEventError {
+    EventNotFound,
+}
+
+impl From<Result<GetConfigAtEventResult, GetConfigAtEventError>> for GetConfigAtEventResponse {
+    fn from(r: Result<GetConfigAtEventResult, GetConfigAtEventError>) -> Self {
+        match r {
+            Ok(result) => GetConfigAtEventResponse::Ok(result),
+            Err(error) => GetConfigAtEventResponse::Err(error),
+        }
+    }
+}
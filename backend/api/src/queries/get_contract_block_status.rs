@@ -2,7 +2,7 @@ use candid::{CandidType, Principal};
 use common_canister_types::Timestamped;
 use serde::Deserialize;
 
-use crate::types::DeploymentId;
+use crate::types::{ContractBlockHistoryEntry, DeploymentId};
 
 pub type Args = GetContractBlockStatusArgs;
 pub type Response = GetContractBlockStatusResponse;
@@ -27,6 +27,7 @@ pub enum GetContractBlockStatusResponse {
 #[derive(CandidType, Deserialize, Debug, PartialEq, Eq)]
 pub struct GetContractBlockStatusResult {
     pub blocked: Option<Timestamped<String>>,
+    pub history: Vec<ContractBlockHistoryEntry>,
 }
 
 #[derive(CandidType, Deserialize, Debug, PartialEq, Eq)]
@@ -0,0 +1,44 @@
+use candid::CandidType;
+use common_contract_api::ContractTemplateId;
+use serde::Deserialize;
+
+use crate::types::{PublisherEarnings, PublisherFee};
+
+pub type Args = GetContractTemplateEarningsArgs;
+pub type Response = GetContractTemplateEarningsResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetContractTemplateEarningsArgs {
+    pub contract_template_id: ContractTemplateId,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetContractTemplateEarningsResponse {
+    Ok(Box<GetContractTemplateEarningsResult>),
+    Err(GetContractTemplateEarningsError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetContractTemplateEarningsResult {
+    pub contract_template_id: ContractTemplateId,
+    pub publisher_fee: Option<PublisherFee>,
+    pub earnings: PublisherEarnings,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetContractTemplateEarningsError {
+    ContractTemplateNotFound,
+}
+
+impl From<Result<GetContractTemplateEarningsResult, GetContractTemplateEarningsError>>
+    for GetContractTemplateEarningsResponse
+{
+    fn from(
+        r: Result<GetContractTemplateEarningsResult, GetContractTemplateEarningsError>,
+    ) -> Self {
+        match r {
+            Ok(result) => GetContractTemplateEarningsResponse::Ok(Box::new(result)),
+            Err(error) => GetContractTemplateEarningsResponse::Err(error),
+        }
+    }
+}
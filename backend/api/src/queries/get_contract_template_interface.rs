@@ -0,0 +1,43 @@
+use candid::CandidType;
+use common_contract_api::ContractTemplateId;
+use serde::Deserialize;
+
+use crate::types::ContractTemplateInterface;
+
+pub type Args = GetContractTemplateInterfaceArgs;
+pub type Response = GetContractTemplateInterfaceResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetContractTemplateInterfaceArgs {
+    pub contract_template_id: ContractTemplateId,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetContractTemplateInterfaceResponse {
+    Ok(Box<GetContractTemplateInterfaceResult>),
+    Err(GetContractTemplateInterfaceError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetContractTemplateInterfaceResult {
+    pub interface: ContractTemplateInterface,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetContractTemplateInterfaceError {
+    ContractTemplateNotFound,
+    ContractTemplateInterfaceNotFound,
+}
+
+impl From<Result<GetContractTemplateInterfaceResult, GetContractTemplateInterfaceError>>
+    for GetContractTemplateInterfaceResponse
+{
+    fn from(
+        r: Result<GetContractTemplateInterfaceResult, GetContractTemplateInterfaceError>,
+    ) -> Self {
+        match r {
+            Ok(result) => GetContractTemplateInterfaceResponse::Ok(Box::new(result)),
+            Err(error) => GetContractTemplateInterfaceResponse::Err(error),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use candid::CandidType;
 use common_canister_types::{ChunkDef, SortingDefinition};
 use serde::Deserialize;
 
-use crate::types::ContractTemplateInformation;
+use crate::types::{ContractTemplateInformation, ContractTemplateReviewStatus};
 
 pub type Args = GetContractTemplatesArgs;
 pub type Response = GetContractTemplatesResponse;
@@ -12,6 +12,9 @@ pub struct ContractTemplatesFilter {
     pub filter: Option<String>,
     pub blocked: Option<bool>,
     pub retired: Option<bool>,
+    pub category: Option<String>,
+    pub tag: Option<String>,
+    pub review_status: Option<ContractTemplateReviewStatus>,
 }
 
 #[derive(CandidType, Deserialize, Debug)]
@@ -26,6 +29,7 @@ pub struct GetContractTemplatesArgs {
     pub chunk_def: ChunkDef,
     pub filter: Option<ContractTemplatesFilter>,
     pub sorting: Option<SortingDefinition<ContractTemplatesSortingKey>>,
+    pub include_interface_summary: Option<bool>,
 }
 
 #[derive(CandidType, Deserialize, Debug)]
@@ -38,6 +42,14 @@ pub enum GetContractTemplatesResponse {
 pub struct GetContractTemplatesResult {
     pub contract_templates: Vec<ContractTemplateInformation>,
     pub total_count: usize,
+    pub facets: ContractTemplatesFacets,
+}
+
+/// Number of the filtered contract templates per category and per tag.
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ContractTemplatesFacets {
+    pub categories: Vec<(String, usize)>,
+    pub tags: Vec<(String, usize)>,
 }
 
 #[derive(CandidType, Deserialize, Debug)]
@@ -0,0 +1,23 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::types::DeployerBan;
+
+pub type Args = GetDeployerBanArgs;
+pub type Response = GetDeployerBanResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetDeployerBanArgs {
+    pub deployer: Principal,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetDeployerBanResponse {
+    Ok(GetDeployerBanResult),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetDeployerBanResult {
+    /// Active ban of the deployer, the expired bans are omitted.
+    pub ban: Option<DeployerBan>,
+}
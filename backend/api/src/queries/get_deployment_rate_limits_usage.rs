@@ -0,0 +1,56 @@
+use candid::{CandidType, Principal};
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use serde::Deserialize;
+
+use crate::types::DeploymentRateLimit;
+
+pub type Args = GetDeploymentRateLimitsUsageArgs;
+pub type Response = GetDeploymentRateLimitsUsageResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetDeploymentRateLimitsUsageArgs {
+    pub deployer: Principal,
+    pub contract_template_id: Option<ContractTemplateId>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetDeploymentRateLimitsUsageResponse {
+    Ok(GetDeploymentRateLimitsUsageResult),
+    Err(GetDeploymentRateLimitsUsageError),
+}
+
+/// Usage of the configured limits, the usage is absent for the limits not configured.
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetDeploymentRateLimitsUsageResult {
+    pub per_deployer: Option<DeploymentRateLimitUsage>,
+    pub per_contract_template: Option<DeploymentRateLimitUsage>,
+    pub hub: Option<DeploymentRateLimitUsage>,
+}
+
+#[derive(CandidType, Deserialize, Debug, PartialEq, Eq)]
+pub struct DeploymentRateLimitUsage {
+    pub limit: DeploymentRateLimit,
+    /// Deployments started within the current window.
+    pub deployments_count: u64,
+    /// Time the next deployment is allowed at, if the limit is reached.
+    pub retry_after: Option<TimestampMillis>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetDeploymentRateLimitsUsageError {
+    PermissionDenied,
+}
+
+impl From<Result<GetDeploymentRateLimitsUsageResult, GetDeploymentRateLimitsUsageError>>
+    for GetDeploymentRateLimitsUsageResponse
+{
+    fn from(
+        r: Result<GetDeploymentRateLimitsUsageResult, GetDeploymentRateLimitsUsageError>,
+    ) -> Self {
+        match r {
+            Ok(result) => GetDeploymentRateLimitsUsageResponse::Ok(result),
+            Err(error) => GetDeploymentRateLimitsUsageResponse::Err(error),
+        }
+    }
+}
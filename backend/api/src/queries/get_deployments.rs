@@ -14,10 +14,14 @@ pub enum DeploymentsSelector {
     ByContractTemplate {
         contract_template_id: ContractTemplateId,
     },
+    /// Selects by the current contract owner.
     ByDeployer {
         deployer: Principal,
         contract_template_id: Option<ContractTemplateId>,
     },
+    BySponsor {
+        sponsor: Principal,
+    },
 }
 
 #[derive(CandidType, Deserialize, Debug)]
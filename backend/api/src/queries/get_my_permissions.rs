@@ -0,0 +1,21 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::{Permissions, RoleAssignment};
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct Args {}
+
+pub type Response = GetMyPermissionsResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetMyPermissionsResponse {
+    Ok(GetMyPermissionsResult),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetMyPermissionsResult {
+    /// Effective permissions of the caller, granted directly or by the active roles.
+    pub permissions: Permissions,
+    pub role_assignments: Vec<RoleAssignment>,
+}
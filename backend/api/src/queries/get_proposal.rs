@@ -0,0 +1,37 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::{Proposal, ProposalId};
+
+pub type Args = GetProposalArgs;
+pub type Response = GetProposalResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetProposalArgs {
+    pub proposal_id: ProposalId,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetProposalResponse {
+    Ok(GetProposalResult),
+    Err(GetProposalError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetProposalResult {
+    pub proposal: Proposal,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetProposalError {
+    ProposalNotFound,
+}
+
+impl From<Result<GetProposalResult, GetProposalError>> for GetProposalResponse {
+    fn from(r: Result<GetProposalResult, GetProposalError>) -> Self {
+        match r {
+            Ok(result) => GetProposalResponse::Ok(result),
+            Err(error) => GetProposalResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::types::PublisherInformation;
+
+pub type Args = GetPublisherArgs;
+pub type Response = GetPublisherResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetPublisherArgs {
+    pub publisher: Principal,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetPublisherResponse {
+    Ok(Box<GetPublisherResult>),
+    Err(GetPublisherError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetPublisherResult {
+    pub publisher: PublisherInformation,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetPublisherError {
+    PublisherNotFound,
+}
+
+impl From<Result<GetPublisherResult, GetPublisherError>> for GetPublisherResponse {
+    fn from(r: Result<GetPublisherResult, GetPublisherError>) -> Self {
+        match r {
+            Ok(result) => GetPublisherResponse::Ok(Box::new(result)),
+            Err(error) => GetPublisherResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::types::SponsorshipInformation;
+
+pub type Args = GetSponsorshipArgs;
+pub type Response = GetSponsorshipResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetSponsorshipArgs {
+    pub sponsor: Principal,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetSponsorshipResponse {
+    Ok(Box<GetSponsorshipResult>),
+    Err(GetSponsorshipError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetSponsorshipResult {
+    pub sponsorship: SponsorshipInformation,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetSponsorshipError {
+    SponsorshipNotFound,
+}
+
+impl From<Result<GetSponsorshipResult, GetSponsorshipError>> for GetSponsorshipResponse {
+    fn from(r: Result<GetSponsorshipResult, GetSponsorshipError>) -> Self {
+        match r {
+            Ok(result) => GetSponsorshipResponse::Ok(Box::new(result)),
+            Err(error) => GetSponsorshipResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::{VoucherCode, VoucherInformation};
+
+pub type Args = GetVoucherArgs;
+pub type Response = GetVoucherResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetVoucherArgs {
+    pub code: VoucherCode,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetVoucherResponse {
+    Ok(Box<GetVoucherResult>),
+    Err(GetVoucherError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct GetVoucherResult {
+    pub voucher: VoucherInformation,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum GetVoucherError {
+    VoucherNotFound,
+}
+
+impl From<Result<GetVoucherResult, GetVoucherError>> for GetVoucherResponse {
+    fn from(r: Result<GetVoucherResult, GetVoucherError>) -> Self {
+        match r {
+            Ok(result) => GetVoucherResponse::Ok(Box::new(result)),
+            Err(error) => GetVoucherResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use crate::types::DaoProposalValidationResponse;
+
+pub use crate::updates::add_contract_template::Args;
+
+pub type Response = DaoProposalValidationResponse;
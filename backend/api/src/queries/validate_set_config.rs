@@ -0,0 +1,5 @@
+use crate::types::DaoProposalValidationResponse;
+
+pub use crate::updates::set_config::Args;
+
+pub type Response = DaoProposalValidationResponse;
@@ -1,4 +1,5 @@
 use candid::{CandidType, Principal};
+use common_canister_types::TimestampMillis;
 use serde::{Deserialize, Serialize};
 
 #[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -9,6 +10,11 @@ pub enum Permission {
     BlockContract,
     BlockContractTemplate,
     RetireContractTemplate,
+    SetContractTemplateMetadata,
+    SetPublisher,
+    ReviewContractTemplate,
+    SetVoucher,
+    BlockDeployer,
 }
 
 pub type Permissions = Option<Vec<Permission>>;
@@ -19,3 +25,24 @@ pub struct AccessRight {
     pub permissions: Permissions,
     pub description: Option<String>,
 }
+
+/// Named bundle of permissions, which is granted through the role assignments.
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Role {
+    pub name: String,
+    pub permissions: Vec<Permission>,
+    pub description: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RoleAssignmentSettings {
+    /// The role is not granted since the time, the assignment without expiration is permanent.
+    pub expiration: Option<TimestampMillis>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RoleAssignment {
+    pub caller: Principal,
+    pub role: String,
+    pub settings: RoleAssignmentSettings,
+}
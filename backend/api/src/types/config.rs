@@ -1,7 +1,9 @@
 use candid::{CandidType, Principal};
-use common_canister_types::TimestampMillis;
+use common_canister_types::{LedgerAccount, TimestampMillis};
 use serde::{Deserialize, Serialize};
 
+use super::GovernancePolicy;
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 pub struct Config {
     pub contract_wasm_max_size: usize,
@@ -16,6 +18,22 @@ pub struct Config {
     pub cycles_converting_strategy: CyclesConvertingStrategy,
     pub contract_canister_creation_strategy: CreateContractCanisterStrategy,
     pub deployment_fallback_account_hex: String,
+    #[serde(default)]
+    pub deployment_beneficiaries: Vec<DeploymentBeneficiary>,
+    /// Cycles the hub may spend itself on the deployments discounted by vouchers.
+    #[serde(default)]
+    pub vouchers_cycles_budget: u128,
+    /// Cycles cost of a contract certificate renewal, the renewal is free when zero.
+    #[serde(default)]
+    pub certificate_renewal_cycles_cost: u128,
+    #[serde(default)]
+    pub deployment_rate_limits: DeploymentRateLimits,
+    /// Sensitive operations executed only by the approved proposals.
+    #[serde(default)]
+    pub governance_policies: Vec<GovernancePolicy>,
+    /// DAO governance, e.g. the SNS governance canister, which executes its proposals.
+    #[serde(default)]
+    pub governance_principal: Option<Principal>,
     pub max_hub_events_per_chunk: usize,
     pub max_contract_templates_per_chunk: usize,
     pub max_deployments_per_chunk: usize,
@@ -26,6 +44,97 @@ pub struct Config {
     pub long_description_max_length: usize,
 }
 
+/// Number of the config changes, the patch of the config must match it.
+pub type ConfigVersion = u64;
+
+macro_rules! config_fields {
+    ($($variant:ident($field:ident: $type:ty)),* $(,)?) => {
+        /// New value of a single config field.
+        #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+        pub enum ConfigField {
+            $($variant($type)),*
+        }
+
+        impl Config {
+            pub fn set_field(&mut self, field: ConfigField) {
+                match field {
+                    $(ConfigField::$variant(value) => self.$field = value),*
+                }
+            }
+
+            /// Returns the value of the same field of this config.
+            pub fn get_field(&self, field: &ConfigField) -> ConfigField {
+                match field {
+                    $(ConfigField::$variant(_) => ConfigField::$variant(self.$field.clone())),*
+                }
+            }
+
+            /// Returns the fields of the other config differing from this config.
+            pub fn get_changed_fields(&self, other: &Config) -> Vec<ConfigField> {
+                let mut fields = Vec::new();
+                $(
+                    if self.$field != other.$field {
+                        fields.push(ConfigField::$variant(other.$field.clone()));
+                    }
+                )*
+                fields
+            }
+        }
+    };
+}
+
+config_fields! {
+    ContractWasmMaxSize(contract_wasm_max_size: usize),
+    ContractWasmUploadChunkSize(contract_wasm_upload_chunk_size: usize),
+    RegexForContractPrincipalParsing(regex_for_contract_principal_parsing: Vec<String>),
+    IsDeploymentAvailable(is_deployment_available: bool),
+    DeploymentCyclesCost(deployment_cycles_cost: u128),
+    DeploymentExpensesAmountBufferPermyriad(deployment_expenses_amount_buffer_permyriad: u64),
+    DeploymentExpensesAmountDecimalPlaces(deployment_expenses_amount_decimal_places: u8),
+    DeploymentAllowanceExpirationTimeout(deployment_allowance_expiration_timeout: TimestampMillis),
+    IcpXdrConversionRateStrategy(icp_xdr_conversion_rate_strategy: IcpXdrConversionRateStrategy),
+    CyclesConvertingStrategy(cycles_converting_strategy: CyclesConvertingStrategy),
+    ContractCanisterCreationStrategy(contract_canister_creation_strategy: CreateContractCanisterStrategy),
+    DeploymentFallbackAccountHex(deployment_fallback_account_hex: String),
+    DeploymentBeneficiaries(deployment_beneficiaries: Vec<DeploymentBeneficiary>),
+    VouchersCyclesBudget(vouchers_cycles_budget: u128),
+    CertificateRenewalCyclesCost(certificate_renewal_cycles_cost: u128),
+    DeploymentRateLimits(deployment_rate_limits: DeploymentRateLimits),
+    GovernancePolicies(governance_policies: Vec<GovernancePolicy>),
+    GovernancePrincipal(governance_principal: Option<Principal>),
+    MaxHubEventsPerChunk(max_hub_events_per_chunk: usize),
+    MaxContractTemplatesPerChunk(max_contract_templates_per_chunk: usize),
+    MaxDeploymentsPerChunk(max_deployments_per_chunk: usize),
+    MaxDeploymentEventsPerChunk(max_deployment_events_per_chunk: usize),
+    ContractUrlPattern(contract_url_pattern: String),
+    NameMaxLength(name_max_length: usize),
+    ShortDescriptionMaxLength(short_description_max_length: usize),
+    LongDescriptionMaxLength(long_description_max_length: usize),
+}
+
+/// Receiver of a share of the funds left on the deployment transit account.
+/// Without beneficiaries the funds are sent to the deployment fallback account.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentBeneficiary {
+    pub account: LedgerAccount,
+    pub share_permyriad: u64,
+}
+
+/// Sliding window limits of the started deployments, absent limits are not enforced.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct DeploymentRateLimits {
+    pub per_deployer: Option<DeploymentRateLimit>,
+    pub per_contract_template: Option<DeploymentRateLimit>,
+    pub hub: Option<DeploymentRateLimit>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentRateLimit {
+    /// Length of the sliding window in milliseconds.
+    pub window: TimestampMillis,
+    pub max_deployments: u64,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum IcpXdrConversionRateStrategy {
     CMC { cmc_canister: Principal },
@@ -0,0 +1,16 @@
+use candid::CandidType;
+use common_canister_types::TimestampMillis;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ContractBlockAction {
+    Blocked,
+    Unblocked,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ContractBlockHistoryEntry {
+    pub time: TimestampMillis,
+    pub action: ContractBlockAction,
+    pub reason: String,
+}
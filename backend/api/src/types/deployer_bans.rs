@@ -0,0 +1,27 @@
+use candid::{CandidType, Principal};
+use common_canister_types::TimestampMillis;
+use serde::{Deserialize, Serialize};
+
+/// Denial of new deployments for the principal, e.g. a known abuser.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DeployerBanSettings {
+    pub reason: String,
+    /// The ban is lifted automatically after this time, if set.
+    pub expiration: Option<TimestampMillis>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DeployerBan {
+    pub banned: TimestampMillis,
+    pub banned_by: Principal,
+    pub settings: DeployerBanSettings,
+}
+
+impl DeployerBan {
+    pub fn is_active(&self, time: TimestampMillis) -> bool {
+        self.settings
+            .expiration
+            .map(|expiration| time < expiration)
+            .unwrap_or(true)
+    }
+}
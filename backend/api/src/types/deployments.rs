@@ -5,7 +5,7 @@ use common_canister_types::{
 use common_contract_api::{ContractTemplateId, SignedContractCertificate};
 use serde::{Deserialize, Serialize};
 
-use crate::types::CanisterSettings;
+use crate::types::{CanisterSettings, PublisherFee, VoucherDiscount, VoucherRedemption};
 
 pub type DeploymentId = u64;
 pub type DeploymentEventId = u64;
@@ -46,10 +46,22 @@ pub enum DeploymentState {
 #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub enum FinalizeDeploymentState {
     StartDeploymentFinalization,
+    TransferPublisherFee,
     TransferTransitFundsToExternalService,
+    TransferTransitFundsToBeneficiaries {
+        payouts: Vec<BeneficiaryPayout>,
+        paid_count: usize,
+    },
     Finalized,
 }
 
+/// Amount of the transit funds planned for the beneficiary, the ledger fee is paid from it.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct BeneficiaryPayout {
+    pub account: LedgerAccount,
+    pub amount: TokenE8s,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub enum DeploymentResult {
     Success,
@@ -106,10 +118,22 @@ pub enum DeploymentProcessingEvent {
     ContractWasmInstalled,
     ContractSelfControlledMade,
     StartCompleteDeployment,
+    PublisherFeeTransferred {
+        block_index: Option<u64>,
+        transfer_amount: TokenE8s,
+    },
     TransitFundsToExternalServiceTransferred {
         block_index: Option<u64>,
         transfer_amount: TokenE8s,
     },
+    TransitFundsDistributionStarted {
+        payouts: Vec<BeneficiaryPayout>,
+    },
+    TransitFundsToBeneficiaryTransferred {
+        beneficiary_index: usize,
+        block_index: Option<u64>,
+        transfer_amount: TokenE8s,
+    },
     DeploymentCanceled {
         reason: String,
     },
@@ -123,6 +147,13 @@ pub struct DeploymentInformation {
     pub contract_template_id: ContractTemplateId,
     pub deployment_expenses: DeploymentExpenses,
     pub expenses_amount: TokenE8s,
+    pub publisher_fee_amount: TokenE8s,
+    pub voucher_redemption: Option<VoucherRedemption>,
+    pub sponsor: Option<Principal>,
+    pub beneficiary: Option<Principal>,
+    pub pending_owner: Option<Principal>,
+    pub ownership_history: Vec<DeploymentOwnershipTransfer>,
+    pub certificate_renewals: Vec<CertificateRenewal>,
     pub approved_account: LedgerAccount,
     pub subnet_type: Option<String>,
     pub contract_canister: Option<Principal>,
@@ -132,6 +163,22 @@ pub struct DeploymentInformation {
     pub lock: Option<DelayedTimestampMillis>,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DeploymentOwnershipTransfer {
+    pub previous_owner: Principal,
+    pub new_owner: Principal,
+    pub transferred: TimestampMillis,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CertificateRenewal {
+    pub renewed: TimestampMillis,
+    pub renewed_by: Principal,
+    pub expiration: TimestampMillis,
+    pub expenses_amount: TokenE8s,
+    pub block_index: Option<u64>,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct DeploymentExpenses {
     pub deployment_cycles_cost: u128,
@@ -139,6 +186,10 @@ pub struct DeploymentExpenses {
     pub amount_buffer_permyriad: u64,
     pub amount_decimal_places: u8,
     pub icp_conversation_rate: IcpConversationRate,
+    #[serde(default)]
+    pub publisher_fee: Option<PublisherFee>,
+    #[serde(default)]
+    pub voucher_discount: Option<VoucherDiscount>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
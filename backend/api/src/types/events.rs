@@ -3,16 +3,35 @@ use common_canister_types::TimestampMillis;
 use common_contract_api::ContractTemplateId;
 use serde::{Deserialize, Serialize};
 
-use super::{AccessRight, Config};
+use super::{
+    AccessRight, Config, ConfigField, ConfigVersion, ContractTemplateMetadata,
+    ContractTemplateReviewStatus, ContractTemplateVisibility, DeployerBanSettings,
+    GovernedOperation, ProposalId, PublisherSettings, Role, RoleAssignmentSettings,
+    SponsorshipSettings, VoucherCode, VoucherDefinition,
+};
 
 #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub enum HubEventType {
     AccessRightsSet {
         access_rights: Vec<AccessRight>,
     },
+    RolesSet {
+        roles: Vec<Role>,
+    },
+    RoleAssignmentSet {
+        caller: Principal,
+        role: String,
+        settings: Option<RoleAssignmentSettings>,
+    },
+    /// Full config recorded before the field-level config changes.
     ConfigSet {
         config: Box<Config>,
     },
+    ConfigPatched {
+        config_version: ConfigVersion,
+        changes: Vec<ConfigField>,
+        previous: Vec<ConfigField>,
+    },
     ContractTemplateAdded {
         contract_template_id: ContractTemplateId,
     },
@@ -22,9 +41,56 @@ pub enum HubEventType {
     ContractBlocked {
         deployment_ids_count: u64,
     },
+    ContractUnblocked {
+        deployment_ids_count: u64,
+    },
     ContractTemplateRetired {
         contract_template_id: ContractTemplateId,
         retired: bool,
+        #[serde(default)]
+        retire_at: Option<TimestampMillis>,
+        #[serde(default)]
+        replacement: Option<ContractTemplateId>,
+    },
+    ContractTemplateVisibilitySet {
+        contract_template_id: ContractTemplateId,
+        visibility: ContractTemplateVisibility,
+    },
+    ContractTemplateMetadataSet {
+        contract_template_id: ContractTemplateId,
+        previous_metadata: Box<ContractTemplateMetadata>,
+    },
+    PublisherSet {
+        publisher: Principal,
+        settings: Option<PublisherSettings>,
+    },
+    ContractTemplateReviewStatusSet {
+        contract_template_id: ContractTemplateId,
+        status: ContractTemplateReviewStatus,
+    },
+    VoucherSet {
+        code: VoucherCode,
+        definition: Option<VoucherDefinition>,
+    },
+    SponsorshipSet {
+        sponsor: Principal,
+        settings: Option<SponsorshipSettings>,
+    },
+    DeployerBanSet {
+        deployer: Principal,
+        settings: Option<DeployerBanSettings>,
+    },
+    ProposalCreated {
+        proposal_id: ProposalId,
+        operation: GovernedOperation,
+    },
+    ProposalApproved {
+        proposal_id: ProposalId,
+    },
+    ProposalExecuted {
+        proposal_id: ProposalId,
+        /// Reason of the failed execution, the proposal is not executed again.
+        error: Option<String>,
     },
 }
 
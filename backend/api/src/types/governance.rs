@@ -0,0 +1,108 @@
+use candid::{CandidType, Principal};
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use serde::{Deserialize, Serialize};
+
+use super::{AccessRight, Config, Role, RoleAssignmentSettings};
+
+pub type ProposalId = u64;
+
+/// Sensitive operations, which can be executed only by the approved proposals.
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GovernedOperationType {
+    SetConfig,
+    /// Governs the roles and the role assignments too.
+    SetAccessRights,
+    BlockContractTemplate,
+}
+
+/// The governed operation is executed after `required_approvals` of the `approvers`
+/// approve the proposal within the `approval_window` since the proposal creation.
+/// Governing the `SetConfig` protects the policies themselves.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct GovernancePolicy {
+    pub operation_type: GovernedOperationType,
+    pub approvers: Vec<Principal>,
+    pub required_approvals: u32,
+    pub approval_window: TimestampMillis,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum GovernedOperation {
+    SetConfig {
+        config: Box<Config>,
+    },
+    SetAccessRights {
+        access_rights: Vec<AccessRight>,
+    },
+    SetRoles {
+        roles: Vec<Role>,
+    },
+    SetRoleAssignment {
+        caller: Principal,
+        role: String,
+        settings: Option<RoleAssignmentSettings>,
+    },
+    BlockContractTemplate {
+        contract_template_id: ContractTemplateId,
+        reason: String,
+    },
+}
+
+impl GovernedOperation {
+    pub fn get_operation_type(&self) -> GovernedOperationType {
+        match self {
+            GovernedOperation::SetConfig { .. } => GovernedOperationType::SetConfig,
+            GovernedOperation::SetAccessRights { .. }
+            | GovernedOperation::SetRoles { .. }
+            | GovernedOperation::SetRoleAssignment { .. } => GovernedOperationType::SetAccessRights,
+            GovernedOperation::BlockContractTemplate { .. } => {
+                GovernedOperationType::BlockContractTemplate
+            }
+        }
+    }
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Pending,
+    Expired,
+    Executed,
+    ExecutionFailed { reason: String },
+}
+
+/// Proposed governed operation, the approvers and the required approvals are taken
+/// from the policy at the proposal creation.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Proposal {
+    pub proposer: Principal,
+    pub operation: GovernedOperation,
+    pub created: TimestampMillis,
+    pub expiration: TimestampMillis,
+    pub approvers: Vec<Principal>,
+    pub required_approvals: u32,
+    pub approvals: Vec<Principal>,
+    pub status: ProposalStatus,
+}
+
+impl Proposal {
+    pub fn is_expired(&self, time: TimestampMillis) -> bool {
+        self.status == ProposalStatus::Pending && self.expiration <= time
+    }
+}
+
+/// Reply of the DAO proposal validators, the human-readable rendering of the valid payload.
+#[derive(CandidType, Deserialize, Debug)]
+pub enum DaoProposalValidationResponse {
+    Ok(String),
+    Err(String),
+}
+
+impl From<Result<String, String>> for DaoProposalValidationResponse {
+    fn from(r: Result<String, String>) -> Self {
+        match r {
+            Ok(rendering) => DaoProposalValidationResponse::Ok(rendering),
+            Err(error) => DaoProposalValidationResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use super::Config;
+
+/// Version of the hub stable state, increased by every migration.
+pub type SchemaVersion = u32;
+
+/// The install and the upgrade share the argument type.
+#[derive(CandidType, Deserialize, Debug, Clone)]
+pub enum HubArgs {
+    Init(HubInitArgs),
+    Upgrade(HubUpgradeArgs),
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+pub struct HubInitArgs {
+    /// Principals granted all the permissions at the hub installation.
+    pub admins: Vec<Principal>,
+    /// The hub is installed with the default config if not specified.
+    pub config: Option<Box<Config>>,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone, Default)]
+pub struct HubUpgradeArgs {
+    /// Principals granted all the permissions only if the hub has no access rights.
+    pub admins: Vec<Principal>,
+    /// The stable state is migrated to the latest version if not specified.
+    pub migration: Option<MigrationDirective>,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum MigrationDirective {
+    /// Runs the migrations up to the version.
+    MigrateTo { version: SchemaVersion },
+    /// Rolls the version of the stable state back without running the migrations,
+    /// so the migrations above the version run again on the next upgrade.
+    SetVersion { version: SchemaVersion },
+}
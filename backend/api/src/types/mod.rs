@@ -1,11 +1,25 @@
 mod access_rights;
 mod config;
+mod contract_blocks;
+mod deployer_bans;
 mod deployments;
 mod events;
+mod governance;
+mod lifecycle;
+mod publishers;
+mod sponsors;
 mod templates;
+mod vouchers;
 
 pub use access_rights::*;
 pub use config::*;
+pub use contract_blocks::*;
+pub use deployer_bans::*;
 pub use deployments::*;
 pub use events::*;
+pub use governance::*;
+pub use lifecycle::*;
+pub use publishers::*;
+pub use sponsors::*;
 pub use templates::*;
+pub use vouchers::*;
@@ -0,0 +1,62 @@
+use candid::{CandidType, Principal};
+use common_canister_types::{LedgerAccount, TimestampMillis, TokenE8s};
+use common_contract_api::ContractTemplateId;
+use serde::{Deserialize, Serialize};
+
+use super::ContractTemplateReviewStatus;
+
+/// Quotas of the third-party registrar, who publishes and manages own contract templates.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PublisherSettings {
+    pub max_contract_templates: usize,
+    pub max_total_wasm_length: usize,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct PublisherInformation {
+    pub publisher: Principal,
+    pub registered: TimestampMillis,
+    pub settings: PublisherSettings,
+    pub contract_templates_count: usize,
+    pub total_wasm_length: usize,
+    pub deployments_count: usize,
+    pub contract_templates: Vec<PublisherContractTemplate>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct PublisherContractTemplate {
+    pub contract_template_id: ContractTemplateId,
+    pub name: String,
+    pub registered: TimestampMillis,
+    pub blocked: bool,
+    pub retired: bool,
+    /// Time of the scheduled retirement, which has not come yet.
+    pub pending_retirement: Option<TimestampMillis>,
+    pub review_status: ContractTemplateReviewStatus,
+    pub deployments_count: usize,
+}
+
+/// Fee paid to the contract template publisher from every successful deployment.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PublisherFee {
+    pub amount: PublisherFeeAmount,
+    pub payout_account: LedgerAccount,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum PublisherFeeAmount {
+    Flat {
+        amount: TokenE8s,
+    },
+    /// Share of the deployment expenses amount.
+    Share {
+        permyriad: u64,
+    },
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct PublisherEarnings {
+    pub total_amount: TokenE8s,
+    pub payouts_count: usize,
+    pub last_payout: Option<TimestampMillis>,
+}
@@ -0,0 +1,28 @@
+use candid::{CandidType, Principal};
+use common_canister_types::{LedgerAccount, TimestampMillis, TokenE8s};
+use common_contract_api::ContractTemplateId;
+use serde::{Deserialize, Serialize};
+
+/// Third party, who pays the deployments of the listed deployers or contract templates.
+///
+/// The `approved_account` must grant the allowance to the hub
+/// with `principal_to_subaccount(sponsor)` as the spender subaccount.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SponsorshipSettings {
+    pub approved_account: LedgerAccount,
+    /// Total amount the sponsor agrees to spend on deployments.
+    pub budget: TokenE8s,
+    pub deployers: Option<Vec<Principal>>,
+    pub contract_template_ids: Option<Vec<ContractTemplateId>>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SponsorshipInformation {
+    pub sponsor: Principal,
+    pub registered: TimestampMillis,
+    pub settings: SponsorshipSettings,
+    /// Amount of the deployments, whose funds are not transferred yet.
+    pub reserved_amount: TokenE8s,
+    pub charged_amount: TokenE8s,
+    pub deployments_count: usize,
+}
@@ -3,6 +3,8 @@ use common_canister_types::{TimestampMillis, Timestamped};
 use common_contract_api::{ContractTemplateId, WasmHash};
 use serde::{Deserialize, Serialize};
 
+use super::PublisherFee;
+
 #[derive(CandidType, Deserialize, Debug)]
 pub struct ContractTemplateInformation {
     pub contract_template_id: ContractTemplateId,
@@ -11,7 +13,98 @@ pub struct ContractTemplateInformation {
     pub definition: ContractTemplateDefinition,
     pub blocked: Option<Timestamped<String>>,
     pub retired: Option<Timestamped<String>>,
+    /// The scheduled retirement, which is not effective yet.
+    pub pending_retirement: Option<Timestamped<String>>,
+    pub replacement: Option<ContractTemplateId>,
     pub deployments_count: usize,
+    /// In-flight deployments counted against the deployment cap.
+    pub reserved_deployments_count: usize,
+    pub remaining_supply: Option<usize>,
+    pub interface_summary: Option<ContractTemplateInterfaceSummary>,
+    pub review: ContractTemplateReview,
+    pub visibility: ContractTemplateVisibility,
+}
+
+/// Who can see and deploy the contract template.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub enum ContractTemplateVisibility {
+    #[default]
+    Public,
+    /// Deployable by anyone, but omitted from the contract templates list.
+    Unlisted,
+    /// Visible and deployable by the listed principals and the publisher only.
+    Restricted { allowed_deployers: Vec<Principal> },
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ContractTemplateReviewStatus {
+    Submitted,
+    UnderReview,
+    Approved,
+    Rejected,
+}
+
+/// Only approved contract templates can be deployed.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ContractTemplateReview {
+    pub status: ContractTemplateReviewStatus,
+    pub updated: TimestampMillis,
+    pub reviewer: Option<Principal>,
+    pub rejection_reason: Option<String>,
+    pub notes: Vec<ContractTemplateReviewNote>,
+    pub attestations: Vec<ReproducibleBuildAttestation>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ContractTemplateReviewNote {
+    pub reviewer: Principal,
+    pub time: TimestampMillis,
+    pub note: String,
+}
+
+/// Reviewer's confirmation, that the contract wasm has been reproduced from the sources.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ReproducibleBuildAttestation {
+    pub reviewer: Principal,
+    pub time: TimestampMillis,
+    pub wasm_hash: WasmHash,
+    pub build_url: String,
+    pub description: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CanisterMethodType {
+    Query,
+    CompositeQuery,
+    Update,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CanisterMethod {
+    pub name: String,
+    pub method_type: CanisterMethodType,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ContractTemplateInterface {
+    /// Content of the public `candid:service` metadata section.
+    pub candid_service: Option<String>,
+
+    /// Content of the public `candid:args` metadata section.
+    pub candid_args: Option<String>,
+
+    /// Canister methods exported by the contract wasm.
+    pub methods: Vec<CanisterMethod>,
+
+    /// Other public text metadata sections of the contract wasm,
+    /// e.g. `git_commit_id`, `git_repo` or build information.
+    pub metadata: Vec<(String, String)>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ContractTemplateInterfaceSummary {
+    pub methods: Vec<CanisterMethod>,
+    pub candid_service_available: bool,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -33,6 +126,55 @@ pub struct ContractTemplateDefinition {
     pub contract_canister_settings: CanisterSettings,
     pub documentation_url: String,
     pub terms_of_use_url: String,
+    #[serde(default)]
+    pub categories: Option<Vec<String>>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub publisher_fee: Option<PublisherFee>,
+    /// The limited edition: the maximum number of deployments of the contract template.
+    #[serde(default)]
+    pub max_deployments: Option<usize>,
+    #[serde(default)]
+    pub max_deployments_per_deployer: Option<usize>,
+}
+
+/// Presentation fields of [`ContractTemplateDefinition`] which can be edited after the registration.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ContractTemplateMetadata {
+    pub name: String,
+    pub short_description: String,
+    pub long_description: Option<String>,
+    pub documentation_url: String,
+    pub terms_of_use_url: String,
+    #[serde(default)]
+    pub categories: Option<Vec<String>>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+}
+
+impl ContractTemplateDefinition {
+    pub fn get_metadata(&self) -> ContractTemplateMetadata {
+        ContractTemplateMetadata {
+            name: self.name.clone(),
+            short_description: self.short_description.clone(),
+            long_description: self.long_description.clone(),
+            documentation_url: self.documentation_url.clone(),
+            terms_of_use_url: self.terms_of_use_url.clone(),
+            categories: self.categories.clone(),
+            tags: self.tags.clone(),
+        }
+    }
+
+    pub fn set_metadata(&mut self, metadata: ContractTemplateMetadata) {
+        self.name = metadata.name;
+        self.short_description = metadata.short_description;
+        self.long_description = metadata.long_description;
+        self.documentation_url = metadata.documentation_url;
+        self.terms_of_use_url = metadata.terms_of_use_url;
+        self.categories = metadata.categories;
+        self.tags = metadata.tags;
+    }
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
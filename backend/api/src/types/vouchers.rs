@@ -0,0 +1,40 @@
+use candid::CandidType;
+use common_canister_types::{TimestampMillis, TokenE8s};
+use common_contract_api::ContractTemplateId;
+use serde::{Deserialize, Serialize};
+
+pub type VoucherCode = String;
+
+/// Conditions of the voucher, which discounts the deployment expenses.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VoucherDefinition {
+    pub discount: VoucherDiscount,
+    pub max_redemptions: usize,
+    pub expiration: Option<TimestampMillis>,
+    /// Contract templates the voucher applies to, any template if not set.
+    pub contract_template_ids: Option<Vec<ContractTemplateId>>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum VoucherDiscount {
+    Percentage { permyriad: u64 },
+    Fixed { amount: TokenE8s },
+    Free,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct VoucherInformation {
+    pub code: VoucherCode,
+    pub created: TimestampMillis,
+    pub definition: VoucherDefinition,
+    pub redemptions_count: usize,
+    pub subsidized_cycles: u128,
+}
+
+/// Voucher applied to the deployment, the hub covers the subsidized cycles itself.
+#[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VoucherRedemption {
+    pub code: VoucherCode,
+    pub discount_amount: TokenE8s,
+    pub subsidized_cycles: u128,
+}
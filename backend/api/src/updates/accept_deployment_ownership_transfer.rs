@@ -0,0 +1,43 @@
+use crate::types::{DeployerBan, DeploymentId, DeploymentInformation};
+use candid::CandidType;
+use serde::Deserialize;
+
+pub type Args = AcceptDeploymentOwnershipTransferArgs;
+pub type Response = AcceptDeploymentOwnershipTransferResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct AcceptDeploymentOwnershipTransferArgs {
+    pub deployment_id: DeploymentId,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum AcceptDeploymentOwnershipTransferResponse {
+    Ok(Box<AcceptDeploymentOwnershipTransferResult>),
+    Err(AcceptDeploymentOwnershipTransferError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct AcceptDeploymentOwnershipTransferResult {
+    pub deployment: DeploymentInformation,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum AcceptDeploymentOwnershipTransferError {
+    DeploymentNotFound,
+    PermissionDenied,
+    DeploymentWrongState,
+    DeployerBanned { ban: DeployerBan },
+}
+
+impl From<Result<AcceptDeploymentOwnershipTransferResult, AcceptDeploymentOwnershipTransferError>>
+    for AcceptDeploymentOwnershipTransferResponse
+{
+    fn from(
+        r: Result<AcceptDeploymentOwnershipTransferResult, AcceptDeploymentOwnershipTransferError>,
+    ) -> Self {
+        match r {
+            Ok(result) => AcceptDeploymentOwnershipTransferResponse::Ok(Box::new(result)),
+            Err(error) => AcceptDeploymentOwnershipTransferResponse::Err(error),
+        }
+    }
+}
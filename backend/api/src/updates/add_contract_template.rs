@@ -34,6 +34,19 @@ pub enum AddContractTemplateError {
     ContractNameIsTooLong { max_length: usize },
     ContractShortDescriptionIsTooLong { max_length: usize },
     ContractLongDescriptionIsTooLong { max_length: usize },
+    TooManyContractCategories { max_count: usize },
+    InvalidContractCategory { category: String },
+    TooManyContractTags { max_count: usize },
+    InvalidContractTag { tag: String },
+    InvalidPublisherFee { reason: String },
+    InvalidDeploymentLimits { reason: String },
+    ContractTemplatesQuotaExceeded { max_count: usize },
+    WasmQuotaExceeded { available_length: usize },
+    InvalidWasmModule { reason: String },
+    InvalidWasmCustomSection { name: String, reason: String },
+    MissingCanisterMethod { method: String },
+    WasmMemoryExceedsWasmMemoryLimit { initial_memory: u128, limit: u128 },
+    WasmMemoryExceedsMemoryAllocation { initial_memory: u128, limit: u128 },
 }
 
 impl From<Result<AddContractTemplateResult, AddContractTemplateError>>
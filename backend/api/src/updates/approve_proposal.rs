@@ -0,0 +1,42 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::{ProposalId, ProposalStatus};
+
+pub type Args = ApproveProposalArgs;
+pub type Response = ApproveProposalResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ApproveProposalArgs {
+    pub proposal_id: ProposalId,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ApproveProposalResponse {
+    Ok(ApproveProposalResult),
+    Err(ApproveProposalError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ApproveProposalResult {
+    /// The operation is executed by the last required approval.
+    pub status: ProposalStatus,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ApproveProposalError {
+    PermissionDenied,
+    ProposalNotFound,
+    ProposalExpired,
+    ProposalNotPending,
+    AlreadyApproved,
+}
+
+impl From<Result<ApproveProposalResult, ApproveProposalError>> for ApproveProposalResponse {
+    fn from(r: Result<ApproveProposalResult, ApproveProposalError>) -> Self {
+        match r {
+            Ok(result) => ApproveProposalResponse::Ok(result),
+            Err(error) => ApproveProposalResponse::Err(error),
+        }
+    }
+}
@@ -22,6 +22,7 @@ pub enum BlockContractTemplateError {
     PermissionDenied,
     ContractTemplateNotFound,
     ContractTemplateAlreadyBlocked,
+    ProposalRequired,
 }
 
 impl From<Result<(), BlockContractTemplateError>> for BlockContractTemplateResponse {
@@ -1,6 +1,6 @@
-use crate::types::DeploymentInformation;
-use candid::CandidType;
-use common_canister_types::LedgerAccount;
+use crate::types::{DeployerBan, DeploymentInformation, VoucherCode};
+use candid::{CandidType, Principal};
+use common_canister_types::{LedgerAccount, TimestampMillis};
 use common_contract_api::ContractTemplateId;
 use serde::Deserialize;
 
@@ -14,6 +14,11 @@ pub struct DeployContractArgs {
     pub approved_account: LedgerAccount,
     pub contract_template_id: ContractTemplateId,
     pub subnet_type: Option<String>,
+    pub voucher: Option<VoucherCode>,
+    /// Sponsor, who pays the deployment from own approved account instead of the `approved_account`.
+    pub sponsor: Option<Principal>,
+    /// Principal the contract is deployed for, the caller stays the payer.
+    pub beneficiary: Option<Principal>,
 }
 
 #[derive(CandidType, Deserialize, Debug)]
@@ -27,13 +32,31 @@ pub type DeployContractResult = ProcessDeploymentResult;
 #[derive(CandidType, Deserialize, Debug)]
 pub enum DeployContractError {
     CallerNotAuthorized,
+    DeployerBanned {
+        ban: DeployerBan,
+    },
+    InvalidBeneficiary,
+    BeneficiaryBanned {
+        ban: DeployerBan,
+    },
     DeploymentUnavailable,
+    RateLimited {
+        retry_after: TimestampMillis,
+    },
     ActiveDeploymentExists {
         deployment: Box<DeploymentInformation>,
     },
     ContractTemplateNotFound,
     ContractTemplateBlocked,
-    ContractTemplateRetired,
+    ContractTemplateRetired {
+        replacement: Option<ContractTemplateId>,
+    },
+    ContractTemplateNotApproved,
+    ContractTemplateRestricted,
+    ContractTemplateSupplyExhausted,
+    DeployerSupplyExhausted {
+        max_deployments: usize,
+    },
     InvalidApprovedAccount {
         reason: String,
     },
@@ -52,6 +75,14 @@ pub enum DeployContractError {
     GenerateActivationCodeError {
         reason: String,
     },
+    VoucherNotFound,
+    VoucherExpired,
+    VoucherRedemptionsExhausted,
+    VoucherNotApplicable,
+    VouchersCyclesBudgetExhausted,
+    SponsorshipNotFound,
+    SponsorshipNotApplicable,
+    SponsorshipBudgetExhausted,
 }
 
 impl From<Result<DeployContractResult, DeployContractError>> for DeployContractResponse {
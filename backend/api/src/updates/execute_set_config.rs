@@ -0,0 +1,4 @@
+pub use crate::updates::set_config::Args;
+
+/// The execution error traps, so the DAO proposal is marked as failed.
+pub type Response = ();
@@ -0,0 +1,42 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::{ConfigField, ConfigVersion};
+
+pub type Args = PatchConfigArgs;
+pub type Response = PatchConfigResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct PatchConfigArgs {
+    /// The version of the config the changes are based on.
+    pub config_version: ConfigVersion,
+    pub changes: Vec<ConfigField>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum PatchConfigResponse {
+    Ok(PatchConfigResult),
+    Err(PatchConfigError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct PatchConfigResult {
+    pub config_version: ConfigVersion,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum PatchConfigError {
+    PermissionDenied,
+    WrongConfig { reason: String },
+    ProposalRequired,
+    ConfigVersionMismatch { config_version: ConfigVersion },
+}
+
+impl From<Result<PatchConfigResult, PatchConfigError>> for PatchConfigResponse {
+    fn from(r: Result<PatchConfigResult, PatchConfigError>) -> Self {
+        match r {
+            Ok(result) => PatchConfigResponse::Ok(result),
+            Err(error) => PatchConfigResponse::Err(error),
+        }
+    }
+}
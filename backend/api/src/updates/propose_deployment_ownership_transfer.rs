@@ -0,0 +1,48 @@
+use crate::types::{DeploymentId, DeploymentInformation};
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+pub type Args = ProposeDeploymentOwnershipTransferArgs;
+pub type Response = ProposeDeploymentOwnershipTransferResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ProposeDeploymentOwnershipTransferArgs {
+    pub deployment_id: DeploymentId,
+    /// Principal to accept the ownership, `None` withdraws the pending proposal.
+    pub new_owner: Option<Principal>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ProposeDeploymentOwnershipTransferResponse {
+    Ok(Box<ProposeDeploymentOwnershipTransferResult>),
+    Err(ProposeDeploymentOwnershipTransferError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ProposeDeploymentOwnershipTransferResult {
+    pub deployment: DeploymentInformation,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ProposeDeploymentOwnershipTransferError {
+    DeploymentNotFound,
+    PermissionDenied,
+    DeploymentWrongState,
+    InvalidNewOwner,
+}
+
+impl From<Result<ProposeDeploymentOwnershipTransferResult, ProposeDeploymentOwnershipTransferError>>
+    for ProposeDeploymentOwnershipTransferResponse
+{
+    fn from(
+        r: Result<
+            ProposeDeploymentOwnershipTransferResult,
+            ProposeDeploymentOwnershipTransferError,
+        >,
+    ) -> Self {
+        match r {
+            Ok(result) => ProposeDeploymentOwnershipTransferResponse::Ok(Box::new(result)),
+            Err(error) => ProposeDeploymentOwnershipTransferResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::{GovernedOperation, ProposalId};
+
+pub type Args = ProposeOperationArgs;
+pub type Response = ProposeOperationResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ProposeOperationArgs {
+    pub operation: GovernedOperation,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ProposeOperationResponse {
+    Ok(ProposeOperationResult),
+    Err(ProposeOperationError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ProposeOperationResult {
+    pub proposal_id: ProposalId,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ProposeOperationError {
+    PermissionDenied,
+    OperationNotGoverned,
+    InvalidOperation { reason: String },
+}
+
+impl From<Result<ProposeOperationResult, ProposeOperationError>> for ProposeOperationResponse {
+    fn from(r: Result<ProposeOperationResult, ProposeOperationError>) -> Self {
+        match r {
+            Ok(result) => ProposeOperationResponse::Ok(result),
+            Err(error) => ProposeOperationResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::types::{DeploymentId, DeploymentInformation};
+use candid::CandidType;
+use common_canister_types::{LedgerAccount, TimestampMillis};
+use serde::Deserialize;
+
+pub type Args = RenewContractCertificateArgs;
+pub type Response = RenewContractCertificateResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct RenewContractCertificateArgs {
+    pub deployment_id: DeploymentId,
+    /// Account approved to pay the renewal, required when the renewal is not free.
+    pub approved_account: Option<LedgerAccount>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum RenewContractCertificateResponse {
+    Ok(Box<RenewContractCertificateResult>),
+    Err(RenewContractCertificateError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct RenewContractCertificateResult {
+    pub deployment: DeploymentInformation,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum RenewContractCertificateError {
+    DeploymentNotFound,
+    PermissionDenied,
+    DeploymentWrongState,
+    ContractBlocked { reason: String },
+    ContractTemplateBlocked,
+    ContractInfoUnavailable,
+    ContractWasmHashChanged,
+    InvalidApprovedAccount { reason: String },
+    GetIcpXdrConversionRateError { reason: String },
+    CalculateRenewalExpensesError { reason: String },
+    LedgerUnavailable { reason: String },
+    RenewalPaymentFailed { reason: String },
+    CertificateRenewalTooEarly { renewable_from: TimestampMillis },
+}
+
+impl From<Result<RenewContractCertificateResult, RenewContractCertificateError>>
+    for RenewContractCertificateResponse
+{
+    fn from(r: Result<RenewContractCertificateResult, RenewContractCertificateError>) -> Self {
+        match r {
+            Ok(result) => RenewContractCertificateResponse::Ok(Box::new(result)),
+            Err(error) => RenewContractCertificateResponse::Err(error),
+        }
+    }
+}
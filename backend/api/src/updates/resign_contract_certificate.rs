@@ -0,0 +1,34 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::DeploymentId;
+
+pub type Args = ResignContractCertificateArgs;
+pub type Response = ResignContractCertificateResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ResignContractCertificateArgs {
+    pub deployment_id: DeploymentId,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ResignContractCertificateResponse {
+    Ok,
+    Err(ResignContractCertificateError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ResignContractCertificateError {
+    DeploymentNotFound,
+    PermissionDenied,
+    DeploymentWrongState,
+}
+
+impl From<Result<(), ResignContractCertificateError>> for ResignContractCertificateResponse {
+    fn from(r: Result<(), ResignContractCertificateError>) -> Self {
+        match r {
+            Ok(_) => ResignContractCertificateResponse::Ok,
+            Err(error) => ResignContractCertificateResponse::Err(error),
+        }
+    }
+}
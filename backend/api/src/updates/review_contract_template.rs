@@ -0,0 +1,61 @@
+use candid::CandidType;
+use common_contract_api::{ContractTemplateId, WasmHash};
+use serde::Deserialize;
+
+use crate::types::ContractTemplateReviewStatus;
+
+pub type Args = ReviewContractTemplateArgs;
+pub type Response = ReviewContractTemplateResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ReviewContractTemplateArgs {
+    pub contract_template_id: ContractTemplateId,
+    pub action: ContractTemplateReviewAction,
+}
+
+/// `StartReview` moves the template to `UnderReview`, which is finished by `Approve` or `Reject`.
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ContractTemplateReviewAction {
+    StartReview,
+    Approve,
+    Reject {
+        reason: String,
+    },
+    AddNote {
+        note: String,
+    },
+    AddBuildAttestation {
+        wasm_hash: WasmHash,
+        build_url: String,
+        description: Option<String>,
+    },
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ReviewContractTemplateResponse {
+    Ok,
+    Err(ReviewContractTemplateError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ReviewContractTemplateError {
+    PermissionDenied,
+    ContractTemplateNotFound,
+    InvalidReviewStatus {
+        status: ContractTemplateReviewStatus,
+    },
+    ReviewTextIsEmpty,
+    ReviewTextIsTooLong {
+        max_length: usize,
+    },
+    AttestationWasmHashMismatch,
+}
+
+impl From<Result<(), ReviewContractTemplateError>> for ReviewContractTemplateResponse {
+    fn from(r: Result<(), ReviewContractTemplateError>) -> Self {
+        match r {
+            Ok(()) => ReviewContractTemplateResponse::Ok,
+            Err(error) => ReviewContractTemplateResponse::Err(error),
+        }
+    }
+}
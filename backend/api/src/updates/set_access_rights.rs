@@ -21,6 +21,7 @@ pub enum SetAccessRightsResponse {
 pub enum SetAccessRightsError {
     PermissionDenied,
     LoseControlDangerous,
+    ProposalRequired,
 }
 
 impl From<Result<(), SetAccessRightsError>> for SetAccessRightsResponse {
@@ -21,6 +21,7 @@ pub enum SetConfigResponse {
 pub enum SetConfigError {
     PermissionDenied,
     WrongConfig { reason: String },
+    ProposalRequired,
 }
 
 impl From<Result<(), SetConfigError>> for SetConfigResponse {
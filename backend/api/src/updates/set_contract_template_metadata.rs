@@ -0,0 +1,43 @@
+use candid::CandidType;
+use common_contract_api::ContractTemplateId;
+use serde::Deserialize;
+
+use crate::types::ContractTemplateMetadata;
+
+pub type Args = SetContractTemplateMetadataArgs;
+pub type Response = SetContractTemplateMetadataResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetContractTemplateMetadataArgs {
+    pub contract_template_id: ContractTemplateId,
+    pub metadata: ContractTemplateMetadata,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetContractTemplateMetadataResponse {
+    Ok,
+    Err(SetContractTemplateMetadataError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetContractTemplateMetadataError {
+    PermissionDenied,
+    ContractTemplateNotFound,
+    ContractTemplateNameAlreadyExists,
+    ContractNameIsTooLong { max_length: usize },
+    ContractShortDescriptionIsTooLong { max_length: usize },
+    ContractLongDescriptionIsTooLong { max_length: usize },
+    TooManyContractCategories { max_count: usize },
+    InvalidContractCategory { category: String },
+    TooManyContractTags { max_count: usize },
+    InvalidContractTag { tag: String },
+}
+
+impl From<Result<(), SetContractTemplateMetadataError>> for SetContractTemplateMetadataResponse {
+    fn from(r: Result<(), SetContractTemplateMetadataError>) -> Self {
+        match r {
+            Ok(_) => SetContractTemplateMetadataResponse::Ok,
+            Err(error) => SetContractTemplateMetadataResponse::Err(error),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use candid::CandidType;
+use common_canister_types::TimestampMillis;
 use common_contract_api::ContractTemplateId;
 use serde::Deserialize;
 
@@ -9,6 +10,12 @@ pub type Response = SetContractTemplateRetiredResponse;
 pub struct SetContractTemplateRetiredArgs {
     pub contract_template_id: ContractTemplateId,
     pub reason: Option<String>,
+    /// The retirement is scheduled, if the time is in the future.
+    #[serde(default)]
+    pub retire_at: Option<TimestampMillis>,
+    /// The contract template recommended to the deployers instead.
+    #[serde(default)]
+    pub replacement: Option<ContractTemplateId>,
 }
 
 #[derive(CandidType, Deserialize, Debug)]
@@ -21,6 +28,7 @@ pub enum SetContractTemplateRetiredResponse {
 pub enum SetContractTemplateRetiredError {
     PermissionDenied,
     ContractTemplateNotFound,
+    InvalidReplacement { reason: String },
 }
 
 impl From<Result<(), SetContractTemplateRetiredError>> for SetContractTemplateRetiredResponse {
@@ -0,0 +1,38 @@
+use candid::CandidType;
+use common_contract_api::ContractTemplateId;
+use serde::Deserialize;
+
+use crate::types::ContractTemplateVisibility;
+
+pub type Args = SetContractTemplateVisibilityArgs;
+pub type Response = SetContractTemplateVisibilityResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetContractTemplateVisibilityArgs {
+    pub contract_template_id: ContractTemplateId,
+    pub visibility: ContractTemplateVisibility,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetContractTemplateVisibilityResponse {
+    Ok,
+    Err(SetContractTemplateVisibilityError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetContractTemplateVisibilityError {
+    PermissionDenied,
+    ContractTemplateNotFound,
+    InvalidVisibility { reason: String },
+}
+
+impl From<Result<(), SetContractTemplateVisibilityError>>
+    for SetContractTemplateVisibilityResponse
+{
+    fn from(r: Result<(), SetContractTemplateVisibilityError>) -> Self {
+        match r {
+            Ok(_) => SetContractTemplateVisibilityResponse::Ok,
+            Err(error) => SetContractTemplateVisibilityResponse::Err(error),
+        }
+    }
+}
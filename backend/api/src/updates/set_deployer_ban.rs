@@ -0,0 +1,38 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::types::DeployerBanSettings;
+
+pub type Args = SetDeployerBanArgs;
+pub type Response = SetDeployerBanResponse;
+
+/// Bans the deployer or lifts the ban, if the settings are absent.
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetDeployerBanArgs {
+    pub deployer: Principal,
+    pub settings: Option<DeployerBanSettings>,
+    /// Blocks all existing contracts of the banned deployer, requires the `BlockContract` permission.
+    pub block_contracts: Option<bool>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetDeployerBanResponse {
+    Ok,
+    Err(SetDeployerBanError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetDeployerBanError {
+    PermissionDenied,
+    DeployerBanNotFound,
+    InvalidDeployerBan { reason: String },
+}
+
+impl From<Result<(), SetDeployerBanError>> for SetDeployerBanResponse {
+    fn from(r: Result<(), SetDeployerBanError>) -> Self {
+        match r {
+            Ok(()) => SetDeployerBanResponse::Ok,
+            Err(error) => SetDeployerBanResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::types::PublisherSettings;
+
+pub type Args = SetPublisherArgs;
+pub type Response = SetPublisherResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetPublisherArgs {
+    pub publisher: Principal,
+    pub settings: Option<PublisherSettings>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetPublisherResponse {
+    Ok,
+    Err(SetPublisherError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetPublisherError {
+    PermissionDenied,
+    PublisherNotFound,
+}
+
+impl From<Result<(), SetPublisherError>> for SetPublisherResponse {
+    fn from(r: Result<(), SetPublisherError>) -> Self {
+        match r {
+            Ok(()) => SetPublisherResponse::Ok,
+            Err(error) => SetPublisherResponse::Err(error),
+        }
+    }
+}
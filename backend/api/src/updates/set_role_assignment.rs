@@ -0,0 +1,40 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::types::RoleAssignmentSettings;
+
+pub type Args = SetRoleAssignmentArgs;
+pub type Response = SetRoleAssignmentResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetRoleAssignmentArgs {
+    pub caller: Principal,
+    pub role: String,
+    /// The role assignment is removed, if the settings are not set.
+    pub settings: Option<RoleAssignmentSettings>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetRoleAssignmentResponse {
+    Ok,
+    Err(SetRoleAssignmentError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetRoleAssignmentError {
+    PermissionDenied,
+    RoleNotFound,
+    RoleAssignmentNotFound,
+    InvalidRoleAssignment { reason: String },
+    LoseControlDangerous,
+    ProposalRequired,
+}
+
+impl From<Result<(), SetRoleAssignmentError>> for SetRoleAssignmentResponse {
+    fn from(r: Result<(), SetRoleAssignmentError>) -> Self {
+        match r {
+            Ok(_) => SetRoleAssignmentResponse::Ok,
+            Err(error) => SetRoleAssignmentResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::Role;
+
+pub type Args = SetRolesArgs;
+pub type Response = SetRolesResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetRolesArgs {
+    pub roles: Vec<Role>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetRolesResponse {
+    Ok,
+    Err(SetRolesError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetRolesError {
+    PermissionDenied,
+    InvalidRole { reason: String },
+    LoseControlDangerous,
+    ProposalRequired,
+}
+
+impl From<Result<(), SetRolesError>> for SetRolesResponse {
+    fn from(r: Result<(), SetRolesError>) -> Self {
+        match r {
+            Ok(_) => SetRolesResponse::Ok,
+            Err(error) => SetRolesResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use candid::CandidType;
+use common_canister_types::TokenE8s;
+use serde::Deserialize;
+
+use crate::types::SponsorshipSettings;
+
+pub type Args = SetSponsorshipArgs;
+pub type Response = SetSponsorshipResponse;
+
+/// Sets or removes the sponsorship of the caller.
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetSponsorshipArgs {
+    pub settings: Option<SponsorshipSettings>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetSponsorshipResponse {
+    Ok,
+    Err(SetSponsorshipError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetSponsorshipError {
+    CallerNotAuthorized,
+    SponsorshipNotFound,
+    SponsoredDeploymentsInProgress { reserved_amount: TokenE8s },
+    InvalidSponsorship { reason: String },
+}
+
+impl From<Result<(), SetSponsorshipError>> for SetSponsorshipResponse {
+    fn from(r: Result<(), SetSponsorshipError>) -> Self {
+        match r {
+            Ok(()) => SetSponsorshipResponse::Ok,
+            Err(error) => SetSponsorshipResponse::Err(error),
+        }
+    }
+}
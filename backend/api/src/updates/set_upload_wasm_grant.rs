@@ -21,6 +21,8 @@ pub enum SetUploadWasmGrantResponse {
 pub enum SetUploadWasmGrantError {
     PermissionDenied,
     WasmLengthIsTooBig,
+    UploadWasmGrantIsBusy,
+    WasmQuotaExceeded { available_length: usize },
 }
 
 impl From<Result<(), SetUploadWasmGrantError>> for SetUploadWasmGrantResponse {
@@ -0,0 +1,36 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::types::{VoucherCode, VoucherDefinition};
+
+pub type Args = SetVoucherArgs;
+pub type Response = SetVoucherResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct SetVoucherArgs {
+    pub code: VoucherCode,
+    pub definition: Option<VoucherDefinition>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetVoucherResponse {
+    Ok,
+    Err(SetVoucherError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum SetVoucherError {
+    PermissionDenied,
+    VoucherNotFound,
+    InvalidVoucherCode { reason: String },
+    InvalidVoucherDefinition { reason: String },
+}
+
+impl From<Result<(), SetVoucherError>> for SetVoucherResponse {
+    fn from(r: Result<(), SetVoucherError>) -> Self {
+        match r {
+            Ok(()) => SetVoucherResponse::Ok,
+            Err(error) => SetVoucherResponse::Err(error),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::types::DeploymentId;
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+pub type Args = UnblockContractsArgs;
+pub type Response = UnblockContractsResponse;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct UnblockContractsArgs {
+    pub deployment_ids: Vec<DeploymentId>,
+    pub contract_canister_ids: Vec<Principal>,
+    pub reason: String,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum UnblockContractsResponse {
+    Ok,
+    Err(UnblockContractsError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum UnblockContractsError {
+    PermissionDenied,
+}
+
+impl From<Result<(), UnblockContractsError>> for UnblockContractsResponse {
+    fn from(r: Result<(), UnblockContractsError>) -> Self {
+        match r {
+            Ok(_) => UnblockContractsResponse::Ok,
+            Err(error) => UnblockContractsResponse::Err(error),
+        }
+    }
+}
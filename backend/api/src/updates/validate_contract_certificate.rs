@@ -1,5 +1,5 @@
 use candid::{CandidType, Principal};
-use common_canister_types::TimestampMillis;
+use common_canister_types::{TimestampMillis, Timestamped};
 use common_contract_api::SignedContractCertificate;
 use serde::Deserialize;
 
@@ -15,6 +15,8 @@ pub enum ContractReference {
 #[derive(CandidType, Deserialize, Debug)]
 pub struct ValidateContractCertificateArgs {
     pub contract_reference: ContractReference,
+    /// Fails the validation of a blocked contract instead of reporting the block.
+    pub fail_if_blocked: Option<bool>,
 }
 
 #[derive(CandidType, Deserialize, Debug)]
@@ -27,6 +29,8 @@ pub enum ValidateContractCertificateResponse {
 pub struct ValidateContractCertificateResult {
     pub certificate: SignedContractCertificate,
     pub delay_to_expiration_millis: Option<TimestampMillis>,
+    pub contract_blocked: Option<Timestamped<String>>,
+    pub contract_template_blocked: Option<Timestamped<String>>,
 }
 
 #[derive(CandidType, Deserialize, Debug)]
@@ -36,6 +40,8 @@ pub enum ValidateContractCertificateError {
     CertificateUnavailable,
     ContractInfoUnavailable,
     CertificateWrong { reason: String },
+    ContractBlocked { blocked: Timestamped<String> },
+    ContractTemplateBlocked { blocked: Timestamped<String> },
 }
 
 impl From<Result<ValidateContractCertificateResult, ValidateContractCertificateError>>
@@ -5,31 +5,69 @@ fn main() {
     generate_query_candid_method!(common_canister_api, get_canister_metrics);
     generate_query_candid_method!(hub_canister_api, get_access_rights);
     generate_query_candid_method!(hub_canister_api, get_config);
+    generate_query_candid_method!(hub_canister_api, get_config_at_event);
     generate_query_candid_method!(hub_canister_api, get_contract_block_status);
     generate_query_candid_method!(hub_canister_api, get_contract_template);
+    generate_query_candid_method!(hub_canister_api, get_contract_template_earnings);
+    generate_query_candid_method!(hub_canister_api, get_contract_template_interface);
     generate_query_candid_method!(hub_canister_api, get_contract_templates);
+    generate_query_candid_method!(hub_canister_api, get_deployer_ban);
+    generate_query_candid_method!(hub_canister_api, get_deployment_rate_limits_usage);
     generate_query_candid_method!(hub_canister_api, get_hub_events);
+    generate_query_candid_method!(hub_canister_api, get_my_permissions);
+    generate_query_candid_method!(hub_canister_api, get_proposal);
+    generate_query_candid_method!(hub_canister_api, get_publisher);
+    generate_query_candid_method!(hub_canister_api, get_sponsorship);
+    generate_query_candid_method!(hub_canister_api, get_voucher);
     generate_query_candid_method!(hub_canister_api, get_contract_activation_code);
     generate_query_candid_method!(hub_canister_api, get_deployment_events);
     generate_query_candid_method!(hub_canister_api, get_deployment);
     generate_query_candid_method!(hub_canister_api, get_deployments);
     generate_query_candid_method!(hub_canister_api, obtain_contract_certificate);
+    generate_query_candid_method!(hub_canister_api, validate_add_contract_template);
+    generate_query_candid_method!(hub_canister_api, validate_block_contract_template);
+    generate_query_candid_method!(hub_canister_api, validate_set_config);
+    generate_query_candid_method!(hub_canister_api, validate_set_contract_template_retired);
 
     generate_update_candid_method!(common_canister_api, get_canister_status, None);
     generate_update_candid_method!(hub_canister_api, set_access_rights);
+    generate_update_candid_method!(hub_canister_api, set_roles);
+    generate_update_candid_method!(hub_canister_api, set_role_assignment);
     generate_update_candid_method!(hub_canister_api, set_config);
+    generate_update_candid_method!(hub_canister_api, patch_config);
     generate_update_candid_method!(hub_canister_api, set_upload_wasm_grant);
     generate_update_candid_method!(hub_canister_api, upload_wasm_chunk);
     generate_update_candid_method!(hub_canister_api, add_contract_template);
     generate_update_candid_method!(hub_canister_api, block_contracts);
+    generate_update_candid_method!(hub_canister_api, unblock_contracts);
     generate_update_candid_method!(hub_canister_api, block_contract_template);
+    generate_update_candid_method!(hub_canister_api, propose_operation);
+    generate_update_candid_method!(hub_canister_api, approve_proposal);
+    generate_update_candid_method!(hub_canister_api, set_contract_template_metadata);
     generate_update_candid_method!(hub_canister_api, set_contract_template_retired);
+    generate_update_candid_method!(hub_canister_api, set_contract_template_visibility);
+    generate_update_candid_method!(hub_canister_api, set_publisher);
+    generate_update_candid_method!(hub_canister_api, set_sponsorship);
+    generate_update_candid_method!(hub_canister_api, set_deployer_ban);
+    generate_update_candid_method!(hub_canister_api, set_voucher);
     generate_update_candid_method!(hub_canister_api, deploy_contract);
     generate_update_candid_method!(hub_canister_api, process_deployment);
     generate_update_candid_method!(hub_canister_api, cancel_deployment);
     generate_update_candid_method!(hub_canister_api, retry_generate_contract_certificate);
+    generate_update_candid_method!(hub_canister_api, review_contract_template);
     generate_update_candid_method!(hub_canister_api, initialize_contract_certificate);
     generate_update_candid_method!(hub_canister_api, validate_contract_certificate);
+    generate_update_candid_method!(hub_canister_api, propose_deployment_ownership_transfer);
+    generate_update_candid_method!(hub_canister_api, accept_deployment_ownership_transfer);
+    generate_update_candid_method!(hub_canister_api, renew_contract_certificate);
+    generate_update_candid_method!(hub_canister_api, resign_contract_certificate);
+    generate_update_candid_method!(hub_canister_api, execute_add_contract_template);
+    generate_update_candid_method!(hub_canister_api, execute_block_contract_template);
+    generate_update_candid_method!(hub_canister_api, execute_set_config);
+    generate_update_candid_method!(hub_canister_api, execute_set_contract_template_retired);
+
+    #[candid::candid_method(init)]
+    fn init(_args: Option<hub_canister_api::types::HubArgs>) {}
 
     candid::export_service!();
     std::print!("{}", __export_service());
@@ -1,5 +1,7 @@
+use std::cmp::min;
+
 use common_canister_types::TokenE8s;
-use hub_canister_api::types::DeploymentExpenses;
+use hub_canister_api::types::{DeploymentExpenses, PublisherFeeAmount, VoucherDiscount};
 
 pub struct DeploymentExpensesCalculator {
     deployment_expenses: DeploymentExpenses,
@@ -39,6 +41,73 @@ impl DeploymentExpensesCalculator {
             .and_then(|r| r.try_into().ok())
             .ok_or("failed to calculate reserved deployment expenses amount".to_owned())
     }
+
+    /// The publisher fee is charged on top of the deployment expenses, the share is calculated
+    /// from the deployment expenses amount without the buffer.
+    pub fn get_publisher_fee_amount(&self, expenses_amount: TokenE8s) -> Result<TokenE8s, String> {
+        let Some(publisher_fee) = &self.deployment_expenses.publisher_fee else {
+            return Ok(0);
+        };
+
+        match publisher_fee.amount {
+            PublisherFeeAmount::Flat { amount } => Ok(amount),
+            PublisherFeeAmount::Share { permyriad } => (expenses_amount as u128)
+                .checked_mul(permyriad as u128)
+                .and_then(|r| r.checked_div(10_000))
+                .and_then(|r| r.try_into().ok())
+                .ok_or("failed to calculate publisher fee amount".to_owned()),
+        }
+    }
+
+    /// The voucher discount never exceeds the expenses amount, the free voucher covers it whole.
+    pub fn get_voucher_discount_amount(
+        &self,
+        expenses_amount: TokenE8s,
+    ) -> Result<TokenE8s, String> {
+        let Some(voucher_discount) = &self.deployment_expenses.voucher_discount else {
+            return Ok(0);
+        };
+
+        match voucher_discount {
+            VoucherDiscount::Percentage { permyriad } => (expenses_amount as u128)
+                .checked_mul(*permyriad as u128)
+                .and_then(|r| r.checked_div(10_000))
+                .and_then(|r| r.try_into().ok())
+                .map(|r| min(r, expenses_amount))
+                .ok_or("failed to calculate voucher discount amount".to_owned()),
+            VoucherDiscount::Fixed { amount } => Ok(min(*amount, expenses_amount)),
+            VoucherDiscount::Free => Ok(expenses_amount),
+        }
+    }
+
+    /// Cycles the hub covers itself for the discounted part of the deployment expenses.
+    pub fn get_subsidized_cycles(
+        &self,
+        expenses_amount: TokenE8s,
+        discount_amount: TokenE8s,
+    ) -> Result<u128, String> {
+        let cycles = self
+            .deployment_expenses
+            .deployment_cycles_cost
+            .checked_add(self.deployment_expenses.contract_initial_cycles)
+            .ok_or("failed to calculate subsidized cycles".to_owned())?;
+
+        if matches!(
+            self.deployment_expenses.voucher_discount,
+            Some(VoucherDiscount::Free)
+        ) || (discount_amount > 0 && discount_amount >= expenses_amount)
+        {
+            return Ok(cycles);
+        }
+        if discount_amount == 0 {
+            return Ok(0);
+        }
+
+        cycles
+            .checked_mul(discount_amount as u128)
+            .and_then(|r| r.checked_div(expenses_amount as u128))
+            .ok_or("failed to calculate subsidized cycles".to_owned())
+    }
 }
 
 pub(crate) fn round_e8s_ceil(amount: u128, decimal_places: u8) -> Option<u128> {
@@ -10,7 +10,8 @@ use super::states::{
     create_contract_canister_over_cmc, create_contract_canister_over_management,
     generate_contract_certificate, install_contract_wasm, make_contract_self_controlled,
     notify_top_up_cmc, start_deployment_finalization, start_install_wasm,
-    transfer_deployer_funds_to_transit_account, transfer_top_up_funds_to_cmc,
+    transfer_deployer_funds_to_transit_account, transfer_publisher_fee,
+    transfer_top_up_funds_to_cmc, transfer_transit_funds_to_beneficiaries,
     transfer_transit_funds_to_external_service,
 };
 
@@ -56,9 +57,15 @@ pub(crate) fn get_processor<'a>(
             FinalizeDeploymentState::StartDeploymentFinalization => {
                 processor_toolkit!(start_deployment_finalization)
             }
+            FinalizeDeploymentState::TransferPublisherFee => {
+                processor_toolkit!(transfer_publisher_fee)
+            }
             FinalizeDeploymentState::TransferTransitFundsToExternalService => {
                 processor_toolkit!(transfer_transit_funds_to_external_service)
             }
+            FinalizeDeploymentState::TransferTransitFundsToBeneficiaries { .. } => {
+                processor_toolkit!(transfer_transit_funds_to_beneficiaries)
+            }
             FinalizeDeploymentState::Finalized => None,
         },
     }
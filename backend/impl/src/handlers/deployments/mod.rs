@@ -1,9 +1,12 @@
+use crate::model::DataModel;
 use crate::read_state;
 use crate::{components::Environment, model::deployments::Deployment};
 use candid::Principal;
+use common_canister_impl::components::cmc::interface::CallWrapperError;
 use common_canister_impl::stable_structures::CBor;
 use hub_canister_api::types::{
     DeploymentId, DeploymentInformation, DeploymentState, FinalizeDeploymentState,
+    IcpConversationRate, IcpXdrConversionRateStrategy,
 };
 use processor::need_process_deployment;
 
@@ -26,6 +29,13 @@ pub(crate) fn build_deployment_information(
         contract_template_id: deployment.contract_template_id,
         deployment_expenses: deployment.deployment_expenses.clone(),
         expenses_amount: deployment.expenses_amount,
+        publisher_fee_amount: deployment.publisher_fee_amount,
+        voucher_redemption: deployment.voucher_redemption.clone(),
+        sponsor: deployment.sponsor,
+        beneficiary: deployment.beneficiary,
+        pending_owner: deployment.pending_owner,
+        ownership_history: deployment.ownership_history.clone(),
+        certificate_renewals: deployment.certificate_renewals.clone(),
         approved_account: deployment.approved_account.clone(),
         subnet_type: deployment.subnet_type.clone(),
         state: deployment.state.value.clone(),
@@ -58,28 +68,66 @@ where
     F: Fn(&Environment, &DeploymentId, CBor<Deployment>) -> T,
 {
     read_state(|state| {
-        let mut last_deployment_id = None;
+        find_active_deployment_id(state.get_model(), deployer).and_then(|deployment_id| {
+            state
+                .get_model()
+                .get_deployments_storage()
+                .get_deployment(&deployment_id)
+                .map(|deployment| receiver(&state.get_env(), &deployment_id, deployment))
+        })
+    })
+}
 
-        let storage = state.get_model().get_deployments_storage();
-        storage.iterate_by_deployer(*deployer, true, |deployment_id| {
-            last_deployment_id = Some(deployment_id);
-            false
-        });
+/// Returns the last deployment of the owner, if it is not finalized yet.
+pub(crate) fn find_active_deployment_id(
+    model: &DataModel,
+    deployer: &Principal,
+) -> Option<DeploymentId> {
+    let mut last_deployment_id = None;
 
-        last_deployment_id.and_then(|last_id| {
-            storage.get_deployment(&last_id).and_then(|deployment| {
-                if matches!(
-                    deployment.state.value,
-                    DeploymentState::FinalizeDeployment {
-                        sub_state: FinalizeDeploymentState::Finalized,
-                        ..
-                    }
-                ) {
-                    None
-                } else {
-                    Some(receiver(&state.get_env(), &last_id, deployment))
+    let storage = model.get_deployments_storage();
+    // deployments received by an ownership transfer are finalized, skip them
+    storage.iterate_by_deployer(*deployer, true, |deployment_id| {
+        last_deployment_id = Some(deployment_id);
+        storage
+            .get_deployment(&deployment_id)
+            .is_some_and(|deployment| !deployment.ownership_history.is_empty())
+    });
+
+    last_deployment_id.filter(|last_id| {
+        storage.get_deployment(last_id).is_some_and(|deployment| {
+            !matches!(
+                deployment.state.value,
+                DeploymentState::FinalizeDeployment {
+                    sub_state: FinalizeDeploymentState::Finalized,
+                    ..
                 }
-            })
+            )
         })
     })
 }
+
+pub(crate) async fn get_icp_conversation_rate(
+    env: &Environment,
+    strategy: IcpXdrConversionRateStrategy,
+) -> Result<IcpConversationRate, String> {
+    match strategy {
+        IcpXdrConversionRateStrategy::Fixed {
+            xdr_permyriad_per_icp,
+        } => Ok(IcpConversationRate::Fixed {
+            xdr_permyriad_per_icp,
+        }),
+        IcpXdrConversionRateStrategy::CMC { cmc_canister } => env
+            .get_cmc()
+            .get_icp_xdr_conversion_rate(cmc_canister)
+            .await
+            .map(|rate| IcpConversationRate::CMC {
+                xdr_permyriad_per_icp: rate.xdr_permyriad_per_icp,
+                timestamp_seconds: rate.timestamp_seconds,
+            })
+            .map_err(|reason| match reason {
+                CallWrapperError::CallError { reason } => reason,
+                CallWrapperError::WrappedError { .. } => panic!(),
+            }),
+    }
+}
@@ -17,18 +17,7 @@ pub(crate) async fn process(
         build_contract_certificate(state, deployment)
     });
 
-    let labeled_certificates_hash = mutate_state(|state| {
-        state
-            .get_env()
-            .get_certification()
-            .add_contract_signature_to_signature_map(
-                state.get_model_mut().get_deployments_signature_map_mut(),
-                &certificate,
-            )
-    });
-
-    env.get_ic()
-        .set_certified_data(&labeled_certificates_hash[..]);
+    sign_contract_certificate(env, &certificate);
 
     log_info!(
         env,
@@ -44,6 +33,31 @@ pub(crate) async fn process(
     Ok(ProcessingTaskResult::Stop)
 }
 
+/// Adds the certificate signature to the signature map and certifies the map,
+/// the signed certificate can be obtained by a query call afterwards.
+pub(crate) fn sign_contract_certificate(env: &Environment, certificate: &ContractCertificate) {
+    let labeled_certificates_hash = mutate_state(|state| {
+        state
+            .get_env()
+            .get_certification()
+            .add_contract_signature_to_signature_map(
+                state.get_model_mut().get_deployments_signature_map_mut(),
+                certificate,
+            )
+    });
+
+    env.get_ic()
+        .set_certified_data(&labeled_certificates_hash[..]);
+}
+
+/// Signs the certificate of the deployed contract built from the current deployment data.
+pub(crate) fn reissue_contract_certificate(env: &Environment, deployment_id: &DeploymentId) {
+    let certificate = get_deployment_data(deployment_id, |state, deployment| {
+        build_contract_certificate(state, deployment)
+    });
+    sign_contract_certificate(env, &certificate);
+}
+
 pub(crate) fn build_contract_certificate(
     state: &CanisterState,
     deployment: CBor<Deployment>,
@@ -58,10 +72,10 @@ pub(crate) fn build_contract_certificate(
 
     ContractCertificate {
         hub_canister: state.get_env().get_ic().get_canister(),
-        deployer: deployment.deployer,
+        deployer: deployment.get_owner(),
         contract_canister: deployment.contract_canister.unwrap(),
         contract_wasm_hash: get_contract_wasm_hash(state, &deployment.contract_template_id),
-        expiration: deployment.created.saturating_add(certificate_duration),
+        expiration: deployment.get_certificate_expiration(certificate_duration),
         contract_template_id: deployment.contract_template_id,
     }
 }
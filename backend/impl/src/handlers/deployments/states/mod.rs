@@ -18,7 +18,9 @@ pub mod start_deployment;
 pub mod start_deployment_finalization;
 pub mod start_install_wasm;
 pub mod transfer_deployer_funds_to_transit_account;
+pub mod transfer_publisher_fee;
 pub mod transfer_top_up_funds_to_cmc;
+pub mod transfer_transit_funds_to_beneficiaries;
 pub mod transfer_transit_funds_to_external_service;
 pub mod upload_contract_wasm;
 
@@ -2,9 +2,9 @@ use hub_canister_api::types::{DeploymentId, DeploymentProcessingEvent};
 
 use crate::components::Environment;
 use crate::handlers::deployments::processor::ProcessingTaskResult;
-use crate::handlers::deployments::states::update_deployment;
-use crate::log_info;
+use crate::handlers::deployments::states::{get_deployment_data, update_deployment};
 use crate::model::deployments::DeploymentLock;
+use crate::{log_info, mutate_state};
 
 pub(crate) async fn process(
     env: &Environment,
@@ -19,5 +19,53 @@ pub(crate) async fn process(
         DeploymentProcessingEvent::StartCompleteDeployment,
     )?;
 
+    release_cancelled_deployment_reservations(env, deployment_id);
+
     Ok(ProcessingTaskResult::Continue)
 }
+
+/// The voucher redemption is given back if the contract canister was never created,
+/// the sponsor reservation is given back if the funds were not transferred yet,
+/// the contract template supply is given back always.
+fn release_cancelled_deployment_reservations(env: &Environment, deployment_id: &DeploymentId) {
+    let (cancelled, contract_template_id, contract_canister, voucher_redemption, sponsor) =
+        get_deployment_data(deployment_id, |_, deployment| {
+            (
+                deployment.is_cancelled(),
+                deployment.contract_template_id,
+                deployment.contract_canister,
+                deployment.voucher_redemption.clone(),
+                deployment.sponsor,
+            )
+        });
+
+    if !cancelled {
+        return;
+    }
+
+    mutate_state(|state| {
+        let model = state.get_model_mut();
+
+        model
+            .get_contract_templates_storage_mut()
+            .deployment_reservation_released(&contract_template_id);
+
+        if let Some(redemption) = voucher_redemption.filter(|_| contract_canister.is_none()) {
+            model
+                .get_vouchers_storage_mut()
+                .voucher_released(&redemption.code, redemption.subsidized_cycles);
+
+            log_info!(
+                env,
+                "Deployment '{deployment_id}': voucher '{}' released.",
+                redemption.code
+            );
+        }
+
+        if let Some(sponsor) = sponsor {
+            model
+                .get_sponsors_storage_mut()
+                .sponsored_deployment_released(sponsor, *deployment_id);
+        }
+    });
+}
@@ -4,8 +4,8 @@ use crate::handlers::deployments::states::{
     delay_processing, get_deployment_data, to_processing_error, update_deployment,
 };
 use crate::handlers::wallet::get_deployment_transit_canister_sub_account;
-use crate::log_info;
 use crate::model::deployments::DeploymentLock;
+use crate::{log_info, mutate_state};
 use common_canister_impl::components::icrc2_ledger::{to_icrc1_account, Account, TransferFromArgs};
 use common_canister_types::TokenE8s;
 use hub_canister_api::types::{DeploymentId, DeploymentProcessingEvent};
@@ -24,10 +24,11 @@ pub(crate) async fn process(
     let ledger = env.get_ledger();
     let ledger_fee = ledger.get_ledger_fee().await?;
 
-    let (deployer, approved_account, expenses_amount) =
+    // the allowance is granted to the sponsor subaccount for the sponsored deployment
+    let (payer, approved_account, expenses_amount) =
         get_deployment_data(deployment_id, |_, deployment| {
             (
-                deployment.deployer,
+                deployment.sponsor.unwrap_or(deployment.deployer),
                 deployment.approved_account.clone(),
                 deployment.expenses_amount,
             )
@@ -40,7 +41,8 @@ pub(crate) async fn process(
 
     log_info!(env, "Deployment {deployment_id}: transit balance: {transit_balance}, expenses amount: {expenses_amount}, ledger fee: {ledger_fee}.");
 
-    let transfer_amount = expenses_amount - ledger_fee;
+    // the fully discounted deployment has nothing to transfer
+    let transfer_amount = expenses_amount.saturating_sub(ledger_fee);
     if transit_balance >= transfer_amount {
         transit_success(env, deployment_id, lock, transit_balance, 0, None)?;
         return Ok(delay_processing());
@@ -58,7 +60,7 @@ pub(crate) async fn process(
     match env
         .get_icrc2_ledger()
         .icrc2_transfer_from(TransferFromArgs {
-            spender_subaccount: Some(principal_to_subaccount(deployer)),
+            spender_subaccount: Some(principal_to_subaccount(payer)),
             from: to_icrc1_account(&approved_account).unwrap(),
             to: Account {
                 owner: env.get_ic().get_canister(),
@@ -108,5 +110,16 @@ fn transit_success(
             transfer_amount,
             block_index,
         },
-    )
+    )?;
+
+    let sponsor = get_deployment_data(deployment_id, |_, deployment| deployment.sponsor);
+    if let Some(sponsor) = sponsor {
+        mutate_state(|state| {
+            state
+                .get_model_mut()
+                .get_sponsors_storage_mut()
+                .sponsored_deployment_charged(sponsor, *deployment_id);
+        });
+    }
+    Ok(())
 }
@@ -0,0 +1,128 @@
+use crate::components::Environment;
+use crate::handlers::deployments::processor::ProcessingTaskResult;
+use crate::handlers::deployments::states::{
+    delay_processing, get_deployment_data, to_processing_error,
+};
+use crate::handlers::wallet::get_deployment_transit_canister_sub_account;
+use crate::model::deployments::DeploymentLock;
+use crate::{log_info, mutate_state};
+use common_canister_impl::components::ledger::to_account_identifier;
+use common_canister_types::TokenE8s;
+use hub_canister_api::types::{DeploymentId, DeploymentProcessingEvent};
+use ic_ledger_types::{BlockIndex, Memo};
+
+use super::update_deployment;
+
+/// Move publisher fee from transit sub_account to contract template publisher account
+pub(crate) async fn process(
+    env: &Environment,
+    deployment_id: &DeploymentId,
+    lock: &DeploymentLock,
+) -> Result<ProcessingTaskResult, String> {
+    let ledger = env.get_ledger();
+
+    let (publisher_fee_amount, publisher_fee) =
+        get_deployment_data(deployment_id, |_, deployment| {
+            (
+                deployment.publisher_fee_amount,
+                deployment.deployment_expenses.publisher_fee.clone(),
+            )
+        });
+
+    let Some(publisher_fee) = publisher_fee else {
+        publisher_fee_success(deployment_id, lock, 0, None)?;
+        return Ok(delay_processing());
+    };
+
+    let transit_sub_account = get_deployment_transit_canister_sub_account(deployment_id);
+
+    // check funds on transit account
+
+    let transit_amount = ledger
+        .get_canister_subaccount_balance(&transit_sub_account)
+        .await?;
+
+    let ledger_fee = ledger.get_ledger_fee().await?;
+
+    let available_amount = publisher_fee_amount.min(transit_amount);
+    if available_amount <= ledger_fee {
+        log_info!(
+            env,
+            "Deployment {deployment_id}: publisher fee {publisher_fee_amount} does not cover ledger fee (transit balance: {transit_amount})."
+        );
+        publisher_fee_success(deployment_id, lock, 0, None)?;
+        return Ok(delay_processing());
+    }
+
+    let transfer_amount = available_amount - ledger_fee;
+
+    let receiver_account = to_account_identifier(&publisher_fee.payout_account)?;
+
+    // transfer
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': transfer {transfer_amount} publisher fee to account: {:?} ...",
+        receiver_account.to_hex()
+    );
+
+    let block_index = ledger
+        .transfer_from_canister(
+            Memo(*deployment_id),
+            transit_sub_account,
+            receiver_account,
+            transfer_amount,
+            ledger_fee,
+            None,
+        )
+        .await?
+        .map_err(to_processing_error)?;
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': publisher fee transferred at block index: {block_index:?}."
+    );
+
+    publisher_fee_success(deployment_id, lock, transfer_amount, Some(block_index))?;
+
+    Ok(delay_processing())
+}
+
+fn publisher_fee_success(
+    deployment_id: &DeploymentId,
+    lock: &DeploymentLock,
+    transfer_amount: TokenE8s,
+    block_index: Option<BlockIndex>,
+) -> Result<(), String> {
+    update_deployment(
+        deployment_id,
+        lock,
+        DeploymentProcessingEvent::PublisherFeeTransferred {
+            block_index,
+            transfer_amount,
+        },
+    )?;
+
+    if block_index.is_none() {
+        return Ok(());
+    }
+
+    mutate_state(|state| {
+        let time = state
+            .get_env()
+            .get_time()
+            .get_current_unix_epoch_time_millis();
+        let model = state.get_model_mut();
+        let contract_template_id = model
+            .get_deployments_storage_mut()
+            .get_deployment(deployment_id)
+            .unwrap()
+            .contract_template_id;
+
+        model
+            .get_contract_templates_storage_mut()
+            .publisher_fee_paid(&contract_template_id, time, transfer_amount);
+    });
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::components::Environment;
 use crate::handlers::deployments::processor::ProcessingTaskResult;
 use crate::handlers::deployments::states::{
-    delay_processing, to_processing_error, update_deployment,
+    delay_processing, get_deployment_data, to_processing_error, update_deployment,
 };
 use crate::handlers::wallet::get_deployment_transit_canister_sub_account;
 use crate::log_info;
@@ -46,7 +46,12 @@ pub(crate) async fn process(
 
     let ledger_fee = ledger.get_ledger_fee().await?;
 
-    if transit_amount <= ledger_fee {
+    // the publisher fee stays on the transit account until the deployment finalization
+    let publisher_fee_amount = get_deployment_data(deployment_id, |_, deployment| {
+        deployment.publisher_fee_amount
+    });
+
+    if transit_amount <= ledger_fee + publisher_fee_amount {
         return use_external_service_converting(
             env,
             deployment_id,
@@ -55,7 +60,7 @@ pub(crate) async fn process(
         );
     }
 
-    let transfer_amount = transit_amount - ledger_fee;
+    let transfer_amount = transit_amount - ledger_fee - publisher_fee_amount;
 
     let receiver_account = AccountIdentifier::new(
         &cmc_canister,
@@ -66,7 +71,7 @@ pub(crate) async fn process(
 
     log_info!(
         env,
-        "Deployment {deployment_id}: transferring {transit_amount} transit funds to account {:?} ...",
+        "Deployment {deployment_id}: transferring {transfer_amount} transit funds to account {:?} ...",
         receiver_account.to_hex()
     );
 
@@ -0,0 +1,144 @@
+use crate::components::Environment;
+use crate::handlers::deployments::processor::ProcessingTaskResult;
+use crate::handlers::deployments::states::transfer_transit_funds_to_external_service::contract_deployment_finalized;
+use crate::handlers::deployments::states::{
+    delay_processing, get_deployment_data, to_processing_error,
+};
+use crate::handlers::wallet::get_deployment_transit_canister_sub_account;
+use crate::log_info;
+use crate::model::deployments::DeploymentLock;
+use common_canister_impl::components::ledger::to_account_identifier;
+use common_canister_types::TokenE8s;
+use hub_canister_api::types::{
+    BeneficiaryPayout, DeploymentBeneficiary, DeploymentId, DeploymentProcessingEvent,
+    DeploymentState, FinalizeDeploymentState,
+};
+use ic_ledger_types::{BlockIndex, Memo};
+
+use super::update_deployment;
+
+/// Move the planned share of transit funds to the next beneficiary account
+pub(crate) async fn process(
+    env: &Environment,
+    deployment_id: &DeploymentId,
+    lock: &DeploymentLock,
+) -> Result<ProcessingTaskResult, String> {
+    let ledger = env.get_ledger();
+
+    let (beneficiary_index, payout, last_payout) =
+        get_deployment_data(deployment_id, |_, deployment| {
+            match &deployment.state.value {
+                DeploymentState::FinalizeDeployment {
+                    sub_state:
+                        FinalizeDeploymentState::TransferTransitFundsToBeneficiaries {
+                            payouts,
+                            paid_count,
+                        },
+                    ..
+                } => (
+                    *paid_count,
+                    payouts[*paid_count].clone(),
+                    *paid_count + 1 == payouts.len(),
+                ),
+                _ => panic!(),
+            }
+        });
+
+    let ledger_fee = ledger.get_ledger_fee().await?;
+
+    if payout.amount <= ledger_fee {
+        log_info!(
+            env,
+            "Deployment {deployment_id}: beneficiary {beneficiary_index} payout {} does not cover ledger fee.",
+            payout.amount
+        );
+        beneficiary_success(deployment_id, lock, beneficiary_index, 0, None, last_payout)?;
+        return Ok(ProcessingTaskResult::Continue);
+    }
+
+    let transfer_amount = payout.amount - ledger_fee;
+
+    let transit_sub_account = get_deployment_transit_canister_sub_account(deployment_id);
+    let receiver_account = to_account_identifier(&payout.account)?;
+
+    // transfer
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': transfer {transfer_amount} transit funds to beneficiary {beneficiary_index} account: {:?} ...",
+        receiver_account.to_hex()
+    );
+
+    let block_index = ledger
+        .transfer_from_canister(
+            Memo(*deployment_id),
+            transit_sub_account,
+            receiver_account,
+            transfer_amount,
+            ledger_fee,
+            None,
+        )
+        .await?
+        .map_err(to_processing_error)?;
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': beneficiary {beneficiary_index} funds transferred at block index: {block_index:?}."
+    );
+
+    beneficiary_success(
+        deployment_id,
+        lock,
+        beneficiary_index,
+        transfer_amount,
+        Some(block_index),
+        last_payout,
+    )?;
+
+    Ok(delay_processing())
+}
+
+/// Splits the transit funds between the beneficiaries by their shares.
+/// Shares are rounded down, the remainder goes to the first beneficiary.
+pub(crate) fn split_transit_funds(
+    amount: TokenE8s,
+    beneficiaries: &[DeploymentBeneficiary],
+) -> Vec<BeneficiaryPayout> {
+    let mut payouts: Vec<BeneficiaryPayout> = beneficiaries
+        .iter()
+        .map(|beneficiary| BeneficiaryPayout {
+            account: beneficiary.account.clone(),
+            amount: (amount as u128 * beneficiary.share_permyriad as u128 / 10_000) as TokenE8s,
+        })
+        .collect();
+
+    let distributed_amount: TokenE8s = payouts.iter().map(|payout| payout.amount).sum();
+    if let Some(payout) = payouts.first_mut() {
+        payout.amount += amount.saturating_sub(distributed_amount);
+    }
+    payouts
+}
+
+fn beneficiary_success(
+    deployment_id: &DeploymentId,
+    lock: &DeploymentLock,
+    beneficiary_index: usize,
+    transfer_amount: TokenE8s,
+    block_index: Option<BlockIndex>,
+    last_payout: bool,
+) -> Result<(), String> {
+    update_deployment(
+        deployment_id,
+        lock,
+        DeploymentProcessingEvent::TransitFundsToBeneficiaryTransferred {
+            beneficiary_index,
+            block_index,
+            transfer_amount,
+        },
+    )?;
+
+    if last_payout {
+        contract_deployment_finalized(deployment_id);
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::components::Environment;
 use crate::handlers::deployments::processor::ProcessingTaskResult;
+use crate::handlers::deployments::states::transfer_transit_funds_to_beneficiaries::split_transit_funds;
 use crate::handlers::deployments::states::{delay_processing, get_config, to_processing_error};
 use crate::handlers::wallet::get_deployment_transit_canister_sub_account;
 use crate::model::deployments::DeploymentLock;
@@ -11,6 +12,7 @@ use ic_ledger_types::{AccountIdentifier, BlockIndex, Memo};
 use super::update_deployment;
 
 /// Move funds from transit sub_account to emergency account
+/// or plan their distribution between the beneficiaries
 pub(crate) async fn process(
     env: &Environment,
     deployment_id: &DeploymentId,
@@ -26,6 +28,25 @@ pub(crate) async fn process(
         .get_canister_subaccount_balance(&transit_sub_account)
         .await?;
 
+    // the distribution is planned once, so the payouts survive a partial failure
+
+    let beneficiaries = get_config(|_, config| config.deployment_beneficiaries.clone());
+    if !beneficiaries.is_empty() {
+        let payouts = split_transit_funds(transit_amount, &beneficiaries);
+
+        log_info!(
+            env,
+            "Deployment '{deployment_id}': distribute {transit_amount} transit funds: {payouts:?}."
+        );
+
+        update_deployment(
+            deployment_id,
+            lock,
+            DeploymentProcessingEvent::TransitFundsDistributionStarted { payouts },
+        )?;
+        return Ok(ProcessingTaskResult::Continue);
+    }
+
     let ledger_fee = ledger.get_ledger_fee().await?;
 
     if transit_amount <= ledger_fee {
@@ -88,18 +109,27 @@ fn transit_success(
         },
     )?;
 
+    contract_deployment_finalized(deployment_id);
+
+    Ok(())
+}
+
+/// Counts the contract deployed, the reservation of the cancelled deployment
+/// is already released at the start of the finalization.
+pub(crate) fn contract_deployment_finalized(deployment_id: &DeploymentId) {
     mutate_state(|state| {
         let model = state.get_model_mut();
-        let contract_template_id = model
+        let deployment = model
             .get_deployments_storage_mut()
             .get_deployment(deployment_id)
-            .unwrap()
-            .contract_template_id;
+            .unwrap();
+        if deployment.is_cancelled() {
+            return;
+        }
+        let contract_template_id = deployment.contract_template_id;
 
         model
             .get_contract_templates_storage_mut()
             .contract_deployed(&contract_template_id);
     });
-
-    Ok(())
 }
@@ -0,0 +1,132 @@
+use candid::Principal;
+use hub_canister_api::types::{
+    GovernancePolicy, GovernedOperation, GovernedOperationType, Permission,
+};
+
+use crate::{
+    read_state,
+    updates::{
+        block_contract_template::apply_contract_template_block,
+        set_access_rights::apply_access_rights, set_config::apply_config,
+        set_role_assignment::apply_role_assignment, set_roles::apply_roles,
+    },
+};
+
+const MAX_APPROVERS: usize = 20;
+
+pub(crate) fn get_governance_policy(
+    operation_type: GovernedOperationType,
+) -> Option<GovernancePolicy> {
+    read_state(|state| {
+        state
+            .get_model()
+            .get_config_storage()
+            .get_config()
+            .governance_policies
+            .iter()
+            .find(|policy| policy.operation_type == operation_type)
+            .cloned()
+    })
+}
+
+/// The direct calls of the governed operation are rejected.
+pub(crate) fn is_operation_governed(operation_type: GovernedOperationType) -> bool {
+    get_governance_policy(operation_type).is_some()
+}
+
+/// The DAO proposals are executed by the configured governance principal only.
+pub(crate) fn is_caller_governance_principal() -> bool {
+    read_state(|state| {
+        let caller = state.get_env().get_ic().get_caller();
+        state
+            .get_model()
+            .get_config_storage()
+            .get_config()
+            .governance_principal
+            == Some(caller)
+    })
+}
+
+/// The proposer of the governed operation must have the permission of the operation.
+pub(crate) fn get_operation_permission(operation_type: GovernedOperationType) -> Permission {
+    match operation_type {
+        GovernedOperationType::SetConfig => Permission::SetConfig,
+        GovernedOperationType::SetAccessRights => Permission::SetAccessRights,
+        GovernedOperationType::BlockContractTemplate => Permission::BlockContractTemplate,
+    }
+}
+
+pub(crate) fn check_governance_policies(policies: &[GovernancePolicy]) -> Result<(), String> {
+    for (index, policy) in policies.iter().enumerate() {
+        if policies[..index]
+            .iter()
+            .any(|other| other.operation_type == policy.operation_type)
+        {
+            return Err(format!(
+                "duplicate governance policy of {:?}",
+                policy.operation_type
+            ));
+        }
+
+        if policy.approvers.is_empty() || policy.approvers.len() > MAX_APPROVERS {
+            return Err(format!(
+                "governance approvers count must be from 1 to {MAX_APPROVERS}"
+            ));
+        }
+
+        for (index, approver) in policy.approvers.iter().enumerate() {
+            if approver == &Principal::anonymous() {
+                return Err("anonymous principal can not be a governance approver".to_owned());
+            }
+            if policy.approvers[..index].contains(approver) {
+                return Err(format!(
+                    "duplicate governance approver '{}'",
+                    approver.to_text()
+                ));
+            }
+        }
+
+        if policy.required_approvals == 0
+            || policy.required_approvals as usize > policy.approvers.len()
+        {
+            return Err(format!(
+                "governance required approvals must be from 1 to {}",
+                policy.approvers.len()
+            ));
+        }
+
+        if policy.approval_window == 0 {
+            return Err("governance approval window must be positive".to_owned());
+        }
+    }
+    Ok(())
+}
+
+/// Executes the approved operation on behalf of the proposer.
+pub(crate) fn execute_governed_operation(
+    proposer: Principal,
+    operation: GovernedOperation,
+) -> Result<(), String> {
+    match operation {
+        GovernedOperation::SetConfig { config } => {
+            apply_config(proposer, *config).map_err(|error| format!("{error:?}"))
+        }
+        GovernedOperation::SetAccessRights { access_rights } => {
+            apply_access_rights(proposer, access_rights).map_err(|error| format!("{error:?}"))
+        }
+        GovernedOperation::SetRoles { roles } => {
+            apply_roles(proposer, roles).map_err(|error| format!("{error:?}"))
+        }
+        GovernedOperation::SetRoleAssignment {
+            caller,
+            role,
+            settings,
+        } => apply_role_assignment(proposer, caller, role, settings)
+            .map_err(|error| format!("{error:?}")),
+        GovernedOperation::BlockContractTemplate {
+            contract_template_id,
+            reason,
+        } => apply_contract_template_block(proposer, contract_template_id, reason)
+            .map_err(|error| format!("{error:?}")),
+    }
+}
@@ -1,3 +1,8 @@
 pub mod deployments;
+pub mod governance;
+pub mod publishers;
+pub mod rate_limits;
+pub mod sponsors;
 pub mod templates;
+pub mod vouchers;
 pub mod wallet;
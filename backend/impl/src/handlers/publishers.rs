@@ -0,0 +1,98 @@
+use candid::Principal;
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::types::{PublisherContractTemplate, PublisherInformation};
+
+use crate::{
+    handlers::templates::is_contract_template_listed_for,
+    model::{publishers::PublisherModel, DataModel},
+    read_state,
+};
+
+/// Publisher manages the contract templates, which it has registered,
+/// without the global access rights. Blocking remains available to the admins only.
+pub(crate) fn is_caller_contract_template_publisher(
+    contract_template_id: &ContractTemplateId,
+) -> bool {
+    read_state(|state| {
+        let caller = state.get_env().get_ic().get_caller();
+        let model = state.get_model();
+        model
+            .get_publishers_storage()
+            .get_publisher(&caller)
+            .is_some()
+            && model
+                .get_contract_templates_storage()
+                .get_contract_template(contract_template_id)
+                .is_some_and(|contract_template| contract_template.registrar == caller)
+    })
+}
+
+/// Counts the contract templates and the wasm bytes registered by the principal.
+pub(crate) fn calculate_publisher_usage(
+    model: &DataModel,
+    publisher: &Principal,
+) -> (usize, usize) {
+    let storage = model.get_contract_templates_storage();
+    storage
+        .get_iter()
+        .filter(|entry| entry.value().registrar == *publisher)
+        .fold((0, 0), |(count, wasm_length), entry| {
+            let length = storage
+                .get_contract_template_wasm(entry.key())
+                .map(|wasm| wasm.len())
+                .unwrap_or_default();
+            (count + 1, wasm_length + length)
+        })
+}
+
+/// The contract templates and their deployments are listed as the caller sees them
+/// in the contract templates list.
+pub(crate) fn build_publisher_information(
+    model: &DataModel,
+    publisher: &Principal,
+    publisher_model: &PublisherModel,
+    caller: &Principal,
+    is_admin: bool,
+    time: TimestampMillis,
+) -> PublisherInformation {
+    let contract_templates: Vec<_> = model
+        .get_contract_templates_storage()
+        .get_iter()
+        .filter(|entry| {
+            let contract_template = entry.value();
+            contract_template.registrar == *publisher
+                && is_contract_template_listed_for(&contract_template, caller, is_admin)
+        })
+        .map(|entry| {
+            let contract_template = entry.value();
+            PublisherContractTemplate {
+                contract_template_id: *entry.key(),
+                name: contract_template.definition.name.clone(),
+                registered: contract_template.registered,
+                blocked: contract_template.blocked.is_some(),
+                retired: contract_template.is_retired(time),
+                pending_retirement: contract_template
+                    .retired
+                    .as_ref()
+                    .filter(|_| !contract_template.is_retired(time))
+                    .map(|retired| retired.timestamp),
+                review_status: contract_template.review.status.clone(),
+                deployments_count: contract_template.deployments_count,
+            }
+        })
+        .collect();
+
+    PublisherInformation {
+        publisher: *publisher,
+        registered: publisher_model.registered,
+        settings: publisher_model.settings.clone(),
+        contract_templates_count: publisher_model.contract_templates_count,
+        total_wasm_length: publisher_model.total_wasm_length,
+        deployments_count: contract_templates
+            .iter()
+            .map(|contract_template| contract_template.deployments_count)
+            .sum(),
+        contract_templates,
+    }
+}
@@ -0,0 +1,147 @@
+use candid::Principal;
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    get_deployment_rate_limits_usage::{
+        DeploymentRateLimitUsage, GetDeploymentRateLimitsUsageResult,
+    },
+    types::{DeploymentId, DeploymentRateLimit, DeploymentRateLimits},
+};
+
+use crate::model::DataModel;
+
+pub(crate) fn check_deployment_rate_limit(limit: &DeploymentRateLimit) -> Result<(), String> {
+    if limit.window == 0 {
+        return Err("rate limit window is zero".to_owned());
+    }
+    if limit.max_deployments == 0 {
+        return Err("rate limit max deployments is zero".to_owned());
+    }
+    Ok(())
+}
+
+pub(crate) fn build_deployment_rate_limits_usage(
+    model: &DataModel,
+    deployer: Principal,
+    contract_template_id: Option<ContractTemplateId>,
+    time: TimestampMillis,
+) -> GetDeploymentRateLimitsUsageResult {
+    let limits = &model
+        .get_config_storage()
+        .get_config()
+        .deployment_rate_limits;
+    let storage = model.get_deployment_rate_limits_storage();
+
+    GetDeploymentRateLimitsUsageResult {
+        per_deployer: limits.per_deployer.as_ref().map(|limit| {
+            build_usage(
+                limit,
+                &storage.get_deployer_starts(deployer, get_window_start(limit, time)),
+            )
+        }),
+        per_contract_template: limits
+            .per_contract_template
+            .as_ref()
+            .zip(contract_template_id)
+            .map(|(limit, contract_template_id)| {
+                build_usage(
+                    limit,
+                    &storage.get_contract_template_starts(
+                        contract_template_id,
+                        get_window_start(limit, time),
+                    ),
+                )
+            }),
+        hub: limits.hub.as_ref().map(|limit| {
+            build_usage(
+                limit,
+                &storage.get_hub_starts(get_window_start(limit, time)),
+            )
+        }),
+    }
+}
+
+/// Rejects the deployment, if any of the configured limits is reached.
+pub(crate) fn check_deployment_rate_limits(
+    model: &DataModel,
+    deployer: Principal,
+    contract_template_id: ContractTemplateId,
+    time: TimestampMillis,
+) -> Result<(), DeployContractError> {
+    let usage =
+        build_deployment_rate_limits_usage(model, deployer, Some(contract_template_id), time);
+
+    let retry_after = [usage.per_deployer, usage.per_contract_template, usage.hub]
+        .into_iter()
+        .flatten()
+        .filter_map(|usage| usage.retry_after)
+        .max();
+
+    match retry_after {
+        Some(retry_after) => Err(DeployContractError::RateLimited { retry_after }),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn register_deployment_start(
+    model: &mut DataModel,
+    deployer: Principal,
+    contract_template_id: ContractTemplateId,
+    deployment_id: DeploymentId,
+    time: TimestampMillis,
+) {
+    let retention = get_retention(
+        &model
+            .get_config_storage()
+            .get_config()
+            .deployment_rate_limits,
+    );
+
+    model
+        .get_deployment_rate_limits_storage_mut()
+        .deployment_started(
+            time,
+            deployer,
+            contract_template_id,
+            deployment_id,
+            retention,
+        );
+}
+
+/// Starts older than the longest window are not needed by any of the limits.
+fn get_retention(limits: &DeploymentRateLimits) -> TimestampMillis {
+    [
+        &limits.per_deployer,
+        &limits.per_contract_template,
+        &limits.hub,
+    ]
+    .into_iter()
+    .flatten()
+    .map(|limit| limit.window)
+    .max()
+    .unwrap_or_default()
+}
+
+fn get_window_start(limit: &DeploymentRateLimit, time: TimestampMillis) -> TimestampMillis {
+    (time + 1).saturating_sub(limit.window)
+}
+
+fn build_usage(
+    limit: &DeploymentRateLimit,
+    starts: &[TimestampMillis],
+) -> DeploymentRateLimitUsage {
+    let deployments_count = starts.len() as u64;
+
+    // the next deployment is allowed, when enough of the oldest starts leave the window
+    let retry_after = deployments_count
+        .checked_sub(limit.max_deployments)
+        .and_then(|excess| starts.get(excess as usize))
+        .map(|start| start + limit.window);
+
+    DeploymentRateLimitUsage {
+        limit: limit.clone(),
+        deployments_count,
+        retry_after,
+    }
+}
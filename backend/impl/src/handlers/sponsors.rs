@@ -0,0 +1,80 @@
+use candid::Principal;
+use common_canister_impl::components::icrc2_ledger::to_icrc1_account;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    types::{SponsorshipInformation, SponsorshipSettings},
+};
+
+use crate::model::{sponsors::SponsorModel, DataModel};
+
+const MAX_SPONSORED_ENTRIES: usize = 1_000;
+
+pub(crate) fn build_sponsorship_information(
+    sponsor: &Principal,
+    model: &SponsorModel,
+) -> SponsorshipInformation {
+    SponsorshipInformation {
+        sponsor: *sponsor,
+        registered: model.registered,
+        settings: model.settings.clone(),
+        reserved_amount: model.reserved_amount,
+        charged_amount: model.charged_amount,
+        deployments_count: model.deployments_count,
+    }
+}
+
+pub(crate) fn check_sponsorship_settings(settings: &SponsorshipSettings) -> Result<(), String> {
+    if settings.budget == 0 {
+        return Err("budget is zero".to_owned());
+    }
+
+    // the funds are transferred over icrc2, so the account must be convertible
+    to_icrc1_account(&settings.approved_account)
+        .map_err(|reason| format!("invalid approved account: {reason}"))?;
+
+    match (&settings.deployers, &settings.contract_template_ids) {
+        (None, None) => Err("neither deployers nor contract templates are listed".to_owned()),
+        (Some(deployers), _) if deployers.is_empty() => Err("deployer list is empty".to_owned()),
+        (_, Some(ids)) if ids.is_empty() => Err("contract template list is empty".to_owned()),
+        (Some(deployers), _) if deployers.len() > MAX_SPONSORED_ENTRIES => Err(format!(
+            "deployer list exceeds {MAX_SPONSORED_ENTRIES} entries"
+        )),
+        (_, Some(ids)) if ids.len() > MAX_SPONSORED_ENTRIES => Err(format!(
+            "contract template list exceeds {MAX_SPONSORED_ENTRIES} entries"
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that the sponsor pays for the deployer and the contract template.
+pub(crate) fn check_sponsorship(
+    model: &DataModel,
+    sponsor: &Principal,
+    deployer: &Principal,
+    contract_template_id: &ContractTemplateId,
+) -> Result<SponsorModel, DeployContractError> {
+    let sponsorship = model
+        .get_sponsors_storage()
+        .get_sponsorship(sponsor)
+        .ok_or(DeployContractError::SponsorshipNotFound)?;
+    let settings = &sponsorship.settings;
+
+    let deployer_listed = settings
+        .deployers
+        .as_ref()
+        .is_none_or(|deployers| deployers.contains(deployer));
+    let contract_template_listed = settings
+        .contract_template_ids
+        .as_ref()
+        .is_none_or(|ids| ids.contains(contract_template_id));
+    if !deployer_listed || !contract_template_listed {
+        return Err(DeployContractError::SponsorshipNotApplicable);
+    }
+
+    if sponsorship.get_available_budget() == 0 {
+        return Err(DeployContractError::SponsorshipBudgetExhausted);
+    }
+
+    Ok(sponsorship.to_owned())
+}
@@ -1,19 +1,250 @@
+use candid::Principal;
+use common_canister_impl::components::ledger::to_account_identifier;
+use common_canister_types::TimestampMillis;
 use common_contract_api::ContractTemplateId;
-use hub_canister_api::types::ContractTemplateInformation;
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    types::{
+        ContractTemplateDefinition, ContractTemplateInformation, ContractTemplateInterface,
+        ContractTemplateInterfaceSummary, ContractTemplateVisibility, PublisherFee,
+        PublisherFeeAmount,
+    },
+};
+use wasm::{get_canister_methods, CANDID_ARGS_SECTION, CANDID_SERVICE_SECTION};
 
-use crate::model::templates::ContractTemplateModel;
+use crate::model::templates::{ContractTemplateModel, ContractTemplateWasmMetadata};
+use crate::model::DataModel;
+
+pub mod wasm;
+
+const MAX_CONTRACT_TEMPLATE_LABELS: usize = 10;
+const MAX_CONTRACT_TEMPLATE_LABEL_LENGTH: usize = 32;
+const MAX_CONTRACT_TEMPLATE_ALLOWED_DEPLOYERS: usize = 1_000;
 
 pub(crate) fn build_contract_template_information(
     id: &ContractTemplateId,
     model: &ContractTemplateModel,
+    interface_summary: Option<ContractTemplateInterfaceSummary>,
+    time: TimestampMillis,
 ) -> ContractTemplateInformation {
+    let (retired, pending_retirement) = if model.is_retired(time) {
+        (model.retired.clone(), None)
+    } else {
+        (None, model.retired.clone())
+    };
+
     ContractTemplateInformation {
         contract_template_id: *id,
         registrar: model.registrar,
         registered: model.registered,
         definition: model.definition.clone(),
         blocked: model.blocked.clone(),
-        retired: model.retired.clone(),
+        retired,
+        pending_retirement,
+        replacement: model.replacement,
         deployments_count: model.deployments_count,
+        reserved_deployments_count: model.reserved_deployments_count,
+        remaining_supply: get_contract_template_remaining_supply(model),
+        interface_summary,
+        review: model.review.clone(),
+        visibility: model.visibility.clone(),
+    }
+}
+
+/// The in-flight deployments reserve the supply of the limited edition.
+pub(crate) fn get_contract_template_remaining_supply(
+    model: &ContractTemplateModel,
+) -> Option<usize> {
+    model.definition.max_deployments.map(|max_deployments| {
+        max_deployments.saturating_sub(model.deployments_count + model.reserved_deployments_count)
+    })
+}
+
+/// Rejects the deployment, if the total or the deployer's supply of the contract template
+/// is exhausted. The deployer's supply counts the deployments it paid for, whoever owns
+/// them now. The cancelled deployments of the deployer are not counted.
+pub(crate) fn check_contract_template_supply(
+    model: &DataModel,
+    contract_template: &ContractTemplateModel,
+    contract_template_id: ContractTemplateId,
+    deployer: Principal,
+) -> Result<(), DeployContractError> {
+    if get_contract_template_remaining_supply(contract_template) == Some(0) {
+        return Err(DeployContractError::ContractTemplateSupplyExhausted);
+    }
+
+    let Some(max_deployments) = contract_template.definition.max_deployments_per_deployer else {
+        return Ok(());
+    };
+
+    let deployments_storage = model.get_deployments_storage();
+    let mut deployments_count = 0;
+    // the deployer index is keyed by the owner, so the deployments are filtered by the payer
+    deployments_storage.iterate_by_contract_template(
+        contract_template_id,
+        false,
+        |deployment_id| {
+            if deployments_storage
+                .get_deployment(&deployment_id)
+                .is_some_and(|deployment| {
+                    deployment.deployer == deployer && !deployment.is_cancelled()
+                })
+            {
+                deployments_count += 1;
+            }
+            deployments_count < max_deployments
+        },
+    );
+
+    if deployments_count >= max_deployments {
+        return Err(DeployContractError::DeployerSupplyExhausted { max_deployments });
+    }
+    Ok(())
+}
+
+/// Restricted contract templates are deployable by the allowed deployers and the publisher only.
+pub(crate) fn is_contract_template_deployable_by(
+    model: &ContractTemplateModel,
+    caller: &Principal,
+) -> bool {
+    match &model.visibility {
+        ContractTemplateVisibility::Public | ContractTemplateVisibility::Unlisted => true,
+        ContractTemplateVisibility::Restricted { allowed_deployers } => {
+            model.registrar == *caller || allowed_deployers.contains(caller)
+        }
+    }
+}
+
+/// The admins and the publisher see all the contract templates in the list.
+pub(crate) fn is_contract_template_listed_for(
+    model: &ContractTemplateModel,
+    caller: &Principal,
+    is_admin: bool,
+) -> bool {
+    if is_admin || model.registrar == *caller {
+        return true;
+    }
+    match &model.visibility {
+        ContractTemplateVisibility::Public => true,
+        ContractTemplateVisibility::Unlisted => false,
+        ContractTemplateVisibility::Restricted { allowed_deployers } => {
+            allowed_deployers.contains(caller)
+        }
+    }
+}
+
+pub(crate) fn check_contract_template_visibility(
+    visibility: &ContractTemplateVisibility,
+) -> Result<(), String> {
+    if let ContractTemplateVisibility::Restricted { allowed_deployers } = visibility {
+        if allowed_deployers.len() > MAX_CONTRACT_TEMPLATE_ALLOWED_DEPLOYERS {
+            return Err(format!(
+                "allowed deployers count exceeds {MAX_CONTRACT_TEMPLATE_ALLOWED_DEPLOYERS}"
+            ));
+        }
+        if allowed_deployers.contains(&Principal::anonymous()) {
+            return Err("anonymous principal can not be allowed".to_owned());
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn build_contract_template_interface(
+    wasm_metadata: &ContractTemplateWasmMetadata,
+) -> ContractTemplateInterface {
+    let mut candid_service = None;
+    let mut candid_args = None;
+    let mut metadata = Vec::new();
+
+    // private sections are available to the contract controllers only
+    for section in wasm_metadata.custom_sections.iter().filter(|s| s.public) {
+        let Ok(content) = String::from_utf8(section.content.clone()) else {
+            continue;
+        };
+        match section.name.as_str() {
+            CANDID_SERVICE_SECTION => candid_service = Some(content),
+            CANDID_ARGS_SECTION => candid_args = Some(content),
+            _ => metadata.push((section.name.clone(), content)),
+        }
     }
+
+    ContractTemplateInterface {
+        candid_service,
+        candid_args,
+        methods: get_canister_methods(&wasm_metadata.exported_methods),
+        metadata,
+    }
+}
+
+pub(crate) fn build_contract_template_interface_summary(
+    wasm_metadata: &ContractTemplateWasmMetadata,
+) -> ContractTemplateInterfaceSummary {
+    ContractTemplateInterfaceSummary {
+        methods: get_canister_methods(&wasm_metadata.exported_methods),
+        candid_service_available: wasm_metadata
+            .custom_sections
+            .iter()
+            .any(|s| s.public && s.name == CANDID_SERVICE_SECTION),
+    }
+}
+
+/// Checks the contract template categories or tags: each label is a unique non-empty
+/// word of lowercase latin letters, digits and dashes.
+pub(crate) fn check_contract_template_labels<E>(
+    labels: &[String],
+    too_many_labels: impl FnOnce(usize) -> E,
+    invalid_label: impl FnOnce(String) -> E,
+) -> Result<(), E> {
+    if labels.len() > MAX_CONTRACT_TEMPLATE_LABELS {
+        return Err(too_many_labels(MAX_CONTRACT_TEMPLATE_LABELS));
+    }
+
+    for (index, label) in labels.iter().enumerate() {
+        let valid = !label.is_empty()
+            && label.len() <= MAX_CONTRACT_TEMPLATE_LABEL_LENGTH
+            && label
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && !labels[..index].contains(label);
+        if !valid {
+            return Err(invalid_label(label.clone()));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn check_contract_template_deployment_limits(
+    definition: &ContractTemplateDefinition,
+) -> Result<(), String> {
+    match (
+        definition.max_deployments,
+        definition.max_deployments_per_deployer,
+    ) {
+        (Some(0), _) => Err("max deployments is zero".to_owned()),
+        (_, Some(0)) => Err("max deployments per deployer is zero".to_owned()),
+        (Some(max_deployments), Some(max_deployments_per_deployer))
+            if max_deployments_per_deployer > max_deployments =>
+        {
+            Err("max deployments per deployer exceeds max deployments".to_owned())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks the publisher fee: the amount is positive, the share does not exceed
+/// the deployment expenses and the payout account is valid.
+pub(crate) fn check_publisher_fee(publisher_fee: &PublisherFee) -> Result<(), String> {
+    match publisher_fee.amount {
+        PublisherFeeAmount::Flat { amount: 0 } | PublisherFeeAmount::Share { permyriad: 0 } => {
+            return Err("fee amount is zero".to_owned());
+        }
+        PublisherFeeAmount::Share { permyriad } if permyriad > 10_000 => {
+            return Err(format!("fee share {permyriad} exceeds 10000 permyriad"));
+        }
+        _ => {}
+    }
+
+    to_account_identifier(&publisher_fee.payout_account)
+        .map(|_| ())
+        .map_err(|reason| format!("invalid payout account: {reason}"))
 }
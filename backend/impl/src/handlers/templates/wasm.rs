@@ -0,0 +1,227 @@
+use std::{borrow::Cow, collections::BTreeSet, io::Read};
+
+use flate2::read::GzDecoder;
+use hub_canister_api::{
+    add_contract_template::AddContractTemplateError,
+    types::{CanisterMethod, CanisterMethodType, CanisterSettings},
+};
+use wasmparser::{Encoding, ExternalKind, Parser, Payload};
+
+use crate::model::templates::{ContractTemplateWasmMetadata, WasmCustomSection};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const MAX_DECOMPRESSED_WASM_LENGTH: u64 = 100 * 1024 * 1024;
+const WASM_DEFAULT_PAGE_SIZE_LOG2: u32 = 16;
+
+const ICP_SECTION_PREFIX: &str = "icp:";
+const ICP_PUBLIC_SECTION_PREFIX: &str = "icp:public ";
+const ICP_PRIVATE_SECTION_PREFIX: &str = "icp:private ";
+pub(crate) const CANDID_SERVICE_SECTION: &str = "candid:service";
+pub(crate) const CANDID_ARGS_SECTION: &str = "candid:args";
+const TEXT_SECTIONS: [&str; 2] = [CANDID_SERVICE_SECTION, CANDID_ARGS_SECTION];
+
+const CANISTER_EXPORT_PREFIX: &str = "canister_";
+const CANISTER_INIT_EXPORT: &str = "canister_init";
+const CANISTER_METHOD_EXPORT_PREFIXES: [(&str, CanisterMethodType); 3] = [
+    ("canister_query ", CanisterMethodType::Query),
+    (
+        "canister_composite_query ",
+        CanisterMethodType::CompositeQuery,
+    ),
+    ("canister_update ", CanisterMethodType::Update),
+];
+
+/// Contract canister methods the hub calls after the deployment.
+const REQUIRED_CONTRACT_METHODS: [&str; 1] = ["get_contract_certificate"];
+
+/// Parses the uploaded contract wasm (plain or gzipped) and checks that it can be
+/// installed by the hub with the given canister settings.
+/// Returns the exported canister methods and the canister metadata sections.
+pub(crate) fn inspect_contract_wasm(
+    wasm: &[u8],
+    contract_canister_settings: &CanisterSettings,
+) -> Result<ContractTemplateWasmMetadata, AddContractTemplateError> {
+    let compressed = wasm.starts_with(&GZIP_MAGIC);
+    let module = if compressed {
+        Cow::Owned(decompress_wasm(wasm)?)
+    } else {
+        Cow::Borrowed(wasm)
+    };
+
+    wasmparser::validate(&module).map_err(|error| to_invalid_wasm_module(error.to_string()))?;
+
+    let mut exports = BTreeSet::new();
+    let mut initial_memory: u128 = 0;
+    let mut custom_sections: Vec<WasmCustomSection> = Vec::new();
+
+    for payload in Parser::new(0).parse_all(&module) {
+        match payload.map_err(|error| to_invalid_wasm_module(error.to_string()))? {
+            Payload::Version {
+                encoding: Encoding::Component,
+                ..
+            } => {
+                return Err(to_invalid_wasm_module(
+                    "wasm component is not supported".to_owned(),
+                ));
+            }
+            Payload::MemorySection(reader) => {
+                for memory in reader {
+                    let memory =
+                        memory.map_err(|error| to_invalid_wasm_module(error.to_string()))?;
+                    let page_size_log2 =
+                        memory.page_size_log2.unwrap_or(WASM_DEFAULT_PAGE_SIZE_LOG2);
+                    initial_memory += (memory.initial as u128) << page_size_log2;
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export =
+                        export.map_err(|error| to_invalid_wasm_module(error.to_string()))?;
+                    if export.kind == ExternalKind::Func {
+                        exports.insert(export.name.to_owned());
+                    }
+                }
+            }
+            Payload::CustomSection(reader) => {
+                if let Some(section) = to_metadata_section(reader.name(), reader.data())? {
+                    if custom_sections.iter().any(|s| s.name == section.name) {
+                        return Err(AddContractTemplateError::InvalidWasmCustomSection {
+                            name: section.name,
+                            reason: "duplicate section".to_owned(),
+                        });
+                    }
+                    custom_sections.push(section);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    check_exported_methods(&exports)?;
+    check_initial_memory(initial_memory, contract_canister_settings)?;
+
+    Ok(ContractTemplateWasmMetadata {
+        compressed,
+        exported_methods: exports
+            .into_iter()
+            .filter(|name| name.starts_with(CANISTER_EXPORT_PREFIX))
+            .collect(),
+        custom_sections,
+    })
+}
+
+/// Converts the exported canister entry points to the canister methods.
+pub(crate) fn get_canister_methods(exported_methods: &[String]) -> Vec<CanisterMethod> {
+    exported_methods
+        .iter()
+        .filter_map(|export| {
+            CANISTER_METHOD_EXPORT_PREFIXES
+                .iter()
+                .find_map(|(prefix, method_type)| {
+                    export.strip_prefix(prefix).map(|name| CanisterMethod {
+                        name: name.to_owned(),
+                        method_type: method_type.clone(),
+                    })
+                })
+        })
+        .collect()
+}
+
+fn decompress_wasm(wasm: &[u8]) -> Result<Vec<u8>, AddContractTemplateError> {
+    let mut module = Vec::new();
+    GzDecoder::new(wasm)
+        .take(MAX_DECOMPRESSED_WASM_LENGTH + 1)
+        .read_to_end(&mut module)
+        .map_err(|error| to_invalid_wasm_module(format!("can not decompress wasm: {error}")))?;
+
+    if module.len() as u64 > MAX_DECOMPRESSED_WASM_LENGTH {
+        return Err(to_invalid_wasm_module(
+            "decompressed wasm is too big".to_owned(),
+        ));
+    }
+    Ok(module)
+}
+
+fn to_metadata_section(
+    name: &str,
+    content: &[u8],
+) -> Result<Option<WasmCustomSection>, AddContractTemplateError> {
+    let (section_name, public) = if let Some(name) = name.strip_prefix(ICP_PUBLIC_SECTION_PREFIX) {
+        (name, true)
+    } else if let Some(name) = name.strip_prefix(ICP_PRIVATE_SECTION_PREFIX) {
+        (name, false)
+    } else if name.starts_with(ICP_SECTION_PREFIX) {
+        return Err(AddContractTemplateError::InvalidWasmCustomSection {
+            name: name.to_owned(),
+            reason: "section visibility is not specified".to_owned(),
+        });
+    } else {
+        return Ok(None);
+    };
+
+    if TEXT_SECTIONS.contains(&section_name) && std::str::from_utf8(content).is_err() {
+        return Err(AddContractTemplateError::InvalidWasmCustomSection {
+            name: section_name.to_owned(),
+            reason: "section content is not a valid UTF-8 text".to_owned(),
+        });
+    }
+
+    Ok(Some(WasmCustomSection {
+        name: section_name.to_owned(),
+        public,
+        content: content.to_vec(),
+    }))
+}
+
+fn check_exported_methods(exports: &BTreeSet<String>) -> Result<(), AddContractTemplateError> {
+    if !exports.contains(CANISTER_INIT_EXPORT) {
+        return Err(AddContractTemplateError::MissingCanisterMethod {
+            method: CANISTER_INIT_EXPORT.to_owned(),
+        });
+    }
+
+    for method in REQUIRED_CONTRACT_METHODS {
+        if !CANISTER_METHOD_EXPORT_PREFIXES
+            .iter()
+            .any(|(prefix, _)| exports.contains(&format!("{prefix}{method}")))
+        {
+            return Err(AddContractTemplateError::MissingCanisterMethod {
+                method: method.to_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_initial_memory(
+    initial_memory: u128,
+    contract_canister_settings: &CanisterSettings,
+) -> Result<(), AddContractTemplateError> {
+    // zero value of the limit means that the limit is not set
+    if let Some(limit) = contract_canister_settings
+        .wasm_memory_limit
+        .filter(|limit| *limit > 0 && initial_memory > *limit)
+    {
+        return Err(AddContractTemplateError::WasmMemoryExceedsWasmMemoryLimit {
+            initial_memory,
+            limit,
+        });
+    }
+
+    if let Some(limit) = contract_canister_settings
+        .memory_allocation
+        .filter(|limit| *limit > 0 && initial_memory > *limit)
+    {
+        return Err(
+            AddContractTemplateError::WasmMemoryExceedsMemoryAllocation {
+                initial_memory,
+                limit,
+            },
+        );
+    }
+    Ok(())
+}
+
+fn to_invalid_wasm_module(reason: String) -> AddContractTemplateError {
+    AddContractTemplateError::InvalidWasmModule { reason }
+}
@@ -0,0 +1,100 @@
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    types::{VoucherCode, VoucherDefinition, VoucherDiscount, VoucherInformation},
+};
+
+use crate::model::{vouchers::VoucherModel, DataModel};
+
+const VOUCHER_CODE_MIN_LENGTH: usize = 4;
+const VOUCHER_CODE_MAX_LENGTH: usize = 64;
+
+pub(crate) fn build_voucher_information(
+    code: &VoucherCode,
+    model: &VoucherModel,
+) -> VoucherInformation {
+    VoucherInformation {
+        code: code.clone(),
+        created: model.created,
+        definition: model.definition.clone(),
+        redemptions_count: model.redemptions_count,
+        subsidized_cycles: model.subsidized_cycles,
+    }
+}
+
+/// Checks the voucher code: latin letters, digits, dashes and underscores.
+pub(crate) fn check_voucher_code(code: &VoucherCode) -> Result<(), String> {
+    if code.len() < VOUCHER_CODE_MIN_LENGTH || code.len() > VOUCHER_CODE_MAX_LENGTH {
+        return Err(format!(
+            "code length must be from {VOUCHER_CODE_MIN_LENGTH} to {VOUCHER_CODE_MAX_LENGTH}"
+        ));
+    }
+    if !code
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("code contains invalid characters".to_owned());
+    }
+    Ok(())
+}
+
+pub(crate) fn check_voucher_definition(definition: &VoucherDefinition) -> Result<(), String> {
+    match definition.discount {
+        VoucherDiscount::Percentage { permyriad } if permyriad == 0 || permyriad > 10_000 => {
+            return Err(format!(
+                "discount {permyriad} must be from 1 to 10000 permyriad"
+            ));
+        }
+        VoucherDiscount::Fixed { amount: 0 } => {
+            return Err("discount amount is zero".to_owned());
+        }
+        _ => {}
+    }
+    if definition.max_redemptions == 0 {
+        return Err("max redemptions is zero".to_owned());
+    }
+    if definition
+        .contract_template_ids
+        .as_ref()
+        .is_some_and(|ids| ids.is_empty())
+    {
+        return Err("contract template list is empty".to_owned());
+    }
+    Ok(())
+}
+
+/// Checks the voucher can be redeemed for the contract template deployment at the time.
+pub(crate) fn check_voucher_redemption(
+    model: &DataModel,
+    code: &VoucherCode,
+    contract_template_id: &ContractTemplateId,
+    time: TimestampMillis,
+) -> Result<VoucherDiscount, DeployContractError> {
+    let voucher = model
+        .get_vouchers_storage()
+        .get_voucher(code)
+        .ok_or(DeployContractError::VoucherNotFound)?;
+    let definition = &voucher.definition;
+
+    if definition
+        .expiration
+        .is_some_and(|expiration| expiration <= time)
+    {
+        return Err(DeployContractError::VoucherExpired);
+    }
+
+    if voucher.redemptions_count >= definition.max_redemptions {
+        return Err(DeployContractError::VoucherRedemptionsExhausted);
+    }
+
+    if definition
+        .contract_template_ids
+        .as_ref()
+        .is_some_and(|ids| !ids.contains(contract_template_id))
+    {
+        return Err(DeployContractError::VoucherNotApplicable);
+    }
+
+    Ok(definition.discount.clone())
+}
@@ -17,10 +17,14 @@ fn get_env() -> std::rc::Rc<components::Environment> {
 
 fn is_caller_has_access_right(permission: &hub_canister_api::types::Permission) -> bool {
     read_state(|state| {
-        let caller = state.get_env().get_ic().get_caller();
+        let env = state.get_env();
         state
             .get_model()
             .get_access_rights_storage()
-            .is_access_right(&caller, permission)
+            .is_access_right(
+                &env.get_ic().get_caller(),
+                permission,
+                env.get_time().get_current_unix_epoch_time_millis(),
+            )
     })
 }
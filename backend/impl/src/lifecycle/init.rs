@@ -1,11 +1,38 @@
 use crate::components::factory::create_environment;
+use crate::lifecycle::migrations::LATEST_SCHEMA_VERSION;
 use crate::model::DataModel;
 use crate::state::CanisterState;
-use crate::{get_env, init_state, log_info};
+use crate::updates::set_config::apply_config;
+use crate::{get_env, init_state, log_info, mutate_state};
+use hub_canister_api::types::HubArgs;
 use ic_cdk_macros::init;
 
+/// The hub is managed by the explicit initial admins.
 #[init]
-fn init() {
+fn init(args: Option<HubArgs>) {
+    let Some(HubArgs::Init(args)) = args else {
+        ic_cdk::trap("Hub: init arguments are required.");
+    };
+    if args.admins.is_empty() {
+        ic_cdk::trap("Hub: initial admins are required.");
+    }
+
     init_state(CanisterState::new(create_environment(), DataModel::init()));
-    log_info!(get_env(), "Hub initialized!");
+    mutate_state(|state| {
+        let model = state.get_model_mut();
+        model
+            .get_schema_storage_mut()
+            .set_version(LATEST_SCHEMA_VERSION);
+        model
+            .get_access_rights_storage_mut()
+            .set_initial_admins(&args.admins)
+    });
+
+    if let Some(config) = args.config {
+        if let Err(error) = apply_config(get_env().get_ic().get_caller(), *config) {
+            ic_cdk::trap(&format!("Hub: initial config is wrong: {error:?}"));
+        }
+    }
+
+    log_info!(get_env(), "Hub initialized! Admins: {:?}", args.admins);
 }
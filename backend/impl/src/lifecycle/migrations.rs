@@ -0,0 +1,87 @@
+use crate::model::DataModel;
+use hub_canister_api::types::{MigrationDirective, SchemaVersion};
+
+pub(crate) const LATEST_SCHEMA_VERSION: SchemaVersion = MIGRATIONS.len() as SchemaVersion;
+
+/// Ordered migrations of the stable state, the migration at the index `n`
+/// upgrades the state of the version `n` to the version `n + 1`.
+const MIGRATIONS: [fn(&mut DataModel); 4] = [
+    build_missing_search_index,
+    migrate_access_control,
+    build_missing_block_index,
+    build_missing_config_event_index,
+];
+
+/// Migrates the stable state according to the directive, returns the versions
+/// of the state before and after the migration.
+pub(crate) fn migrate_data_model(
+    model: &mut DataModel,
+    directive: Option<MigrationDirective>,
+) -> Result<(SchemaVersion, SchemaVersion), String> {
+    let current_version = model.get_schema_storage().get_version();
+
+    let target_version = match directive {
+        None => LATEST_SCHEMA_VERSION,
+        Some(MigrationDirective::MigrateTo { version }) => version,
+        Some(MigrationDirective::SetVersion { version }) => {
+            // the version is only rolled back, so the skipped migrations can not be lost
+            if version > current_version {
+                return Err(format!(
+                    "schema version {current_version} can not be set forward to {version}"
+                ));
+            }
+            model.get_schema_storage_mut().set_version(version);
+            return Ok((current_version, version));
+        }
+    };
+
+    check_schema_version(target_version)?;
+    if target_version < current_version {
+        return Err(format!(
+            "schema version {current_version} can not be downgraded to {target_version}"
+        ));
+    }
+
+    for version in current_version..target_version {
+        MIGRATIONS[version as usize](model);
+        model.get_schema_storage_mut().set_version(version + 1);
+    }
+    Ok((current_version, target_version))
+}
+
+fn check_schema_version(version: SchemaVersion) -> Result<(), String> {
+    if version > LATEST_SCHEMA_VERSION {
+        return Err(format!(
+            "schema version {version} is unknown, the latest is {LATEST_SCHEMA_VERSION}"
+        ));
+    }
+    Ok(())
+}
+
+/// Indexes the contract templates registered before the search index was introduced.
+fn build_missing_search_index(model: &mut DataModel) {
+    model
+        .get_contract_templates_storage_mut()
+        .build_missing_search_index();
+}
+
+/// Rewrites the access rights stored before the roles were introduced.
+fn migrate_access_control(model: &mut DataModel) {
+    model
+        .get_access_rights_storage_mut()
+        .migrate_access_control();
+}
+
+/// Indexes the contract blocks registered before the block index was introduced.
+fn build_missing_block_index(model: &mut DataModel) {
+    model
+        .get_blocked_contracts_storage_mut()
+        .build_missing_block_index();
+}
+
+/// Indexes the config events registered before the config event index was introduced.
+fn build_missing_config_event_index(model: &mut DataModel) {
+    model
+        .get_hub_events_storage_mut()
+        .build_missing_config_event_index();
+}
@@ -1,2 +1,3 @@
 mod init;
+pub(crate) mod migrations;
 mod post_upgrade;
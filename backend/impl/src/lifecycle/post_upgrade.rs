@@ -1,11 +1,58 @@
 use crate::components::factory::create_environment;
+use crate::lifecycle::migrations::migrate_data_model;
 use crate::model::DataModel;
 use crate::state::CanisterState;
-use crate::{get_env, init_state, log_info};
+use crate::{get_env, init_state, log_info, mutate_state, read_state};
+use hub_canister_api::types::{HubArgs, HubUpgradeArgs, MigrationDirective};
 use ic_cdk_macros::post_upgrade;
 
+/// The failed migration traps, so the upgrade is rolled back.
 #[post_upgrade]
-fn post_upgrade() {
+fn post_upgrade(args: Option<HubArgs>) {
+    let args = match args {
+        None => HubUpgradeArgs::default(),
+        Some(HubArgs::Upgrade(args)) => args,
+        Some(HubArgs::Init(_)) => ic_cdk::trap("Hub: upgrade arguments are expected."),
+    };
+
     init_state(CanisterState::new(create_environment(), DataModel::init()));
+
+    let set_version = matches!(args.migration, Some(MigrationDirective::SetVersion { .. }));
+    match mutate_state(|state| migrate_data_model(state.get_model_mut(), args.migration)) {
+        Ok((from_version, to_version)) if set_version => log_info!(
+            get_env(),
+            "Hub WARNING: schema version is set without migrations: {from_version} -> {to_version}"
+        ),
+        Ok((from_version, to_version)) => log_info!(
+            get_env(),
+            "Hub schema version: {from_version} -> {to_version}"
+        ),
+        Err(error) => ic_cdk::trap(&format!("Hub: migration failed: {error}")),
+    }
+
+    // the admins are granted only to the hub without any access rights,
+    // which was open to everyone before the explicit initial admins
+    let access_control_empty = read_state(|state| {
+        state
+            .get_model()
+            .get_access_rights_storage()
+            .get_access_control()
+            .is_empty()
+    });
+    if access_control_empty && args.admins.is_empty() {
+        ic_cdk::trap("Hub: admins are required for the hub without any access rights.");
+    }
+
+    let granted = access_control_empty
+        && mutate_state(|state| {
+            state
+                .get_model_mut()
+                .get_access_rights_storage_mut()
+                .set_initial_admins(&args.admins)
+        });
+    if granted {
+        log_info!(get_env(), "Hub admins granted: {:?}", args.admins);
+    }
+
     log_info!(get_env(), "Hub post-upgrade completed.");
 }
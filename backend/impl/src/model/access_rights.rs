@@ -1,48 +1,239 @@
 use candid::Principal;
 use common_canister_impl::stable_structures::CBor;
-use hub_canister_api::types::{Permission, Permissions};
+use common_canister_types::TimestampMillis;
+use hub_canister_api::types::{
+    Permission, Permissions, Role, RoleAssignment, RoleAssignmentSettings,
+};
 use ic_stable_structures::{DefaultMemoryImpl, RestrictedMemory, StableCell};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 type RM = RestrictedMemory<DefaultMemoryImpl>;
 pub type AccessRights = BTreeMap<Principal, (Permissions, Option<String>)>;
+pub type Roles = BTreeMap<String, RoleModel>;
+pub type RoleAssignments = BTreeMap<Principal, BTreeMap<String, RoleAssignmentSettings>>;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RoleModel {
+    pub permissions: Vec<Permission>,
+    pub description: Option<String>,
+}
+
+/// Direct access rights, named roles and role assignments of the principals.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[serde(from = "AccessControlRecord")]
+pub struct AccessControl {
+    pub access_rights: AccessRights,
+    pub roles: Roles,
+    pub role_assignments: RoleAssignments,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AccessControlRecord {
+    Current {
+        access_rights: AccessRights,
+        roles: Roles,
+        role_assignments: RoleAssignments,
+    },
+    /// Flat access rights stored before the roles were introduced.
+    Legacy(AccessRights),
+}
+
+impl From<AccessControlRecord> for AccessControl {
+    fn from(record: AccessControlRecord) -> Self {
+        match record {
+            AccessControlRecord::Current {
+                access_rights,
+                roles,
+                role_assignments,
+            } => Self {
+                access_rights,
+                roles,
+                role_assignments,
+            },
+            AccessControlRecord::Legacy(access_rights) => Self {
+                access_rights,
+                ..Self::default()
+            },
+        }
+    }
+}
+
+impl AccessControl {
+    /// Returns the permissions granted directly or by the roles not expired at the time,
+    /// `None` means all the permissions.
+    pub(crate) fn get_permissions(&self, caller: &Principal, time: TimestampMillis) -> Permissions {
+        let mut permissions = match self.access_rights.get(caller) {
+            Some((None, _)) => return None,
+            Some((Some(permissions), _)) => permissions.clone(),
+            None => Vec::new(),
+        };
+
+        for role in self.get_active_roles(caller, time) {
+            for permission in role.permissions.iter() {
+                if !permissions.contains(permission) {
+                    permissions.push(permission.clone());
+                }
+            }
+        }
+        Some(permissions)
+    }
+
+    pub(crate) fn is_access_right(
+        &self,
+        caller: &Principal,
+        permission: &Permission,
+        time: TimestampMillis,
+    ) -> bool {
+        self.get_permissions(caller, time)
+            .is_none_or(|permissions| permissions.contains(permission))
+    }
+
+    pub(crate) fn get_role_assignments(&self, caller: &Principal) -> Vec<RoleAssignment> {
+        self.role_assignments
+            .get(caller)
+            .into_iter()
+            .flatten()
+            .map(|(role, settings)| RoleAssignment {
+                caller: *caller,
+                role: role.clone(),
+                settings: settings.clone(),
+            })
+            .collect()
+    }
+
+    pub(crate) fn get_all_role_assignments(&self) -> Vec<RoleAssignment> {
+        self.role_assignments
+            .keys()
+            .flat_map(|caller| self.get_role_assignments(caller))
+            .collect()
+    }
+
+    pub(crate) fn get_roles(&self) -> Vec<Role> {
+        self.roles
+            .iter()
+            .map(|(name, role)| Role {
+                name: name.clone(),
+                permissions: role.permissions.clone(),
+                description: role.description.clone(),
+            })
+            .collect()
+    }
+
+    /// Replaces the roles, the assignments of the removed roles are removed too.
+    pub(crate) fn set_roles(&mut self, roles: Vec<Role>) {
+        self.roles = roles
+            .into_iter()
+            .map(|role| {
+                (
+                    role.name,
+                    RoleModel {
+                        permissions: role.permissions,
+                        description: role.description,
+                    },
+                )
+            })
+            .collect();
+
+        for assignments in self.role_assignments.values_mut() {
+            assignments.retain(|role, _| self.roles.contains_key(role));
+        }
+        self.role_assignments
+            .retain(|_, assignments| !assignments.is_empty());
+    }
+
+    pub(crate) fn set_role_assignment(
+        &mut self,
+        caller: Principal,
+        role: String,
+        settings: Option<RoleAssignmentSettings>,
+    ) {
+        let assignments = self.role_assignments.entry(caller).or_default();
+        match settings {
+            Some(settings) => {
+                assignments.insert(role, settings);
+            }
+            None => {
+                assignments.remove(&role);
+            }
+        }
+        if assignments.is_empty() {
+            self.role_assignments.remove(&caller);
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.access_rights.is_empty() && self.role_assignments.is_empty()
+    }
+
+    fn get_active_roles<'a>(
+        &'a self,
+        caller: &Principal,
+        time: TimestampMillis,
+    ) -> impl Iterator<Item = &'a RoleModel> {
+        self.role_assignments
+            .get(caller)
+            .into_iter()
+            .flatten()
+            .filter(move |(_, settings)| {
+                settings
+                    .expiration
+                    .is_none_or(|expiration| expiration > time)
+            })
+            .filter_map(|(role, _)| self.roles.get(role))
+    }
+}
 
 pub struct AccessRightsStorage {
-    access_rights: StableCell<CBor<AccessRights>, RM>,
+    access_control: StableCell<CBor<AccessControl>, RM>,
 }
 
 impl AccessRightsStorage {
     pub(crate) fn init(memory: RM) -> Self {
         AccessRightsStorage {
-            access_rights: StableCell::init(memory, CBor(AccessRights::default())),
+            access_control: StableCell::init(memory, CBor(AccessControl::default())),
         }
     }
 
-    pub(crate) fn is_access_right(&self, caller: &Principal, permission: &Permission) -> bool {
-        check_is_access_right(self.access_rights.get(), caller, permission)
+    /// Rewrites the access rights stored in the legacy format to the current one.
+    pub(crate) fn migrate_access_control(&mut self) {
+        let access_control = AccessControl::clone(self.access_control.get());
+        self.access_control.set(CBor(access_control));
     }
 
-    pub(crate) fn get_access_rights(&self) -> &AccessRights {
-        self.access_rights.get()
+    pub(crate) fn is_access_right(
+        &self,
+        caller: &Principal,
+        permission: &Permission,
+        time: TimestampMillis,
+    ) -> bool {
+        self.access_control
+            .get()
+            .is_access_right(caller, permission, time)
     }
 
-    pub(crate) fn set_access_rights(&mut self, access_rights: AccessRights) {
-        self.access_rights.set(CBor(access_rights));
+    pub(crate) fn get_access_control(&self) -> &AccessControl {
+        self.access_control.get()
     }
-}
 
-pub(crate) fn check_is_access_right(
-    access_rights: &AccessRights,
-    caller: &Principal,
-    permission: &Permission,
-) -> bool {
-    if access_rights.is_empty() {
-        return true;
+    pub(crate) fn set_access_control(&mut self, access_control: AccessControl) {
+        self.access_control.set(CBor(access_control));
     }
 
-    access_rights.get(caller).is_some_and(|(permissions, _)| {
-        permissions
-            .as_ref()
-            .is_none_or(|list| list.contains(permission))
-    })
+    /// Grants all the permissions to the admins of the hub without any access rights.
+    pub(crate) fn set_initial_admins(&mut self, admins: &[Principal]) -> bool {
+        if !self.access_control.get().is_empty() {
+            return false;
+        }
+
+        let mut access_control = AccessControl::clone(self.access_control.get());
+        for admin in admins {
+            access_control
+                .access_rights
+                .insert(*admin, (None, Some("initial admin".to_owned())));
+        }
+        self.set_access_control(access_control);
+        true
+    }
 }
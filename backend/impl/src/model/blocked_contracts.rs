@@ -1,13 +1,16 @@
 use common_canister_impl::stable_structures::CBor;
 use common_canister_types::{TimestampMillis, Timestamped};
-use hub_canister_api::types::DeploymentId;
+use hub_canister_api::types::{ContractBlockAction, ContractBlockHistoryEntry, DeploymentId};
 use ic_stable_structures::{
-    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableLog,
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap, StableLog,
 };
 use serde::{Deserialize, Serialize};
 
 type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
 type ContractBlocksLog = StableLog<CBor<ContractBlockBatch>, VM, VM>;
+type DeploymentBlockIndex = StableBTreeMap<DeploymentId, CBor<Timestamped<String>>, VM>;
+type DeploymentBlockHistory =
+    StableBTreeMap<(DeploymentId, u64), CBor<ContractBlockHistoryEntry>, VM>;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct ContractBlockBatch {
@@ -18,12 +21,41 @@ pub struct ContractBlockBatch {
 
 pub struct BlockedContractsStorage {
     contract_blocks: ContractBlocksLog,
+    deployment_block_index: DeploymentBlockIndex,
+    deployment_block_history: DeploymentBlockHistory,
 }
 
 impl BlockedContractsStorage {
-    pub(crate) fn init(index_memory: VM, data_memory: VM) -> Self {
+    pub(crate) fn init(
+        index_memory: VM,
+        data_memory: VM,
+        deployment_block_index_memory: VM,
+        deployment_block_history_memory: VM,
+    ) -> Self {
         Self {
             contract_blocks: StableLog::init(index_memory, data_memory),
+            deployment_block_index: StableBTreeMap::init(deployment_block_index_memory),
+            deployment_block_history: StableBTreeMap::init(deployment_block_history_memory),
+        }
+    }
+
+    /// Indexes the contract blocks registered before the block index was introduced.
+    pub(crate) fn build_missing_block_index(&mut self) {
+        if !self.deployment_block_history.is_empty() {
+            return;
+        }
+
+        for idx in 0..self.contract_blocks.len() {
+            let Some(record) = self.contract_blocks.get(idx) else {
+                continue;
+            };
+
+            for deployment_id in record.deployment_ids.iter() {
+                if self.deployment_block_index.contains_key(deployment_id) {
+                    continue;
+                }
+                self.index_deployment_block(deployment_id, record.blocked_at, &record.reason);
+            }
         }
     }
 
@@ -33,6 +65,10 @@ impl BlockedContractsStorage {
         reason: String,
         deployment_ids: Vec<DeploymentId>,
     ) {
+        for deployment_id in deployment_ids.iter() {
+            self.index_deployment_block(deployment_id, blocked_at, &reason);
+        }
+
         let record = CBor(ContractBlockBatch {
             blocked_at,
             reason,
@@ -47,25 +83,78 @@ impl BlockedContractsStorage {
         }
     }
 
+    pub(crate) fn remove_contract_blocks(
+        &mut self,
+        unblocked_at: TimestampMillis,
+        reason: String,
+        deployment_ids: Vec<DeploymentId>,
+    ) {
+        for deployment_id in deployment_ids.iter() {
+            if self.deployment_block_index.remove(deployment_id).is_some() {
+                self.add_history_entry(
+                    deployment_id,
+                    ContractBlockHistoryEntry {
+                        time: unblocked_at,
+                        action: ContractBlockAction::Unblocked,
+                        reason: reason.clone(),
+                    },
+                );
+            }
+        }
+    }
 
     pub(crate) fn find_deployment_block(
         &self,
         deployment_id: &DeploymentId,
     ) -> Option<Timestamped<String>> {
-        for idx in 0..self.contract_blocks.len() {
-            let Some(record) = self.contract_blocks.get(idx) else {
-                continue;
-            };
+        self.deployment_block_index
+            .get(deployment_id)
+            .map(|blocked| Timestamped::clone(&blocked))
+    }
 
-            if record
-                .deployment_ids
-                .iter()
-                .any(|blocked_id| blocked_id == deployment_id)
-            {
-                return Some(Timestamped::new(record.blocked_at, record.reason.clone()));
-            }
-        }
+    pub(crate) fn get_deployment_block_history(
+        &self,
+        deployment_id: &DeploymentId,
+    ) -> Vec<ContractBlockHistoryEntry> {
+        self.deployment_block_history
+            .range((*deployment_id, 0)..=(*deployment_id, u64::MAX))
+            .map(|entry| ContractBlockHistoryEntry::clone(&entry.value()))
+            .collect()
+    }
+
+    fn index_deployment_block(
+        &mut self,
+        deployment_id: &DeploymentId,
+        blocked_at: TimestampMillis,
+        reason: &str,
+    ) {
+        self.deployment_block_index.insert(
+            *deployment_id,
+            CBor(Timestamped::new(blocked_at, reason.to_owned())),
+        );
+        self.add_history_entry(
+            deployment_id,
+            ContractBlockHistoryEntry {
+                time: blocked_at,
+                action: ContractBlockAction::Blocked,
+                reason: reason.to_owned(),
+            },
+        );
+    }
+
+    fn add_history_entry(
+        &mut self,
+        deployment_id: &DeploymentId,
+        entry: ContractBlockHistoryEntry,
+    ) {
+        let entry_id = self
+            .deployment_block_history
+            .keys_range((*deployment_id, 0)..=(*deployment_id, u64::MAX))
+            .next_back()
+            .map(|(_, entry_id)| entry_id + 1)
+            .unwrap_or(0);
 
-        None
+        self.deployment_block_history
+            .insert((*deployment_id, entry_id), CBor(entry));
     }
 }
@@ -1,18 +1,23 @@
 use common_canister_impl::stable_structures::CBor;
-use hub_canister_api::types::Config;
-use ic_stable_structures::{DefaultMemoryImpl, RestrictedMemory, StableCell};
+use hub_canister_api::types::{Config, ConfigVersion};
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableCell,
+};
 
 type RM = RestrictedMemory<DefaultMemoryImpl>;
+type VM = VirtualMemory<RM>;
 type ConfigCell = StableCell<CBor<Config>, RM>;
 
 pub struct ConfigStorage {
     config: ConfigCell,
+    config_version: StableCell<ConfigVersion, VM>,
 }
 
 impl ConfigStorage {
-    pub(crate) fn init(memory: RM) -> Self {
+    pub(crate) fn init(memory: RM, config_version_memory: VM) -> Self {
         ConfigStorage {
             config: StableCell::init(memory, CBor(Config::default())),
+            config_version: StableCell::init(config_version_memory, 0),
         }
     }
 
@@ -20,7 +25,15 @@ impl ConfigStorage {
         self.config.get()
     }
 
-    pub(crate) fn set_config(&mut self, config: Config) {
+    pub(crate) fn get_config_version(&self) -> ConfigVersion {
+        *self.config_version.get()
+    }
+
+    /// Sets the config and increases the config version.
+    pub(crate) fn set_config(&mut self, config: Config) -> ConfigVersion {
         self.config.set(CBor(config));
+        let config_version = self.get_config_version() + 1;
+        self.config_version.set(config_version);
+        config_version
     }
 }
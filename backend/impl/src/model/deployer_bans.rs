@@ -0,0 +1,42 @@
+use candid::Principal;
+use common_canister_impl::stable_structures::CBor;
+use common_canister_types::TimestampMillis;
+use hub_canister_api::types::DeployerBan;
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap,
+};
+
+type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
+type DeployerBansTable = StableBTreeMap<Principal, CBor<DeployerBan>, VM>;
+
+pub struct DeployerBansStorage {
+    deployer_bans_table: DeployerBansTable,
+}
+
+impl DeployerBansStorage {
+    pub(crate) fn init(deployer_bans_memory: VM) -> Self {
+        Self {
+            deployer_bans_table: StableBTreeMap::init(deployer_bans_memory),
+        }
+    }
+
+    pub(crate) fn set_deployer_ban(&mut self, deployer: Principal, ban: DeployerBan) {
+        self.deployer_bans_table.insert(deployer, CBor(ban));
+    }
+
+    pub(crate) fn remove_deployer_ban(&mut self, deployer: &Principal) -> bool {
+        self.deployer_bans_table.remove(deployer).is_some()
+    }
+
+    /// Returns the ban of the deployer, unless it has expired by the time.
+    pub(crate) fn find_active_deployer_ban(
+        &self,
+        deployer: &Principal,
+        time: TimestampMillis,
+    ) -> Option<DeployerBan> {
+        self.deployer_bans_table
+            .get(deployer)
+            .map(|ban| DeployerBan::clone(&ban))
+            .filter(|ban| ban.is_active(time))
+    }
+}
@@ -5,11 +5,12 @@ use common_canister_impl::{components::time::Time, stable_structures::CBor};
 use common_canister_types::{LedgerAccount, TimestampMillis, Timestamped, TokenE8s};
 use common_contract_api::{ContractActivationCode, ContractTemplateId};
 use hub_canister_api::types::{
-    DeploymentEventId, DeploymentExpenses, DeploymentId,
+    CertificateRenewal, DeploymentEventId, DeploymentExpenses, DeploymentId,
+    DeploymentOwnershipTransfer,
     DeploymentProcessingEvent::{self, *},
     DeploymentResult,
     DeploymentState::{self, *},
-    FinalizeDeploymentState,
+    FinalizeDeploymentState, VoucherRedemption,
 };
 use ic_stable_structures::{
     memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap, StableLog,
@@ -43,6 +44,22 @@ pub struct Deployment {
     pub contract_template_id: ContractTemplateId,
     pub deployment_expenses: DeploymentExpenses,
     pub expenses_amount: TokenE8s,
+    #[serde(default)]
+    pub publisher_fee_amount: TokenE8s,
+    #[serde(default)]
+    pub voucher_redemption: Option<VoucherRedemption>,
+    #[serde(default)]
+    pub sponsor: Option<Principal>,
+    /// Principal the contract is deployed for, if it differs from the paying deployer.
+    #[serde(default)]
+    pub beneficiary: Option<Principal>,
+    /// Principal proposed as the next owner, waiting for its acceptance.
+    #[serde(default)]
+    pub pending_owner: Option<Principal>,
+    #[serde(default)]
+    pub ownership_history: Vec<DeploymentOwnershipTransfer>,
+    #[serde(default)]
+    pub certificate_renewals: Vec<CertificateRenewal>,
     pub approved_account: LedgerAccount,
     pub subnet_type: Option<String>,
     pub activation_code: Option<ContractActivationCode>,
@@ -53,6 +70,58 @@ pub struct Deployment {
     lock_id_sequence: u64,
 }
 
+impl Deployment {
+    /// The last accepted ownership transfer defines the owner, otherwise the
+    /// beneficiary owns the contract, otherwise the deployer does.
+    pub(crate) fn get_owner(&self) -> Principal {
+        self.ownership_history
+            .last()
+            .map(|transfer| transfer.new_owner)
+            .unwrap_or(self.beneficiary.unwrap_or(self.deployer))
+    }
+
+    pub(crate) fn is_successfully_finalized(&self) -> bool {
+        matches!(
+            self.state.value,
+            FinalizeDeployment {
+                result: DeploymentResult::Success,
+                sub_state: FinalizeDeploymentState::Finalized,
+            }
+        )
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        matches!(
+            self.state.value,
+            FinalizeDeployment {
+                result: DeploymentResult::Cancelled { .. },
+                ..
+            }
+        )
+    }
+
+    /// After an ownership transfer or a renewal of the deployed contract the hub
+    /// reissues the certificate naming the current owner and expiration.
+    pub(crate) fn has_reissued_certificate(&self) -> bool {
+        (!self.ownership_history.is_empty() || !self.certificate_renewals.is_empty())
+            && self.is_successfully_finalized()
+    }
+
+    pub(crate) fn get_certificate_expiration(
+        &self,
+        certificate_duration: TimestampMillis,
+    ) -> TimestampMillis {
+        self.certificate_renewals
+            .last()
+            .map(|renewal| renewal.expiration)
+            .unwrap_or(self.created.saturating_add(certificate_duration))
+    }
+
+    pub(crate) fn is_deployer_or_beneficiary(&self, caller: &Principal) -> bool {
+        self.deployer == *caller || self.beneficiary.as_ref() == Some(caller)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct DeploymentLock {
     pub lock_id: u64,
@@ -118,6 +187,10 @@ impl DeploymentsStorage {
         contract_template_id: ContractTemplateId,
         deployment_expenses: DeploymentExpenses,
         expenses_amount: TokenE8s,
+        publisher_fee_amount: TokenE8s,
+        voucher_redemption: Option<VoucherRedemption>,
+        sponsor: Option<Principal>,
+        beneficiary: Option<Principal>,
         approved_account: LedgerAccount,
         subnet_type: Option<String>,
         activation_code: Option<ContractActivationCode>,
@@ -130,6 +203,13 @@ impl DeploymentsStorage {
             contract_template_id,
             deployment_expenses,
             expenses_amount,
+            publisher_fee_amount,
+            voucher_redemption,
+            sponsor,
+            beneficiary,
+            pending_owner: None,
+            ownership_history: vec![],
+            certificate_renewals: vec![],
             approved_account,
             subnet_type,
             activation_code,
@@ -146,10 +226,13 @@ impl DeploymentsStorage {
         self.contract_template_index
             .insert((contract_template_id, deployment_id), ());
 
-        self.deployer_index.insert((deployer, deployment_id), ());
+        // the contract is indexed by its owner
+        let owner = beneficiary.unwrap_or(deployer);
+
+        self.deployer_index.insert((owner, deployment_id), ());
 
         self.deployer_contract_template_index
-            .insert((deployer, contract_template_id, deployment_id), ());
+            .insert((owner, contract_template_id, deployment_id), ());
 
         deployment_id
     }
@@ -371,6 +454,26 @@ impl DeploymentsStorage {
                             deployment,
                             FinalizeDeploymentState::StartDeploymentFinalization
                         );
+                        // the publisher is paid for successful deployments only
+                        let sub_state = if deployment.publisher_fee_amount > 0
+                            && matches!(
+                                deployment.state.value,
+                                FinalizeDeployment {
+                                    result: DeploymentResult::Success,
+                                    ..
+                                }
+                            ) {
+                            FinalizeDeploymentState::TransferPublisherFee
+                        } else {
+                            FinalizeDeploymentState::TransferTransitFundsToExternalService
+                        };
+                        change_complete_deployment_state(deployment, time, sub_state);
+                    }
+                    PublisherFeeTransferred { .. } => {
+                        complete_state_matches!(
+                            deployment,
+                            FinalizeDeploymentState::TransferPublisherFee
+                        );
                         change_complete_deployment_state(
                             deployment,
                             time,
@@ -388,6 +491,47 @@ impl DeploymentsStorage {
                             FinalizeDeploymentState::Finalized,
                         );
                     }
+                    TransitFundsDistributionStarted { payouts } => {
+                        complete_state_matches!(
+                            deployment,
+                            FinalizeDeploymentState::TransferTransitFundsToExternalService
+                        );
+                        change_complete_deployment_state(
+                            deployment,
+                            time,
+                            FinalizeDeploymentState::TransferTransitFundsToBeneficiaries {
+                                payouts: payouts.clone(),
+                                paid_count: 0,
+                            },
+                        );
+                    }
+                    TransitFundsToBeneficiaryTransferred {
+                        beneficiary_index, ..
+                    } => {
+                        let sub_state = match &deployment.state.value {
+                            FinalizeDeployment {
+                                sub_state:
+                                    FinalizeDeploymentState::TransferTransitFundsToBeneficiaries {
+                                        payouts,
+                                        paid_count,
+                                    },
+                                ..
+                            } if paid_count == beneficiary_index => {
+                                if paid_count + 1 < payouts.len() {
+                                    FinalizeDeploymentState::TransferTransitFundsToBeneficiaries {
+                                        payouts: payouts.clone(),
+                                        paid_count: paid_count + 1,
+                                    }
+                                } else {
+                                    FinalizeDeploymentState::Finalized
+                                }
+                            }
+                            _ => {
+                                return Err(UpdateDeploymentError::WrongState);
+                            }
+                        };
+                        change_complete_deployment_state(deployment, time, sub_state);
+                    }
                 }
 
                 Ok(())
@@ -488,6 +632,77 @@ fn change_complete_deployment_state(
     deployment.state = Timestamped::new(time, FinalizeDeployment { result, sub_state });
 }
 
+/// Ownership management
+impl DeploymentsStorage {
+    pub(crate) fn set_pending_owner(
+        &mut self,
+        deployment_id: &DeploymentId,
+        pending_owner: Option<Principal>,
+    ) {
+        self.update_deployment_in_table(deployment_id, |_, deployment| -> Result<(), ()> {
+            deployment.pending_owner = pending_owner;
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    /// Moves the deployment to the new owner, reindexes it and appends
+    /// the transfer to the ownership history.
+    pub(crate) fn transfer_ownership(
+        &mut self,
+        time: TimestampMillis,
+        deployment_id: &DeploymentId,
+        new_owner: Principal,
+    ) {
+        self.update_deployment_in_table(deployment_id, |storage, deployment| -> Result<(), ()> {
+            let previous_owner = deployment.get_owner();
+            let contract_template_id = deployment.contract_template_id;
+
+            storage
+                .deployer_index
+                .remove(&(previous_owner, *deployment_id));
+            storage.deployer_contract_template_index.remove(&(
+                previous_owner,
+                contract_template_id,
+                *deployment_id,
+            ));
+
+            storage
+                .deployer_index
+                .insert((new_owner, *deployment_id), ());
+            storage
+                .deployer_contract_template_index
+                .insert((new_owner, contract_template_id, *deployment_id), ());
+
+            deployment.pending_owner = None;
+            deployment
+                .ownership_history
+                .push(DeploymentOwnershipTransfer {
+                    previous_owner,
+                    new_owner,
+                    transferred: time,
+                });
+            Ok(())
+        })
+        .unwrap();
+    }
+}
+
+/// Certificate renewals
+impl DeploymentsStorage {
+    pub(crate) fn add_certificate_renewal(
+        &mut self,
+        deployment_id: &DeploymentId,
+        renewal: CertificateRenewal,
+    ) {
+        self.update_deployment_in_table(deployment_id, |_, deployment| -> Result<(), ()> {
+            deployment.certificate_renewals.push(renewal.clone());
+            Ok(())
+        })
+        .unwrap();
+    }
+}
+
 /// Lock management
 impl DeploymentsStorage {
     /// Locks a deployment. Returns an error with expiration if the deployment
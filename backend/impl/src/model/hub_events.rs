@@ -3,20 +3,23 @@ use common_canister_impl::stable_structures::CBor;
 use common_canister_types::TimestampMillis;
 use hub_canister_api::types::{HubEvent, HubEventType};
 use ic_stable_structures::{
-    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableLog,
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap, StableLog,
 };
 
 type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
 type HubEventsLog = StableLog<CBor<HubEvent>, VM, VM>;
+type ConfigEventIndex = StableBTreeMap<u64, (), VM>;
 
 pub struct HubEventsStorage {
     events: HubEventsLog,
+    config_event_index: ConfigEventIndex,
 }
 
 impl HubEventsStorage {
-    pub(crate) fn init(index_memory: VM, data_memory: VM) -> Self {
+    pub(crate) fn init(index_memory: VM, data_memory: VM, config_event_index_memory: VM) -> Self {
         HubEventsStorage {
             events: StableLog::init(index_memory, data_memory),
+            config_event_index: StableBTreeMap::init(config_event_index_memory),
         }
     }
 
@@ -32,11 +35,38 @@ impl HubEventsStorage {
             caller,
         });
 
-        if let Err(error) = self.events.append(&event) {
-            ic_cdk::println!("Hub: failed to append event {:?}: {error:?}", *event);
+        match self.events.append(&event) {
+            Ok(idx) if is_config_event(&event.event) => {
+                self.config_event_index.insert(idx, ());
+            }
+            Ok(_) => {}
+            Err(error) => {
+                ic_cdk::println!("Hub: failed to append event {:?}: {error:?}", *event);
+            }
         }
     }
 
+    /// Indexes the config events registered before the config event index was introduced.
+    pub(crate) fn build_missing_config_event_index(&mut self) {
+        if !self.config_event_index.is_empty() {
+            return;
+        }
+
+        for idx in 0..self.events.len() {
+            let Some(event) = self.events.get(idx) else {
+                continue;
+            };
+            if is_config_event(&event.event) {
+                self.config_event_index.insert(idx, ());
+            }
+        }
+    }
+
+    /// Returns the ascending ids of the config events up to the event.
+    pub(crate) fn get_config_event_ids(&self, until: u64) -> Vec<u64> {
+        self.config_event_index.keys_range(..=until).collect()
+    }
+
     pub(crate) fn get_hub_events_len(&self) -> u64 {
         self.events.len()
     }
@@ -45,3 +75,10 @@ impl HubEventsStorage {
         self.events.get(idx)
     }
 }
+
+fn is_config_event(event: &HubEventType) -> bool {
+    matches!(
+        event,
+        HubEventType::ConfigSet { .. } | HubEventType::ConfigPatched { .. }
+    )
+}
@@ -2,19 +2,33 @@ use crate::model::deployments::DeploymentsStorage;
 use access_rights::AccessRightsStorage;
 use blocked_contracts::BlockedContractsStorage;
 use config::ConfigStorage;
+use deployer_bans::DeployerBansStorage;
 use hub_events::HubEventsStorage;
 use ic_canister_sig_creation::signature_map::SignatureMap;
 use ic_stable_structures::memory_manager::{MemoryId, MemoryManager};
 use ic_stable_structures::{DefaultMemoryImpl as DefMem, RestrictedMemory, MAX_PAGES};
+use proposals::ProposalsStorage;
+use publishers::PublishersStorage;
+use rate_limits::DeploymentRateLimitsStorage;
+use schema::SchemaStorage;
+use sponsors::SponsorsStorage;
 use templates::ContractTemplatesStorage;
+use vouchers::VouchersStorage;
 use wasm::WasmStorage;
 
 pub mod access_rights;
 pub mod blocked_contracts;
 pub mod config;
+pub mod deployer_bans;
 pub mod deployments;
 pub mod hub_events;
+pub mod proposals;
+pub mod publishers;
+pub mod rate_limits;
+pub mod schema;
+pub mod sponsors;
 pub mod templates;
+pub mod vouchers;
 pub mod wasm;
 
 type RM = RestrictedMemory<DefMem>;
@@ -24,6 +38,13 @@ pub struct DataModel {
     access_rights_storage: AccessRightsStorage,
     contract_templates_storage: ContractTemplatesStorage,
     blocked_contracts_storage: BlockedContractsStorage,
+    publishers_storage: PublishersStorage,
+    vouchers_storage: VouchersStorage,
+    sponsors_storage: SponsorsStorage,
+    deployer_bans_storage: DeployerBansStorage,
+    deployment_rate_limits_storage: DeploymentRateLimitsStorage,
+    proposals_storage: ProposalsStorage,
+    schema_storage: SchemaStorage,
     deployments_storage: DeploymentsStorage,
     hub_events_storage: HubEventsStorage,
     wasm_storage: WasmStorage,
@@ -38,6 +59,8 @@ impl DataModel {
 
         let contract_templates_mem = mm.get(MemoryId::new(0));
         let contract_templates_wasm_mem = mm.get(MemoryId::new(1));
+        let contract_templates_wasm_metadata_mem = mm.get(MemoryId::new(14));
+        let contract_templates_search_index_mem = mm.get(MemoryId::new(15));
 
         let deployments_mem = mm.get(MemoryId::new(2));
         let deployments_canister_index_mem = mm.get(MemoryId::new(3));
@@ -53,13 +76,39 @@ impl DataModel {
 
         let blocked_contracts_index_mem = mm.get(MemoryId::new(12));
         let blocked_contracts_data_mem = mm.get(MemoryId::new(13));
+        let blocked_contracts_block_index_mem = mm.get(MemoryId::new(21));
+        let blocked_contracts_block_history_mem = mm.get(MemoryId::new(22));
+
+        let publishers_mem = mm.get(MemoryId::new(16));
+
+        let vouchers_mem = mm.get(MemoryId::new(17));
+        let vouchers_usage_mem = mm.get(MemoryId::new(18));
+
+        let sponsors_mem = mm.get(MemoryId::new(19));
+        let sponsored_deployments_index_mem = mm.get(MemoryId::new(20));
+
+        let deployer_bans_mem = mm.get(MemoryId::new(23));
+
+        let rate_limits_deployer_starts_mem = mm.get(MemoryId::new(24));
+        let rate_limits_contract_template_starts_mem = mm.get(MemoryId::new(25));
+        let rate_limits_hub_starts_mem = mm.get(MemoryId::new(26));
+
+        let proposals_mem = mm.get(MemoryId::new(27));
+
+        let schema_mem = mm.get(MemoryId::new(28));
+
+        let config_version_mem = mm.get(MemoryId::new(29));
+
+        let hub_events_config_event_index_mem = mm.get(MemoryId::new(30));
 
         Self {
-            config_storage: ConfigStorage::init(config_mem),
+            config_storage: ConfigStorage::init(config_mem, config_version_mem),
             access_rights_storage: AccessRightsStorage::init(access_rights_mem),
             contract_templates_storage: ContractTemplatesStorage::init(
                 contract_templates_mem,
                 contract_templates_wasm_mem,
+                contract_templates_wasm_metadata_mem,
+                contract_templates_search_index_mem,
             ),
             deployments_storage: DeploymentsStorage::init(
                 deployments_mem,
@@ -71,11 +120,28 @@ impl DataModel {
                 deployments_events_log_data_mem,
                 deployments_event_index_mem,
             ),
-            hub_events_storage: HubEventsStorage::init(hub_events_index_mem, hub_events_data_mem),
+            hub_events_storage: HubEventsStorage::init(
+                hub_events_index_mem,
+                hub_events_data_mem,
+                hub_events_config_event_index_mem,
+            ),
             blocked_contracts_storage: BlockedContractsStorage::init(
                 blocked_contracts_index_mem,
                 blocked_contracts_data_mem,
+                blocked_contracts_block_index_mem,
+                blocked_contracts_block_history_mem,
             ),
+            publishers_storage: PublishersStorage::init(publishers_mem),
+            vouchers_storage: VouchersStorage::init(vouchers_mem, vouchers_usage_mem),
+            sponsors_storage: SponsorsStorage::init(sponsors_mem, sponsored_deployments_index_mem),
+            deployer_bans_storage: DeployerBansStorage::init(deployer_bans_mem),
+            deployment_rate_limits_storage: DeploymentRateLimitsStorage::init(
+                rate_limits_deployer_starts_mem,
+                rate_limits_contract_template_starts_mem,
+                rate_limits_hub_starts_mem,
+            ),
+            proposals_storage: ProposalsStorage::init(proposals_mem),
+            schema_storage: SchemaStorage::init(schema_mem),
             wasm_storage: WasmStorage::default(),
             deployments_signature_map: SignatureMap::default(),
         }
@@ -129,6 +195,64 @@ impl DataModel {
         &mut self.blocked_contracts_storage
     }
 
+    pub(crate) fn get_publishers_storage(&self) -> &PublishersStorage {
+        &self.publishers_storage
+    }
+
+    pub(crate) fn get_publishers_storage_mut(&mut self) -> &mut PublishersStorage {
+        &mut self.publishers_storage
+    }
+
+    pub(crate) fn get_vouchers_storage(&self) -> &VouchersStorage {
+        &self.vouchers_storage
+    }
+
+    pub(crate) fn get_vouchers_storage_mut(&mut self) -> &mut VouchersStorage {
+        &mut self.vouchers_storage
+    }
+
+    pub(crate) fn get_sponsors_storage(&self) -> &SponsorsStorage {
+        &self.sponsors_storage
+    }
+
+    pub(crate) fn get_sponsors_storage_mut(&mut self) -> &mut SponsorsStorage {
+        &mut self.sponsors_storage
+    }
+
+    pub(crate) fn get_deployer_bans_storage(&self) -> &DeployerBansStorage {
+        &self.deployer_bans_storage
+    }
+
+    pub(crate) fn get_deployer_bans_storage_mut(&mut self) -> &mut DeployerBansStorage {
+        &mut self.deployer_bans_storage
+    }
+
+    pub(crate) fn get_deployment_rate_limits_storage(&self) -> &DeploymentRateLimitsStorage {
+        &self.deployment_rate_limits_storage
+    }
+
+    pub(crate) fn get_deployment_rate_limits_storage_mut(
+        &mut self,
+    ) -> &mut DeploymentRateLimitsStorage {
+        &mut self.deployment_rate_limits_storage
+    }
+
+    pub(crate) fn get_proposals_storage(&self) -> &ProposalsStorage {
+        &self.proposals_storage
+    }
+
+    pub(crate) fn get_proposals_storage_mut(&mut self) -> &mut ProposalsStorage {
+        &mut self.proposals_storage
+    }
+
+    pub(crate) fn get_schema_storage(&self) -> &SchemaStorage {
+        &self.schema_storage
+    }
+
+    pub(crate) fn get_schema_storage_mut(&mut self) -> &mut SchemaStorage {
+        &mut self.schema_storage
+    }
+
     pub(crate) fn get_deployments_signature_map(&self) -> &SignatureMap {
         &self.deployments_signature_map
     }
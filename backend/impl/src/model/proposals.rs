@@ -0,0 +1,36 @@
+use common_canister_impl::stable_structures::CBor;
+use hub_canister_api::types::{Proposal, ProposalId};
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap,
+};
+
+type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
+type ProposalsTable = StableBTreeMap<ProposalId, CBor<Proposal>, VM>;
+
+pub struct ProposalsStorage {
+    proposals_table: ProposalsTable,
+}
+
+impl ProposalsStorage {
+    pub(crate) fn init(proposals_memory: VM) -> Self {
+        Self {
+            proposals_table: StableBTreeMap::init(proposals_memory),
+        }
+    }
+
+    pub(crate) fn add_proposal(&mut self, proposal: Proposal) -> ProposalId {
+        let proposal_id = self.proposals_table.len();
+        self.proposals_table.insert(proposal_id, CBor(proposal));
+        proposal_id
+    }
+
+    pub(crate) fn get_proposal(&self, proposal_id: &ProposalId) -> Option<Proposal> {
+        self.proposals_table
+            .get(proposal_id)
+            .map(|proposal| Proposal::clone(&proposal))
+    }
+
+    pub(crate) fn set_proposal(&mut self, proposal_id: ProposalId, proposal: Proposal) {
+        self.proposals_table.insert(proposal_id, CBor(proposal));
+    }
+}
@@ -0,0 +1,72 @@
+use candid::Principal;
+use common_canister_impl::stable_structures::CBor;
+use common_canister_types::TimestampMillis;
+use hub_canister_api::types::PublisherSettings;
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap,
+};
+use serde::{Deserialize, Serialize};
+
+type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
+type PublishersTable = StableBTreeMap<Principal, CBor<PublisherModel>, VM>;
+
+pub struct PublishersStorage {
+    publishers_table: PublishersTable,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PublisherModel {
+    pub registered: TimestampMillis,
+    pub settings: PublisherSettings,
+    pub contract_templates_count: usize,
+    pub total_wasm_length: usize,
+}
+
+impl PublisherModel {
+    pub(crate) fn get_available_wasm_length(&self) -> usize {
+        self.settings
+            .max_total_wasm_length
+            .saturating_sub(self.total_wasm_length)
+    }
+}
+
+impl PublishersStorage {
+    pub(crate) fn init(publishers_memory: VM) -> Self {
+        Self {
+            publishers_table: StableBTreeMap::init(publishers_memory),
+        }
+    }
+
+    pub(crate) fn add_publisher(&mut self, publisher: Principal, model: PublisherModel) {
+        self.publishers_table.insert(publisher, CBor(model));
+    }
+
+    pub(crate) fn set_publisher_settings(
+        &mut self,
+        publisher: &Principal,
+        settings: PublisherSettings,
+    ) {
+        if let Some(model) = self.publishers_table.get(publisher) {
+            let mut model = model.to_owned();
+            model.settings = settings;
+            self.publishers_table.insert(*publisher, CBor(model));
+        }
+    }
+
+    pub(crate) fn remove_publisher(&mut self, publisher: &Principal) -> bool {
+        self.publishers_table.remove(publisher).is_some()
+    }
+
+    pub(crate) fn contract_template_added(&mut self, publisher: &Principal, wasm_length: usize) {
+        if let Some(model) = self.publishers_table.get(publisher) {
+            let mut model = model.to_owned();
+            model.contract_templates_count += 1;
+            model.total_wasm_length += wasm_length;
+            self.publishers_table.insert(*publisher, CBor(model));
+        }
+    }
+
+    pub(crate) fn get_publisher(&self, publisher: &Principal) -> Option<CBor<PublisherModel>> {
+        self.publishers_table.get(publisher)
+    }
+}
@@ -0,0 +1,113 @@
+use candid::Principal;
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::types::DeploymentId;
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap,
+};
+
+type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
+type DeployerStartsLog = StableBTreeMap<(Principal, TimestampMillis, DeploymentId), (), VM>;
+type ContractTemplateStartsLog =
+    StableBTreeMap<(ContractTemplateId, TimestampMillis, DeploymentId), (), VM>;
+type HubStartsLog =
+    StableBTreeMap<(TimestampMillis, DeploymentId), (Principal, ContractTemplateId), VM>;
+
+const MAX_PRUNED_STARTS: usize = 100;
+
+/// Start times of the recent deployments, the counters of the deployment rate limits.
+pub struct DeploymentRateLimitsStorage {
+    deployer_starts: DeployerStartsLog,
+    contract_template_starts: ContractTemplateStartsLog,
+    hub_starts: HubStartsLog,
+}
+
+impl DeploymentRateLimitsStorage {
+    pub(crate) fn init(
+        deployer_starts_memory: VM,
+        contract_template_starts_memory: VM,
+        hub_starts_memory: VM,
+    ) -> Self {
+        Self {
+            deployer_starts: StableBTreeMap::init(deployer_starts_memory),
+            contract_template_starts: StableBTreeMap::init(contract_template_starts_memory),
+            hub_starts: StableBTreeMap::init(hub_starts_memory),
+        }
+    }
+
+    /// Registers the deployment start and forgets the starts older than the retention.
+    /// The zero retention means there are no limits to count the start for.
+    pub(crate) fn deployment_started(
+        &mut self,
+        time: TimestampMillis,
+        deployer: Principal,
+        contract_template_id: ContractTemplateId,
+        deployment_id: DeploymentId,
+        retention: TimestampMillis,
+    ) {
+        self.prune_expired_starts(time.saturating_sub(retention));
+
+        if retention == 0 {
+            return;
+        }
+
+        self.deployer_starts
+            .insert((deployer, time, deployment_id), ());
+        self.contract_template_starts
+            .insert((contract_template_id, time, deployment_id), ());
+        self.hub_starts
+            .insert((time, deployment_id), (deployer, contract_template_id));
+    }
+
+    /// Forgets the oldest starts of all the deployers and contract templates. The number
+    /// of the starts forgotten at once is bounded, the rest is forgotten by the next starts.
+    fn prune_expired_starts(&mut self, expired: TimestampMillis) {
+        let expired_starts: Vec<_> = self
+            .hub_starts
+            .range(..(expired, 0))
+            .take(MAX_PRUNED_STARTS)
+            .map(|entry| (*entry.key(), entry.value()))
+            .collect();
+
+        for ((time, deployment_id), (deployer, contract_template_id)) in expired_starts {
+            self.hub_starts.remove(&(time, deployment_id));
+            self.deployer_starts
+                .remove(&(deployer, time, deployment_id));
+            self.contract_template_starts
+                .remove(&(contract_template_id, time, deployment_id));
+        }
+    }
+
+    /// Returns the ascending start times of the deployer's deployments since the time.
+    pub(crate) fn get_deployer_starts(
+        &self,
+        deployer: Principal,
+        since: TimestampMillis,
+    ) -> Vec<TimestampMillis> {
+        self.deployer_starts
+            .keys_range((deployer, since, 0)..=(deployer, TimestampMillis::MAX, u64::MAX))
+            .map(|(_, time, _)| time)
+            .collect()
+    }
+
+    pub(crate) fn get_contract_template_starts(
+        &self,
+        contract_template_id: ContractTemplateId,
+        since: TimestampMillis,
+    ) -> Vec<TimestampMillis> {
+        self.contract_template_starts
+            .keys_range(
+                (contract_template_id, since, 0)
+                    ..=(contract_template_id, TimestampMillis::MAX, u64::MAX),
+            )
+            .map(|(_, time, _)| time)
+            .collect()
+    }
+
+    pub(crate) fn get_hub_starts(&self, since: TimestampMillis) -> Vec<TimestampMillis> {
+        self.hub_starts
+            .keys_range((since, 0)..)
+            .map(|(time, _)| time)
+            .collect()
+    }
+}
@@ -0,0 +1,27 @@
+use hub_canister_api::types::SchemaVersion;
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableCell,
+};
+
+type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
+
+/// The stable state stored before the versioning has the zero version.
+pub struct SchemaStorage {
+    version: StableCell<SchemaVersion, VM>,
+}
+
+impl SchemaStorage {
+    pub(crate) fn init(memory: VM) -> Self {
+        Self {
+            version: StableCell::init(memory, 0),
+        }
+    }
+
+    pub(crate) fn get_version(&self) -> SchemaVersion {
+        *self.version.get()
+    }
+
+    pub(crate) fn set_version(&mut self, version: SchemaVersion) {
+        self.version.set(version);
+    }
+}
@@ -0,0 +1,183 @@
+use candid::Principal;
+use common_canister_impl::stable_structures::CBor;
+use common_canister_types::{TimestampMillis, TokenE8s};
+use hub_canister_api::types::{DeploymentId, SponsorshipSettings};
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap,
+};
+use serde::{Deserialize, Serialize};
+
+type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
+type SponsorsTable = StableBTreeMap<Principal, CBor<SponsorModel>, VM>;
+type SponsoredDeploymentsIndex =
+    StableBTreeMap<(Principal, DeploymentId), CBor<SponsoredDeploymentModel>, VM>;
+
+pub struct SponsorsStorage {
+    sponsors_table: SponsorsTable,
+    sponsored_deployments_index: SponsoredDeploymentsIndex,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SponsorModel {
+    pub registered: TimestampMillis,
+    pub settings: SponsorshipSettings,
+    pub reserved_amount: TokenE8s,
+    pub charged_amount: TokenE8s,
+    pub deployments_count: usize,
+}
+
+impl SponsorModel {
+    pub(crate) fn get_available_budget(&self) -> TokenE8s {
+        self.settings
+            .budget
+            .saturating_sub(self.reserved_amount)
+            .saturating_sub(self.charged_amount)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SponsoredDeploymentModel {
+    pub amount: TokenE8s,
+    pub charged: bool,
+}
+
+impl SponsorsStorage {
+    pub(crate) fn init(sponsors_memory: VM, sponsored_deployments_index_memory: VM) -> Self {
+        Self {
+            sponsors_table: StableBTreeMap::init(sponsors_memory),
+            sponsored_deployments_index: StableBTreeMap::init(sponsored_deployments_index_memory),
+        }
+    }
+
+    /// Registers the sponsor or replaces the settings, keeping the spent amounts.
+    pub(crate) fn set_sponsorship(
+        &mut self,
+        sponsor: Principal,
+        time: TimestampMillis,
+        settings: SponsorshipSettings,
+    ) {
+        let model = match self.sponsors_table.get(&sponsor) {
+            Some(model) => SponsorModel {
+                settings,
+                ..model.to_owned()
+            },
+            None => SponsorModel {
+                registered: time,
+                settings,
+                reserved_amount: 0,
+                charged_amount: 0,
+                deployments_count: 0,
+            },
+        };
+        self.sponsors_table.insert(sponsor, CBor(model));
+    }
+
+    pub(crate) fn remove_sponsorship(&mut self, sponsor: &Principal) -> bool {
+        self.sponsors_table.remove(sponsor).is_some()
+    }
+
+    pub(crate) fn get_sponsorship(&self, sponsor: &Principal) -> Option<CBor<SponsorModel>> {
+        self.sponsors_table.get(sponsor)
+    }
+
+    /// Reserves the deployment amount until the funds are transferred from the sponsor.
+    pub(crate) fn deployment_sponsored(
+        &mut self,
+        sponsor: Principal,
+        deployment_id: DeploymentId,
+        amount: TokenE8s,
+    ) {
+        if let Some(model) = self.sponsors_table.get(&sponsor) {
+            let mut model = model.to_owned();
+            model.reserved_amount += amount;
+            model.deployments_count += 1;
+            self.sponsors_table.insert(sponsor, CBor(model));
+        }
+        self.sponsored_deployments_index.insert(
+            (sponsor, deployment_id),
+            CBor(SponsoredDeploymentModel {
+                amount,
+                charged: false,
+            }),
+        );
+    }
+
+    pub(crate) fn sponsored_deployment_charged(
+        &mut self,
+        sponsor: Principal,
+        deployment_id: DeploymentId,
+    ) {
+        self.complete_reservation(sponsor, deployment_id, true);
+    }
+
+    /// Gives the reserved amount back, if the deployment is cancelled before the funds transfer.
+    pub(crate) fn sponsored_deployment_released(
+        &mut self,
+        sponsor: Principal,
+        deployment_id: DeploymentId,
+    ) {
+        self.complete_reservation(sponsor, deployment_id, false);
+    }
+
+    fn complete_reservation(
+        &mut self,
+        sponsor: Principal,
+        deployment_id: DeploymentId,
+        charged: bool,
+    ) {
+        let key = (sponsor, deployment_id);
+        let Some(sponsored_deployment) = self.sponsored_deployments_index.get(&key) else {
+            return;
+        };
+        let mut sponsored_deployment = sponsored_deployment.to_owned();
+        if sponsored_deployment.charged {
+            return;
+        }
+
+        if let Some(model) = self.sponsors_table.get(&sponsor) {
+            let mut model = model.to_owned();
+            model.reserved_amount = model
+                .reserved_amount
+                .saturating_sub(sponsored_deployment.amount);
+            if charged {
+                model.charged_amount += sponsored_deployment.amount;
+            }
+            self.sponsors_table.insert(sponsor, CBor(model));
+        }
+
+        if charged {
+            sponsored_deployment.charged = true;
+        } else {
+            sponsored_deployment.amount = 0;
+        }
+        self.sponsored_deployments_index
+            .insert(key, CBor(sponsored_deployment));
+    }
+
+    pub(crate) fn iterate_by_sponsor<F>(
+        &self,
+        sponsor: Principal,
+        descending: bool,
+        mut receiver: F,
+    ) where
+        F: FnMut(DeploymentId) -> bool,
+    {
+        let mut iter = self
+            .sponsored_deployments_index
+            .keys_range((sponsor, 0)..(sponsor, u64::MAX));
+
+        if descending {
+            while let Some(key) = iter.next_back() {
+                if !receiver(key.1) {
+                    break;
+                }
+            }
+        } else {
+            for key in iter {
+                if !receiver(key.1) {
+                    break;
+                }
+            }
+        }
+    }
+}
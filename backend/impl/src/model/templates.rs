@@ -1,8 +1,11 @@
 use candid::Principal;
 use common_canister_impl::stable_structures::CBor;
-use common_canister_types::{TimestampMillis, Timestamped};
+use common_canister_types::{TimestampMillis, Timestamped, TokenE8s};
 use common_contract_api::ContractTemplateId;
-use hub_canister_api::types::ContractTemplateDefinition;
+use hub_canister_api::types::{
+    ContractTemplateDefinition, ContractTemplateMetadata, ContractTemplateReview,
+    ContractTemplateReviewStatus, ContractTemplateVisibility, PublisherEarnings,
+};
 use ic_stable_structures::{
     btreemap::Iter, memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory,
     StableBTreeMap,
@@ -12,10 +15,14 @@ use serde::{Deserialize, Serialize};
 type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
 type ContractsTable = StableBTreeMap<ContractTemplateId, CBor<ContractTemplateModel>, VM>;
 type WasmTable = StableBTreeMap<ContractTemplateId, Vec<u8>, VM>;
+type WasmMetadataTable = StableBTreeMap<ContractTemplateId, CBor<ContractTemplateWasmMetadata>, VM>;
+type SearchIndexTable = StableBTreeMap<ContractTemplateId, CBor<ContractTemplateSearchIndex>, VM>;
 
 pub struct ContractTemplatesStorage {
     contract_templates_table: ContractsTable,
     wasm_table: WasmTable,
+    wasm_metadata_table: WasmMetadataTable,
+    search_index_table: SearchIndexTable,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -24,16 +31,108 @@ pub struct ContractTemplateModel {
     pub registered: TimestampMillis,
     pub definition: ContractTemplateDefinition,
     pub blocked: Option<Timestamped<String>>,
+    /// The retirement is effective since its time, which could be in the future.
     #[serde(default)]
     pub retired: Option<Timestamped<String>>,
+    #[serde(default)]
+    pub replacement: Option<ContractTemplateId>,
     pub deployments_count: usize,
+    #[serde(default)]
+    pub reserved_deployments_count: usize,
+    #[serde(default = "approved_before_review")]
+    pub review: ContractTemplateReview,
+    #[serde(default)]
+    pub publisher_earnings: PublisherEarnings,
+    #[serde(default)]
+    pub visibility: ContractTemplateVisibility,
+}
+
+impl ContractTemplateModel {
+    pub(crate) fn is_retired(&self, time: TimestampMillis) -> bool {
+        self.retired
+            .as_ref()
+            .is_some_and(|retired| retired.timestamp <= time)
+    }
+}
+
+/// Contract templates registered before the review workflow have been published already.
+fn approved_before_review() -> ContractTemplateReview {
+    ContractTemplateReview {
+        status: ContractTemplateReviewStatus::Approved,
+        updated: 0,
+        reviewer: None,
+        rejection_reason: None,
+        notes: vec![],
+        attestations: vec![],
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ContractTemplateWasmMetadata {
+    pub compressed: bool,
+    pub exported_methods: Vec<String>,
+    pub custom_sections: Vec<WasmCustomSection>,
+}
+
+/// Canister metadata section (`icp:public <name>` or `icp:private <name>`) of the contract wasm.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WasmCustomSection {
+    pub name: String,
+    pub public: bool,
+    #[serde(with = "serde_bytes")]
+    pub content: Vec<u8>,
+}
+
+/// Normalized searchable fields of the contract template definition.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ContractTemplateSearchIndex {
+    pub texts: Vec<String>,
+    pub categories: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl ContractTemplateSearchIndex {
+    fn new(definition: &ContractTemplateDefinition) -> Self {
+        let mut texts = vec![
+            definition.name.to_lowercase(),
+            definition.short_description.to_lowercase(),
+        ];
+        if let Some(long_description) = definition.long_description.as_ref() {
+            texts.push(long_description.to_lowercase());
+        }
+
+        Self {
+            texts,
+            categories: definition.categories.clone().unwrap_or_default(),
+            tags: definition.tags.clone().unwrap_or_default(),
+        }
+    }
 }
 
 impl ContractTemplatesStorage {
-    pub(crate) fn init(contract_templates_memory: VM, wasm_memory: VM) -> Self {
+    pub(crate) fn init(
+        contract_templates_memory: VM,
+        wasm_memory: VM,
+        wasm_metadata_memory: VM,
+        search_index_memory: VM,
+    ) -> Self {
         Self {
             contract_templates_table: StableBTreeMap::init(contract_templates_memory),
             wasm_table: StableBTreeMap::init(wasm_memory),
+            wasm_metadata_table: StableBTreeMap::init(wasm_metadata_memory),
+            search_index_table: StableBTreeMap::init(search_index_memory),
+        }
+    }
+
+    /// Indexes the contract templates without the search index.
+    pub(crate) fn build_missing_search_index(&mut self) {
+        for entry in self.contract_templates_table.iter() {
+            if !self.search_index_table.contains_key(entry.key()) {
+                self.search_index_table.insert(
+                    *entry.key(),
+                    CBor(ContractTemplateSearchIndex::new(&entry.value().definition)),
+                );
+            }
         }
     }
 
@@ -43,19 +142,38 @@ impl ContractTemplatesStorage {
         registered: TimestampMillis,
         definition: ContractTemplateDefinition,
         wasm: Vec<u8>,
+        wasm_metadata: ContractTemplateWasmMetadata,
     ) -> ContractTemplateId {
         let contract_template_id = self.contract_templates_table.len();
+        self.search_index_table.insert(
+            contract_template_id,
+            CBor(ContractTemplateSearchIndex::new(&definition)),
+        );
         let contract_template = ContractTemplateModel {
             registrar,
             registered,
             definition,
             blocked: None,
             retired: None,
+            replacement: None,
             deployments_count: 0,
+            reserved_deployments_count: 0,
+            publisher_earnings: PublisherEarnings::default(),
+            visibility: ContractTemplateVisibility::Public,
+            review: ContractTemplateReview {
+                status: ContractTemplateReviewStatus::Submitted,
+                updated: registered,
+                reviewer: None,
+                rejection_reason: None,
+                notes: vec![],
+                attestations: vec![],
+            },
         };
         self.contract_templates_table
             .insert(contract_template_id, CBor(contract_template));
         self.wasm_table.insert(contract_template_id, wasm);
+        self.wasm_metadata_table
+            .insert(contract_template_id, CBor(wasm_metadata));
         contract_template_id
     }
 
@@ -63,10 +181,57 @@ impl ContractTemplatesStorage {
         &mut self,
         contract_template_id: &ContractTemplateId,
         retired: Option<Timestamped<String>>,
+        replacement: Option<ContractTemplateId>,
     ) {
         if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
             let mut contract_template = contract_template.to_owned();
             contract_template.retired = retired;
+            contract_template.replacement = replacement;
+            self.contract_templates_table
+                .insert(*contract_template_id, CBor(contract_template));
+        }
+    }
+
+    pub(crate) fn set_visibility(
+        &mut self,
+        contract_template_id: &ContractTemplateId,
+        visibility: ContractTemplateVisibility,
+    ) {
+        if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
+            let mut contract_template = contract_template.to_owned();
+            contract_template.visibility = visibility;
+            self.contract_templates_table
+                .insert(*contract_template_id, CBor(contract_template));
+        }
+    }
+
+    pub(crate) fn set_review(
+        &mut self,
+        contract_template_id: &ContractTemplateId,
+        review: ContractTemplateReview,
+    ) {
+        if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
+            let mut contract_template = contract_template.to_owned();
+            contract_template.review = review;
+            self.contract_templates_table
+                .insert(*contract_template_id, CBor(contract_template));
+        }
+    }
+
+    pub(crate) fn set_metadata(
+        &mut self,
+        contract_template_id: &ContractTemplateId,
+        metadata: ContractTemplateMetadata,
+    ) {
+        if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
+            let mut contract_template = contract_template.to_owned();
+            contract_template.definition.set_metadata(metadata);
+            self.search_index_table.insert(
+                *contract_template_id,
+                CBor(ContractTemplateSearchIndex::new(
+                    &contract_template.definition,
+                )),
+            );
             self.contract_templates_table
                 .insert(*contract_template_id, CBor(contract_template));
         }
@@ -87,10 +252,55 @@ impl ContractTemplatesStorage {
         }
     }
 
+    /// Reserves the supply of the contract template for the in-flight deployment.
+    pub(crate) fn deployment_reserved(&mut self, contract_template_id: &ContractTemplateId) {
+        if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
+            let mut contract_template = contract_template.to_owned();
+            contract_template.reserved_deployments_count += 1;
+            self.contract_templates_table
+                .insert(*contract_template_id, CBor(contract_template));
+        }
+    }
+
+    pub(crate) fn deployment_reservation_released(
+        &mut self,
+        contract_template_id: &ContractTemplateId,
+    ) {
+        if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
+            let mut contract_template = contract_template.to_owned();
+            contract_template.reserved_deployments_count = contract_template
+                .reserved_deployments_count
+                .saturating_sub(1);
+            self.contract_templates_table
+                .insert(*contract_template_id, CBor(contract_template));
+        }
+    }
+
+    /// The reservation of the deployment turns into the deployed contract.
     pub(crate) fn contract_deployed(&mut self, contract_template_id: &ContractTemplateId) {
         if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
             let mut contract_template = contract_template.to_owned();
             contract_template.deployments_count += 1;
+            contract_template.reserved_deployments_count = contract_template
+                .reserved_deployments_count
+                .saturating_sub(1);
+            self.contract_templates_table
+                .insert(*contract_template_id, CBor(contract_template));
+        }
+    }
+
+    pub(crate) fn publisher_fee_paid(
+        &mut self,
+        contract_template_id: &ContractTemplateId,
+        time: TimestampMillis,
+        amount: TokenE8s,
+    ) {
+        if let Some(contract_template) = self.contract_templates_table.get(contract_template_id) {
+            let mut contract_template = contract_template.to_owned();
+            let earnings = &mut contract_template.publisher_earnings;
+            earnings.total_amount += amount;
+            earnings.payouts_count += 1;
+            earnings.last_payout = Some(time);
             self.contract_templates_table
                 .insert(*contract_template_id, CBor(contract_template));
         }
@@ -110,6 +320,20 @@ impl ContractTemplatesStorage {
         self.wasm_table.get(contract_template_id)
     }
 
+    pub(crate) fn get_contract_template_wasm_metadata(
+        &self,
+        contract_template_id: &ContractTemplateId,
+    ) -> Option<CBor<ContractTemplateWasmMetadata>> {
+        self.wasm_metadata_table.get(contract_template_id)
+    }
+
+    pub(crate) fn get_search_index(
+        &self,
+        contract_template_id: &ContractTemplateId,
+    ) -> Option<CBor<ContractTemplateSearchIndex>> {
+        self.search_index_table.get(contract_template_id)
+    }
+
     pub(crate) fn get_iter(&self) -> Iter<'_, ContractTemplateId, CBor<ContractTemplateModel>, VM> {
         self.contract_templates_table.iter()
     }
@@ -0,0 +1,99 @@
+use common_canister_impl::stable_structures::CBor;
+use common_canister_types::TimestampMillis;
+use hub_canister_api::types::{VoucherCode, VoucherDefinition};
+use ic_stable_structures::{
+    memory_manager::VirtualMemory, DefaultMemoryImpl, RestrictedMemory, StableBTreeMap, StableCell,
+};
+use serde::{Deserialize, Serialize};
+
+type VM = VirtualMemory<RestrictedMemory<DefaultMemoryImpl>>;
+type VouchersTable = StableBTreeMap<VoucherCode, CBor<VoucherModel>, VM>;
+type VouchersUsageCell = StableCell<CBor<VouchersUsage>, VM>;
+
+pub struct VouchersStorage {
+    vouchers_table: VouchersTable,
+    usage: VouchersUsageCell,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VoucherModel {
+    pub created: TimestampMillis,
+    pub definition: VoucherDefinition,
+    pub redemptions_count: usize,
+    pub subsidized_cycles: u128,
+}
+
+/// Cycles spent by the hub on all vouchers, including removed ones.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+struct VouchersUsage {
+    subsidized_cycles: u128,
+}
+
+impl VouchersStorage {
+    pub(crate) fn init(vouchers_memory: VM, usage_memory: VM) -> Self {
+        Self {
+            vouchers_table: StableBTreeMap::init(vouchers_memory),
+            usage: StableCell::init(usage_memory, CBor(VouchersUsage::default())),
+        }
+    }
+
+    pub(crate) fn set_voucher(
+        &mut self,
+        code: VoucherCode,
+        time: TimestampMillis,
+        definition: VoucherDefinition,
+    ) {
+        let model = match self.vouchers_table.get(&code) {
+            Some(model) => VoucherModel {
+                definition,
+                ..model.to_owned()
+            },
+            None => VoucherModel {
+                created: time,
+                definition,
+                redemptions_count: 0,
+                subsidized_cycles: 0,
+            },
+        };
+        self.vouchers_table.insert(code, CBor(model));
+    }
+
+    pub(crate) fn remove_voucher(&mut self, code: &VoucherCode) -> bool {
+        self.vouchers_table.remove(code).is_some()
+    }
+
+    pub(crate) fn get_voucher(&self, code: &VoucherCode) -> Option<CBor<VoucherModel>> {
+        self.vouchers_table.get(code)
+    }
+
+    pub(crate) fn get_subsidized_cycles(&self) -> u128 {
+        self.usage.get().subsidized_cycles
+    }
+
+    pub(crate) fn voucher_redeemed(&mut self, code: &VoucherCode, subsidized_cycles: u128) {
+        if let Some(model) = self.vouchers_table.get(code) {
+            let mut model = model.to_owned();
+            model.redemptions_count += 1;
+            model.subsidized_cycles += subsidized_cycles;
+            self.vouchers_table.insert(code.clone(), CBor(model));
+        }
+        self.add_subsidized_cycles(subsidized_cycles as i128);
+    }
+
+    /// Returns the redemption of the deployment cancelled before the contract canister creation.
+    pub(crate) fn voucher_released(&mut self, code: &VoucherCode, subsidized_cycles: u128) {
+        if let Some(model) = self.vouchers_table.get(code) {
+            let mut model = model.to_owned();
+            model.redemptions_count = model.redemptions_count.saturating_sub(1);
+            model.subsidized_cycles = model.subsidized_cycles.saturating_sub(subsidized_cycles);
+            self.vouchers_table.insert(code.clone(), CBor(model));
+        }
+        self.add_subsidized_cycles(-(subsidized_cycles as i128));
+    }
+
+    fn add_subsidized_cycles(&mut self, cycles: i128) {
+        let mut usage = VouchersUsage::clone(self.usage.get());
+        usage.subsidized_cycles = usage.subsidized_cycles.saturating_add_signed(cycles);
+        self.usage.set(CBor(usage));
+    }
+}
@@ -1,3 +1,4 @@
+use candid::Principal;
 use hub_canister_api::types::UploadWasmGrant;
 use serde::{Deserialize, Serialize};
 
@@ -8,13 +9,19 @@ pub struct WasmStorage {
 
 #[derive(Serialize, Deserialize)]
 pub struct CurrentUploadWasm {
+    granted_by: Principal,
     grant: UploadWasmGrant,
     wasm: Vec<u8>,
 }
 
 impl WasmStorage {
-    pub(crate) fn set_upload_wasm_grant(&mut self, upload_grant: Option<UploadWasmGrant>) {
+    pub(crate) fn set_upload_wasm_grant(
+        &mut self,
+        granted_by: Principal,
+        upload_grant: Option<UploadWasmGrant>,
+    ) {
         self.current_upload_wasm = upload_grant.map(|grant| CurrentUploadWasm {
+            granted_by,
             grant,
             wasm: Vec::new(),
         });
@@ -24,6 +31,11 @@ impl WasmStorage {
         self.current_upload_wasm.as_ref().map(|op| &op.grant)
     }
 
+    /// Returns the caller, who has set the current upload wasm grant.
+    pub(crate) fn get_upload_wasm_grantor(&self) -> Option<&Principal> {
+        self.current_upload_wasm.as_ref().map(|op| &op.granted_by)
+    }
+
     pub(crate) fn get_upload_wasm_mut_unsafe(&mut self) -> &mut Vec<u8> {
         self.current_upload_wasm.as_mut().unwrap().wasm.as_mut()
     }
@@ -5,11 +5,13 @@ use ic_cdk_macros::query;
 #[query]
 fn get_access_rights(_args: Args) -> Response {
     read_state(|state| {
+        let access_control = state
+            .get_model()
+            .get_access_rights_storage()
+            .get_access_control();
         GetAccessRightsResponse::Ok(GetAccessRightsResult {
-            access_rights: state
-                .get_model()
-                .get_access_rights_storage()
-                .get_access_rights()
+            access_rights: access_control
+                .access_rights
                 .iter()
                 .map(|(caller, (permissions, description))| AccessRight {
                     caller: *caller,
@@ -17,6 +19,8 @@ fn get_access_rights(_args: Args) -> Response {
                     description: description.clone(),
                 })
                 .collect(),
+            roles: access_control.get_roles(),
+            role_assignments: access_control.get_all_role_assignments(),
         })
     })
 }
@@ -5,8 +5,10 @@ use ic_cdk_macros::query;
 #[query]
 fn get_config(_args: Args) -> Response {
     read_state(|state| {
+        let config_storage = state.get_model().get_config_storage();
         GetConfigResponse::Ok(GetConfigResult {
-            config: state.get_model().get_config_storage().get_config().clone(),
+            config: config_storage.get_config().clone(),
+            config_version: config_storage.get_config_version(),
         })
     })
 }
@@ -0,0 +1,58 @@
+use crate::read_state;
+use hub_canister_api::{
+    get_config_at_event::*,
+    types::{Config, HubEventType},
+};
+use ic_cdk_macros::query;
+
+#[query]
+fn get_config_at_event(Args { event_id }: Args) -> Response {
+    get_config_at_event_int(event_id).into()
+}
+
+/// Replays the config patches over the latest full config set before the event,
+/// or over the default config installed with the hub.
+pub(crate) fn get_config_at_event_int(
+    event_id: u64,
+) -> Result<GetConfigAtEventResult, GetConfigAtEventError> {
+    read_state(|state| {
+        let hub_events_storage = state.get_model().get_hub_events_storage();
+        if event_id >= hub_events_storage.get_hub_events_len() {
+            return Err(GetConfigAtEventError::EventNotFound);
+        }
+
+        let mut config_events = Vec::new();
+        for idx in hub_events_storage
+            .get_config_event_ids(event_id)
+            .into_iter()
+            .rev()
+        {
+            let Some(event) = hub_events_storage.get_hub_event(idx) else {
+                continue;
+            };
+            let is_config_set = matches!(event.event, HubEventType::ConfigSet { .. });
+            config_events.push(event);
+            if is_config_set {
+                break;
+            }
+        }
+
+        let mut config = Config::default();
+        for event in config_events.into_iter().rev() {
+            match &event.event {
+                HubEventType::ConfigSet {
+                    config: event_config,
+                } => {
+                    config = Config::clone(event_config);
+                }
+                HubEventType::ConfigPatched { changes, .. } => {
+                    for change in changes {
+                        config.set_field(change.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(GetConfigAtEventResult { config })
+    })
+}
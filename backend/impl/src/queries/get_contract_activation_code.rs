@@ -17,7 +17,8 @@ pub(crate) fn get_contract_activation_code_int(
             .get_deployment(&deployment_id)
             .ok_or(GetContractActivationCodeError::DeploymentNotFound)?;
 
-        if deployment.deployer != get_env().get_ic().get_caller() {
+        // the activation code is only for the contract owner
+        if deployment.get_owner() != get_env().get_ic().get_caller() {
             return Err(GetContractActivationCodeError::PermissionDenied);
         }
 
@@ -27,6 +27,7 @@ pub(crate) fn get_contract_block_status_int(
 
                 Ok(GetContractBlockStatusResult {
                     blocked: blocked_contracts_storage.find_deployment_block(&deployment_id),
+                    history: blocked_contracts_storage.get_deployment_block_history(&deployment_id),
                 })
             }
             ContractBlockFilter::ByContractCanisterId { canister_id } => {
@@ -37,6 +38,7 @@ pub(crate) fn get_contract_block_status_int(
 
                 Ok(GetContractBlockStatusResult {
                     blocked: blocked_contracts_storage.find_deployment_block(&deployment_id),
+                    history: blocked_contracts_storage.get_deployment_block_history(&deployment_id),
                 })
             }
         }
@@ -1,10 +1,16 @@
 use std::ops::Deref;
 
 use common_contract_api::ContractTemplateId;
-use hub_canister_api::get_contract_template::*;
+use hub_canister_api::{get_contract_template::*, types::Permission};
 use ic_cdk_macros::query;
 
-use crate::{handlers::templates::build_contract_template_information, read_state};
+use crate::{
+    handlers::templates::{
+        build_contract_template_information, build_contract_template_interface_summary,
+        is_contract_template_deployable_by,
+    },
+    is_caller_has_access_right, read_state,
+};
 
 #[query]
 fn get_contract_template(
@@ -18,15 +24,29 @@ fn get_contract_template(
 pub(crate) fn get_contract_template_int(
     contract_template_id: ContractTemplateId,
 ) -> Result<GetContractTemplateResult, GetContractTemplateError> {
+    let is_admin = is_caller_has_access_right(&Permission::SetContractTemplateMetadata);
+
     read_state(|state| {
-        state
-            .get_model()
-            .get_contract_templates_storage()
+        let caller = state.get_env().get_ic().get_caller();
+        let time = state
+            .get_env()
+            .get_time()
+            .get_current_unix_epoch_time_millis();
+        let storage = state.get_model().get_contract_templates_storage();
+        storage
             .get_contract_template(&contract_template_id)
+            // restricted contract templates are not revealed to the other callers
+            .filter(|model| is_admin || is_contract_template_deployable_by(model, &caller))
             .map(|model| GetContractTemplateResult {
                 contract_template: build_contract_template_information(
                     &contract_template_id,
                     model.deref(),
+                    storage
+                        .get_contract_template_wasm_metadata(&contract_template_id)
+                        .map(|wasm_metadata| {
+                            build_contract_template_interface_summary(&wasm_metadata)
+                        }),
+                    time,
                 ),
             })
             .ok_or(GetContractTemplateError::ContractTemplateNotFound)
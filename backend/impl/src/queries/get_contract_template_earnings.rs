@@ -0,0 +1,38 @@
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{get_contract_template_earnings::*, types::Permission};
+use ic_cdk_macros::query;
+
+use crate::{
+    handlers::templates::is_contract_template_deployable_by, is_caller_has_access_right, read_state,
+};
+
+#[query]
+fn get_contract_template_earnings(
+    Args {
+        contract_template_id,
+    }: Args,
+) -> Response {
+    get_contract_template_earnings_int(contract_template_id).into()
+}
+
+pub(crate) fn get_contract_template_earnings_int(
+    contract_template_id: ContractTemplateId,
+) -> Result<GetContractTemplateEarningsResult, GetContractTemplateEarningsError> {
+    let is_admin = is_caller_has_access_right(&Permission::SetContractTemplateMetadata);
+
+    read_state(|state| {
+        let caller = state.get_env().get_ic().get_caller();
+        state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            // restricted contract templates are not revealed to the other callers
+            .filter(|model| is_admin || is_contract_template_deployable_by(model, &caller))
+            .map(|model| GetContractTemplateEarningsResult {
+                contract_template_id,
+                publisher_fee: model.definition.publisher_fee.clone(),
+                earnings: model.publisher_earnings.clone(),
+            })
+            .ok_or(GetContractTemplateEarningsError::ContractTemplateNotFound)
+    })
+}
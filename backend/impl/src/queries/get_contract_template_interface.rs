@@ -0,0 +1,42 @@
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{get_contract_template_interface::*, types::Permission};
+use ic_cdk_macros::query;
+
+use crate::{
+    handlers::templates::{build_contract_template_interface, is_contract_template_deployable_by},
+    is_caller_has_access_right, read_state,
+};
+
+#[query]
+fn get_contract_template_interface(
+    Args {
+        contract_template_id,
+    }: Args,
+) -> Response {
+    get_contract_template_interface_int(contract_template_id).into()
+}
+
+pub(crate) fn get_contract_template_interface_int(
+    contract_template_id: ContractTemplateId,
+) -> Result<GetContractTemplateInterfaceResult, GetContractTemplateInterfaceError> {
+    let is_admin = is_caller_has_access_right(&Permission::SetContractTemplateMetadata);
+
+    read_state(|state| {
+        let caller = state.get_env().get_ic().get_caller();
+        let storage = state.get_model().get_contract_templates_storage();
+        if !storage
+            .get_contract_template(&contract_template_id)
+            // restricted contract templates are not revealed to the other callers
+            .is_some_and(|model| is_admin || is_contract_template_deployable_by(&model, &caller))
+        {
+            return Err(GetContractTemplateInterfaceError::ContractTemplateNotFound);
+        }
+
+        storage
+            .get_contract_template_wasm_metadata(&contract_template_id)
+            .map(|wasm_metadata| GetContractTemplateInterfaceResult {
+                interface: build_contract_template_interface(&wasm_metadata),
+            })
+            .ok_or(GetContractTemplateInterfaceError::ContractTemplateInterfaceNotFound)
+    })
+}
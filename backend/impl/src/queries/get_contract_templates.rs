@@ -1,14 +1,23 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::BTreeMap};
 
 use crate::{
-    handlers::{deployments::states::get_config, templates::build_contract_template_information},
-    model::templates::ContractTemplateModel,
+    handlers::{
+        deployments::states::get_config,
+        templates::{
+            build_contract_template_information, build_contract_template_interface_summary,
+            is_contract_template_listed_for,
+        },
+    },
+    is_caller_has_access_right,
+    model::templates::{
+        ContractTemplateModel, ContractTemplateSearchIndex, ContractTemplatesStorage,
+    },
     read_state,
 };
 use common_canister_impl::stable_structures::CBor;
-use common_canister_types::{SortingDefinition, SortingOrder};
+use common_canister_types::{SortingDefinition, SortingOrder, TimestampMillis};
 use common_contract_api::ContractTemplateId;
-use hub_canister_api::get_contract_templates::*;
+use hub_canister_api::{get_contract_templates::*, types::Permission};
 use ic_cdk_macros::query;
 
 const MIN_FILTER_TEXT_LENGTH: usize = 3;
@@ -19,11 +28,12 @@ fn get_contract_templates(args: Args) -> Response {
     get_contract_templates_int(args).into()
 }
 
-fn get_contract_templates_int(
+pub(crate) fn get_contract_templates_int(
     Args {
         chunk_def,
         filter,
         sorting,
+        include_interface_summary,
     }: Args,
 ) -> Result<GetContractTemplatesResult, GetContractTemplatesError> {
     let start = chunk_def.start;
@@ -39,49 +49,68 @@ fn get_contract_templates_int(
         order: SortingOrder::Ascending,
     });
 
+    let is_admin = is_caller_has_access_right(&Permission::SetContractTemplateMetadata);
+
     read_state(|state| {
+        let caller = state.get_env().get_ic().get_caller();
+        let time = state
+            .get_env()
+            .get_time()
+            .get_current_unix_epoch_time_millis();
+        let storage = state.get_model().get_contract_templates_storage();
+        let listed = storage
+            .get_iter()
+            .map(|entry| (*entry.key(), entry.value()))
+            .filter(|(_, model)| is_contract_template_listed_for(model, &caller, is_admin));
         let mut contract_templates = match filter.as_ref() {
-            Some(filter) => state
-                .get_model()
-                .get_contract_templates_storage()
-                .get_iter()
-                .map(|entry| (*entry.key(), entry.value()))
-                .filter(create_iter_filter(filter)?)
-                .collect::<Vec<_>>(),
-            None => state
-                .get_model()
-                .get_contract_templates_storage()
-                .get_iter()
-                .map(|entry| (*entry.key(), entry.value()))
+            Some(filter) => listed
+                .filter(create_iter_filter(storage, filter, time)?)
                 .collect::<Vec<_>>(),
+            None => listed.collect::<Vec<_>>(),
         };
 
         contract_templates.sort_by(create_sorting(&sorting));
 
         let total_count = contract_templates.len();
+        let facets = build_facets(storage, &contract_templates);
 
         let contract_templates = contract_templates
             .iter()
             .skip(start)
             .take(count)
-            .map(|(id, model)| build_contract_template_information(id, model))
+            .map(|(id, model)| {
+                let interface_summary = if include_interface_summary.unwrap_or(false) {
+                    storage
+                        .get_contract_template_wasm_metadata(id)
+                        .map(|wasm_metadata| {
+                            build_contract_template_interface_summary(&wasm_metadata)
+                        })
+                } else {
+                    None
+                };
+                build_contract_template_information(id, model, interface_summary, time)
+            })
             .collect();
 
         Ok(GetContractTemplatesResult {
             total_count,
             contract_templates,
+            facets,
         })
     })
 }
 
 #[allow(clippy::type_complexity)]
-fn create_iter_filter(
-    filter: &ContractTemplatesFilter,
+fn create_iter_filter<'a>(
+    storage: &'a ContractTemplatesStorage,
+    filter: &'a ContractTemplatesFilter,
+    time: TimestampMillis,
 ) -> Result<
-    Box<dyn FnMut(&(ContractTemplateId, CBor<ContractTemplateModel>)) -> bool + '_>,
+    Box<dyn FnMut(&(ContractTemplateId, CBor<ContractTemplateModel>)) -> bool + 'a>,
     GetContractTemplatesError,
 > {
     let mut filters: Vec<Box<dyn Fn(&ContractTemplateModel) -> bool>> = Vec::new();
+    let mut index_filters: Vec<Box<dyn Fn(&ContractTemplateSearchIndex) -> bool>> = Vec::new();
     if let Some(text) = filter.filter.as_ref() {
         let text_len = text.len();
         if text_len < MIN_FILTER_TEXT_LENGTH {
@@ -92,15 +121,22 @@ fn create_iter_filter(
         }
 
         let text = text.to_lowercase();
-        filters.push(Box::new(move |contract: &ContractTemplateModel| {
-            let def = &contract.definition;
-            def.name.to_lowercase().contains(&text)
-                || def.short_description.to_lowercase().contains(&text)
-                || def
-                    .long_description
-                    .as_ref()
-                    .map(|desc| desc.to_lowercase().contains(&text))
-                    .unwrap_or(false)
+        index_filters.push(Box::new(move |index: &ContractTemplateSearchIndex| {
+            index.texts.iter().any(|t| t.contains(&text))
+        }));
+    }
+
+    if let Some(category) = filter.category.as_ref() {
+        let category = category.to_lowercase();
+        index_filters.push(Box::new(move |index: &ContractTemplateSearchIndex| {
+            index.categories.contains(&category)
+        }));
+    }
+
+    if let Some(tag) = filter.tag.as_ref() {
+        let tag = tag.to_lowercase();
+        index_filters.push(Box::new(move |index: &ContractTemplateSearchIndex| {
+            index.tags.contains(&tag)
         }));
     }
 
@@ -112,15 +148,51 @@ fn create_iter_filter(
 
     if let Some(retired) = filter.retired {
         filters.push(Box::new(move |contract: &ContractTemplateModel| {
-            contract.retired.is_some() == retired
+            contract.is_retired(time) == retired
+        }));
+    }
+
+    if let Some(review_status) = filter.review_status.clone() {
+        filters.push(Box::new(move |contract: &ContractTemplateModel| {
+            contract.review.status == review_status
         }));
     }
 
-    Ok(Box::new(move |(_, contract)| {
+    Ok(Box::new(move |(id, contract)| {
         filters.iter().all(|f| f(contract))
+            && (index_filters.is_empty()
+                || storage
+                    .get_search_index(id)
+                    .map(|index| index_filters.iter().all(|f| f(&index)))
+                    .unwrap_or(false))
     }))
 }
 
+fn build_facets(
+    storage: &ContractTemplatesStorage,
+    contract_templates: &[(ContractTemplateId, CBor<ContractTemplateModel>)],
+) -> ContractTemplatesFacets {
+    let mut categories: BTreeMap<String, usize> = BTreeMap::new();
+    let mut tags: BTreeMap<String, usize> = BTreeMap::new();
+
+    for index in contract_templates
+        .iter()
+        .filter_map(|(id, _)| storage.get_search_index(id))
+    {
+        for category in index.categories.iter() {
+            *categories.entry(category.clone()).or_default() += 1;
+        }
+        for tag in index.tags.iter() {
+            *tags.entry(tag.clone()).or_default() += 1;
+        }
+    }
+
+    ContractTemplatesFacets {
+        categories: categories.into_iter().collect(),
+        tags: tags.into_iter().collect(),
+    }
+}
+
 pub type SortingFn = Box<
     dyn FnMut(
         &(ContractTemplateId, CBor<ContractTemplateModel>),
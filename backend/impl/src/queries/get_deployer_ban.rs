@@ -0,0 +1,20 @@
+use crate::{get_env, read_state};
+use candid::Principal;
+use hub_canister_api::get_deployer_ban::*;
+use ic_cdk_macros::query;
+
+#[query]
+fn get_deployer_ban(Args { deployer }: Args) -> Response {
+    GetDeployerBanResponse::Ok(get_deployer_ban_int(deployer))
+}
+
+pub(crate) fn get_deployer_ban_int(deployer: Principal) -> GetDeployerBanResult {
+    let time = get_env().get_time().get_current_unix_epoch_time_millis();
+
+    read_state(|state| GetDeployerBanResult {
+        ban: state
+            .get_model()
+            .get_deployer_bans_storage()
+            .find_active_deployer_ban(&deployer, time),
+    })
+}
@@ -0,0 +1,41 @@
+use crate::{
+    handlers::rate_limits::build_deployment_rate_limits_usage, is_caller_has_access_right,
+    read_state,
+};
+use candid::Principal;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{get_deployment_rate_limits_usage::*, types::Permission};
+use ic_cdk_macros::query;
+
+#[query]
+fn get_deployment_rate_limits_usage(
+    Args {
+        deployer,
+        contract_template_id,
+    }: Args,
+) -> Response {
+    get_deployment_rate_limits_usage_int(deployer, contract_template_id).into()
+}
+
+pub(crate) fn get_deployment_rate_limits_usage_int(
+    deployer: Principal,
+    contract_template_id: Option<ContractTemplateId>,
+) -> Result<GetDeploymentRateLimitsUsageResult, GetDeploymentRateLimitsUsageError> {
+    if !is_caller_has_access_right(&Permission::SetConfig) {
+        return Err(GetDeploymentRateLimitsUsageError::PermissionDenied);
+    }
+
+    read_state(|state| {
+        let time = state
+            .get_env()
+            .get_time()
+            .get_current_unix_epoch_time_millis();
+
+        Ok(build_deployment_rate_limits_usage(
+            state.get_model(),
+            deployer,
+            contract_template_id,
+            time,
+        ))
+    })
+}
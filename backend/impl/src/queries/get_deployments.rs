@@ -15,7 +15,7 @@ fn get_deployments(args: Args) -> Response {
     get_deployments_int(args).into()
 }
 
-fn get_deployments_int(
+pub(crate) fn get_deployments_int(
     Args {
         chunk_def,
         selector,
@@ -48,6 +48,9 @@ fn get_deployments_int(
             deployer,
             contract_template_id,
         } => get_deployments_by_deployer(start, count, descending, deployer, contract_template_id),
+        DeploymentsSelector::BySponsor { sponsor } => {
+            get_deployments_by_sponsor(start, count, descending, sponsor)
+        }
     };
 
     let env = get_env();
@@ -157,3 +160,36 @@ fn get_deployments_by_deployer(
         (deployments, total_count)
     })
 }
+
+fn get_deployments_by_sponsor(
+    start: usize,
+    count: usize,
+    descending: bool,
+    sponsor: Principal,
+) -> DeploymentsChunkResult {
+    read_state(|state| {
+        let storage = state.get_model().get_deployments_storage();
+
+        let mut deployments = Vec::with_capacity(count);
+        let mut total_count = 0;
+
+        let receiver = |deployment_id: DeploymentId| {
+            total_count += 1;
+
+            if start < total_count && deployments.len() < count {
+                deployments.push((
+                    deployment_id,
+                    storage.get_deployment(&deployment_id).unwrap(),
+                ));
+            }
+            true
+        };
+
+        state
+            .get_model()
+            .get_sponsors_storage()
+            .iterate_by_sponsor(sponsor, descending, receiver);
+
+        (deployments, total_count)
+    })
+}
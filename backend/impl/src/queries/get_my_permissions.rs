@@ -0,0 +1,35 @@
+use crate::read_state;
+use hub_canister_api::get_my_permissions::*;
+use ic_cdk_macros::query;
+
+#[query]
+fn get_my_permissions(_args: Args) -> Response {
+    GetMyPermissionsResponse::Ok(get_my_permissions_int())
+}
+
+pub(crate) fn get_my_permissions_int() -> GetMyPermissionsResult {
+    read_state(|state| {
+        let env = state.get_env();
+        let caller = env.get_ic().get_caller();
+        let time = env.get_time().get_current_unix_epoch_time_millis();
+        let access_control = state
+            .get_model()
+            .get_access_rights_storage()
+            .get_access_control();
+
+        GetMyPermissionsResult {
+            permissions: access_control.get_permissions(&caller, time),
+            // the expired role assignments grant nothing
+            role_assignments: access_control
+                .get_role_assignments(&caller)
+                .into_iter()
+                .filter(|assignment| {
+                    assignment
+                        .settings
+                        .expiration
+                        .is_none_or(|expiration| expiration > time)
+                })
+                .collect(),
+        }
+    })
+}
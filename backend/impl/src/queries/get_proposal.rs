@@ -0,0 +1,30 @@
+use crate::{get_env, read_state};
+use hub_canister_api::{
+    get_proposal::*,
+    types::{ProposalId, ProposalStatus},
+};
+use ic_cdk_macros::query;
+
+#[query]
+fn get_proposal(Args { proposal_id }: Args) -> Response {
+    get_proposal_int(proposal_id).into()
+}
+
+pub(crate) fn get_proposal_int(
+    proposal_id: ProposalId,
+) -> Result<GetProposalResult, GetProposalError> {
+    let time = get_env().get_time().get_current_unix_epoch_time_millis();
+
+    let mut proposal = read_state(|state| {
+        state
+            .get_model()
+            .get_proposals_storage()
+            .get_proposal(&proposal_id)
+    })
+    .ok_or(GetProposalError::ProposalNotFound)?;
+
+    if proposal.is_expired(time) {
+        proposal.status = ProposalStatus::Expired;
+    }
+    Ok(GetProposalResult { proposal })
+}
@@ -0,0 +1,41 @@
+use crate::{
+    handlers::publishers::build_publisher_information, is_caller_has_access_right, read_state,
+};
+use candid::Principal;
+use hub_canister_api::{get_publisher::*, types::Permission};
+use ic_cdk_macros::query;
+
+#[query]
+fn get_publisher(Args { publisher }: Args) -> Response {
+    get_publisher_int(publisher).into()
+}
+
+pub(crate) fn get_publisher_int(
+    publisher: Principal,
+) -> Result<GetPublisherResult, GetPublisherError> {
+    let is_admin = is_caller_has_access_right(&Permission::SetContractTemplateMetadata);
+
+    read_state(|state| {
+        let caller = state.get_env().get_ic().get_caller();
+        let time = state
+            .get_env()
+            .get_time()
+            .get_current_unix_epoch_time_millis();
+        let model = state.get_model();
+        let publisher_model = model
+            .get_publishers_storage()
+            .get_publisher(&publisher)
+            .ok_or(GetPublisherError::PublisherNotFound)?;
+
+        Ok(GetPublisherResult {
+            publisher: build_publisher_information(
+                model,
+                &publisher,
+                &publisher_model,
+                &caller,
+                is_admin,
+                time,
+            ),
+        })
+    })
+}
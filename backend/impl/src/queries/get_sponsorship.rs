@@ -0,0 +1,25 @@
+use crate::{handlers::sponsors::build_sponsorship_information, read_state};
+use candid::Principal;
+use hub_canister_api::get_sponsorship::*;
+use ic_cdk_macros::query;
+
+#[query]
+fn get_sponsorship(Args { sponsor }: Args) -> Response {
+    get_sponsorship_int(sponsor).into()
+}
+
+pub(crate) fn get_sponsorship_int(
+    sponsor: Principal,
+) -> Result<GetSponsorshipResult, GetSponsorshipError> {
+    read_state(|state| {
+        let sponsor_model = state
+            .get_model()
+            .get_sponsors_storage()
+            .get_sponsorship(&sponsor)
+            .ok_or(GetSponsorshipError::SponsorshipNotFound)?;
+
+        Ok(GetSponsorshipResult {
+            sponsorship: build_sponsorship_information(&sponsor, &sponsor_model),
+        })
+    })
+}
@@ -0,0 +1,22 @@
+use crate::{handlers::vouchers::build_voucher_information, read_state};
+use hub_canister_api::{get_voucher::*, types::VoucherCode};
+use ic_cdk_macros::query;
+
+#[query]
+fn get_voucher(Args { code }: Args) -> Response {
+    get_voucher_int(code).into()
+}
+
+pub(crate) fn get_voucher_int(code: VoucherCode) -> Result<GetVoucherResult, GetVoucherError> {
+    read_state(|state| {
+        let voucher_model = state
+            .get_model()
+            .get_vouchers_storage()
+            .get_voucher(&code)
+            .ok_or(GetVoucherError::VoucherNotFound)?;
+
+        Ok(GetVoucherResult {
+            voucher: build_voucher_information(&code, &voucher_model),
+        })
+    })
+}
@@ -1,12 +1,26 @@
 pub mod get_access_rights;
 pub mod get_canister_metrics;
 pub mod get_config;
+pub mod get_config_at_event;
 pub mod get_contract_activation_code;
 pub mod get_contract_block_status;
 pub mod get_contract_template;
+pub mod get_contract_template_earnings;
+pub mod get_contract_template_interface;
 pub mod get_contract_templates;
+pub mod get_deployer_ban;
 pub mod get_deployment;
 pub mod get_deployment_events;
+pub mod get_deployment_rate_limits_usage;
 pub mod get_deployments;
 pub mod get_hub_events;
+pub mod get_my_permissions;
+pub mod get_proposal;
+pub mod get_publisher;
+pub mod get_sponsorship;
+pub mod get_voucher;
 pub mod obtain_contract_certificate;
+pub mod validate_add_contract_template;
+pub mod validate_block_contract_template;
+pub mod validate_set_config;
+pub mod validate_set_contract_template_retired;
@@ -19,12 +19,20 @@ pub(crate) fn obtain_contract_certificate_int(
             .get_deployment(&deployment_id)
             .ok_or(ObtainContractCertificateError::DeploymentNotFound)?;
 
-        if deployment.deployer != state.get_env().get_ic().get_caller() {
-            return Err(ObtainContractCertificateError::PermissionDenied);
-        }
-
-        if DeploymentState::WaitingReceiveContractCertificate != deployment.state.value {
-            return Err(ObtainContractCertificateError::DeploymentWrongState);
+        let caller = state.get_env().get_ic().get_caller();
+        if deployment.has_reissued_certificate() {
+            // the contract fetches the reissued certificate to install it
+            if deployment.get_owner() != caller && deployment.contract_canister != Some(caller) {
+                return Err(ObtainContractCertificateError::PermissionDenied);
+            }
+        } else {
+            if !deployment.is_deployer_or_beneficiary(&caller) {
+                return Err(ObtainContractCertificateError::PermissionDenied);
+            }
+
+            if DeploymentState::WaitingReceiveContractCertificate != deployment.state.value {
+                return Err(ObtainContractCertificateError::DeploymentWrongState);
+            }
         }
 
         let certificate = build_contract_certificate(state, deployment);
@@ -0,0 +1,38 @@
+use crate::{read_state, updates::add_contract_template::check_contract_template_addition};
+use hub_canister_api::{types::ContractTemplateDefinition, validate_add_contract_template::*};
+use ic_cdk_macros::query;
+
+#[query]
+fn validate_add_contract_template(
+    Args {
+        contract_template_definition,
+    }: Args,
+) -> Response {
+    validate_add_contract_template_int(&contract_template_definition).into()
+}
+
+/// The wasm of the contract template is uploaded by the grant before the proposal.
+pub(crate) fn validate_add_contract_template_int(
+    contract_template_definition: &ContractTemplateDefinition,
+) -> Result<String, String> {
+    let Some(governance_principal) = read_state(|state| {
+        state
+            .get_model()
+            .get_config_storage()
+            .get_config()
+            .governance_principal
+    }) else {
+        return Err("governance principal is not configured".to_owned());
+    };
+
+    let (wasm, _) =
+        check_contract_template_addition(&governance_principal, true, contract_template_definition)
+            .map_err(|error| format!("{error:?}"))?;
+
+    Ok(format!(
+        "Add the contract template '{}' (wasm hash: {}, wasm length: {}):\n{contract_template_definition:#?}",
+        contract_template_definition.name,
+        contract_template_definition.wasm_hash,
+        wasm.len()
+    ))
+}
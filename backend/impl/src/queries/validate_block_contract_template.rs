@@ -0,0 +1,34 @@
+use crate::{read_state, updates::block_contract_template::check_contract_template_block};
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::validate_block_contract_template::*;
+use ic_cdk_macros::query;
+
+#[query]
+fn validate_block_contract_template(
+    Args {
+        contract_template_id,
+        reason,
+    }: Args,
+) -> Response {
+    validate_block_contract_template_int(contract_template_id, &reason).into()
+}
+
+pub(crate) fn validate_block_contract_template_int(
+    contract_template_id: ContractTemplateId,
+    reason: &str,
+) -> Result<String, String> {
+    read_state(|state| {
+        let model = state.get_model();
+        check_contract_template_block(model, &contract_template_id)
+            .map_err(|error| format!("{error:?}"))?;
+
+        let name = model
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            .map(|contract_template| contract_template.definition.name.clone())
+            .unwrap_or_default();
+        Ok(format!(
+            "Block the contract template '{contract_template_id}' ({name}).\nReason: {reason}"
+        ))
+    })
+}
@@ -0,0 +1,13 @@
+use crate::updates::set_config::validate_config;
+use hub_canister_api::{types::Config, validate_set_config::*};
+use ic_cdk_macros::query;
+
+#[query]
+fn validate_set_config(Args { config }: Args) -> Response {
+    validate_set_config_int(&config).into()
+}
+
+pub(crate) fn validate_set_config_int(config: &Config) -> Result<String, String> {
+    validate_config(config).map_err(|error| format!("{error:?}"))?;
+    Ok(format!("Set the hub config:\n{config:#?}"))
+}
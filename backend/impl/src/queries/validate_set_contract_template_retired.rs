@@ -0,0 +1,55 @@
+use crate::{
+    read_state, updates::set_contract_template_retired::check_contract_template_retirement,
+};
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::validate_set_contract_template_retired::*;
+use ic_cdk_macros::query;
+
+#[query]
+fn validate_set_contract_template_retired(
+    Args {
+        contract_template_id,
+        reason,
+        retire_at,
+        replacement,
+    }: Args,
+) -> Response {
+    validate_set_contract_template_retired_int(contract_template_id, reason, retire_at, replacement)
+        .into()
+}
+
+pub(crate) fn validate_set_contract_template_retired_int(
+    contract_template_id: ContractTemplateId,
+    reason: Option<String>,
+    retire_at: Option<TimestampMillis>,
+    replacement: Option<ContractTemplateId>,
+) -> Result<String, String> {
+    read_state(|state| {
+        check_contract_template_retirement(
+            state.get_model(),
+            &contract_template_id,
+            &reason,
+            &replacement,
+        )
+        .map_err(|error| format!("{error:?}"))?;
+
+        let Some(reason) = reason else {
+            return Ok(format!(
+                "Cancel the retirement of the contract template '{contract_template_id}'."
+            ));
+        };
+
+        let mut rendering = format!("Retire the contract template '{contract_template_id}'");
+        if let Some(retire_at) = retire_at {
+            rendering.push_str(&format!(" at {retire_at} (unix epoch millis)"));
+        }
+        if let Some(replacement) = replacement {
+            rendering.push_str(&format!(
+                ", replaced by the contract template '{replacement}'"
+            ));
+        }
+        rendering.push_str(&format!(".\nReason: {reason}"));
+        Ok(rendering)
+    })
+}
@@ -1,13 +1,23 @@
 #[cfg(test)]
 mod tests {
+    mod certificates;
     mod components;
     mod contract_management;
+    mod dao_proposals;
+    mod deployer_bans;
     mod deployment_management;
     pub(crate) mod drivers;
     mod expenses_calculator;
+    mod governance;
+    mod migrations;
+    mod ownership;
+    mod publishers;
+    mod rate_limits;
     mod set_access_rights;
     mod set_config;
+    mod sponsors;
     pub(crate) mod support;
+    mod vouchers;
 
     use candid::Principal;
     use common_canister_impl::components::ic::Ic;
@@ -18,7 +28,7 @@ mod tests {
         components::Environment,
         init_state,
         model::DataModel,
-        read_state,
+        mutate_state, read_state,
         state::CanisterState,
         test::tests::components::{
             certification::CertificationTest,
@@ -54,6 +64,12 @@ mod tests {
             ht_create_environment(),
             DataModel::init(),
         ));
+        mutate_state(|state| {
+            state
+                .get_model_mut()
+                .get_access_rights_storage_mut()
+                .set_initial_admins(&[ht_get_test_admin()])
+        });
     }
 
     pub(crate) fn ht_set_initial_config() {
@@ -61,8 +77,7 @@ mod tests {
         let short_description_max_length = 1000;
         let long_description_max_length = 1000;
 
-        let config =
-            read_state(|state| state.get_model().get_config_storage().get_config().clone());
+        let config = ht_get_config();
         let result = set_config_int(Config {
             contract_url_pattern: "http://{principal}.localhost:8080".to_owned(),
             regex_for_contract_principal_parsing: vec![
@@ -81,6 +96,10 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    pub(crate) fn ht_get_config() -> Config {
+        read_state(|state| state.get_model().get_config_storage().get_config().clone())
+    }
+
     fn ht_create_environment() -> Environment {
         let ic = IcTest::new();
         Environment::new(
@@ -0,0 +1,364 @@
+use candid::Principal;
+use common_canister_types::Timestamped;
+use hub_canister_api::{
+    renew_contract_certificate::RenewContractCertificateError,
+    types::{
+        AccessRight, Config, CreateContractCanisterStrategy, CyclesConvertingStrategy, Permission,
+    },
+    validate_contract_certificate::ValidateContractCertificateError,
+};
+
+use crate::{
+    get_env, ht_result_err_matches,
+    queries::obtain_contract_certificate::obtain_contract_certificate_int,
+    read_state,
+    test::tests::{
+        components::{
+            ic::ht_set_test_caller,
+            ledger::{ht_get_account_balance, HT_LEDGER_FEE},
+            time::ht_set_test_time,
+        },
+        drivers::{
+            contract::ht_add_contract,
+            deployment::{
+                ht_calc_expenses_amount_buffered, ht_drive_to_deploying, ht_drive_to_finalized,
+                ht_fund_deployer_account, DeploymentConfig,
+            },
+        },
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{ht_get_face_contract_def, TEST_CONTRACT_INITIAL_CYCLES, TEST_WASM},
+    },
+    updates::{
+        block_contract_template::block_contract_template_int,
+        block_contracts::block_contracts_int,
+        renew_contract_certificate::{
+            charge_certificate_renewal, check_certificate_renewal, complete_certificate_renewal,
+            renew_certificate, renew_contract_certificate_int,
+        },
+        set_access_rights::set_access_rights_int,
+        set_config::set_config_int,
+        validate_contract_certificate::{check_contract_blocks, get_contract_blocks},
+    },
+};
+
+#[tokio::test]
+async fn test_renew_contract_certificate() {
+    let admin = ht_get_test_admin();
+    let owner = ht_get_test_user();
+    let stranger = Principal::from_slice(&[12; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        owner,
+        contract_template_id,
+        &deployment_cfg,
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+
+    // the deployment in progress can not be renewed
+    let result = renew_contract_certificate_int(deployment_id, None).await;
+    ht_result_err_matches!(result, RenewContractCertificateError::DeploymentWrongState);
+
+    ht_drive_to_finalized(owner, &deployment_id).await;
+    let (contract_canister, created, certificate_duration, wasm_hash) = read_state(|state| {
+        let deployment = state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .unwrap();
+        let definition = state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            .unwrap()
+            .definition
+            .clone();
+        (
+            deployment.contract_canister.unwrap(),
+            deployment.created,
+            definition.certificate_duration,
+            definition.wasm_hash,
+        )
+    });
+
+    // CHECKS
+    ht_set_test_caller(stranger);
+    let result = renew_contract_certificate_int(deployment_id, None).await;
+    ht_result_err_matches!(result, RenewContractCertificateError::PermissionDenied);
+
+    ht_set_test_caller(owner);
+    let result = renew_contract_certificate_int(deployment_id + 1, None).await;
+    ht_result_err_matches!(result, RenewContractCertificateError::DeploymentNotFound);
+
+    // the certificate is renewable only within the window before its expiration
+    let renewable_from = created + certificate_duration - certificate_duration / 10;
+    ht_set_test_time(renewable_from - 1);
+    let result = renew_contract_certificate_int(deployment_id, None).await;
+    ht_result_err_matches!(
+        result,
+        RenewContractCertificateError::CertificateRenewalTooEarly { renewable_from: from } if from == renewable_from
+    );
+
+    // the owner and the contract itself may renew
+    ht_set_test_time(renewable_from);
+    let result = read_state(|state| check_certificate_renewal(state, &owner, &deployment_id));
+    assert_eq!(result.unwrap(), (contract_canister, wasm_hash.clone()));
+    let result =
+        read_state(|state| check_certificate_renewal(state, &contract_canister, &deployment_id));
+    assert_eq!(result.unwrap(), (contract_canister, wasm_hash));
+
+    // RENEW the expired certificate
+    let now = created + certificate_duration + 1_000;
+    ht_set_test_time(now);
+    let renewal = renew_certificate(get_env().as_ref(), &deployment_id, owner, 0, None).unwrap();
+    assert_eq!(renewal.renewed_by, owner);
+    assert_eq!(renewal.expiration, now + certificate_duration);
+
+    ht_set_test_caller(contract_canister);
+    let certificate = obtain_contract_certificate_int(deployment_id)
+        .unwrap()
+        .certificate;
+    assert_eq!(
+        certificate.contract_certificate.expiration,
+        now + certificate_duration
+    );
+    assert_eq!(certificate.contract_certificate.deployer, owner);
+
+    // the valid certificate is renewed too early
+    let result = renew_certificate(
+        get_env().as_ref(),
+        &deployment_id,
+        contract_canister,
+        0,
+        None,
+    );
+    ht_result_err_matches!(
+        result,
+        RenewContractCertificateError::CertificateRenewalTooEarly { .. }
+    );
+
+    // the valid certificate is extended from its expiration
+    ht_set_test_time(now + certificate_duration - certificate_duration / 10);
+    let renewal = renew_certificate(
+        get_env().as_ref(),
+        &deployment_id,
+        contract_canister,
+        0,
+        None,
+    )
+    .unwrap();
+    assert_eq!(renewal.expiration, now + 2 * certificate_duration);
+    read_state(|state| {
+        let deployment = state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .unwrap();
+        assert_eq!(deployment.certificate_renewals.len(), 2);
+    });
+
+    // PRICED RENEWAL
+    let certificate_renewal_cycles_cost = 5_000_000_000_000;
+    ht_set_test_caller(admin);
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        certificate_renewal_cycles_cost,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    let result = charge_certificate_renewal(get_env().as_ref(), &owner, &deployment_id, None).await;
+    ht_result_err_matches!(
+        result,
+        RenewContractCertificateError::InvalidApprovedAccount { .. }
+    );
+
+    let renewal_amount = ht_calc_expenses_amount_buffered(
+        &DeploymentConfig {
+            deployment_cycles_cost: certificate_renewal_cycles_cost,
+            deployment_expenses_amount_buffer_permyriad: 0,
+            ..deployment_cfg
+        },
+        0,
+    );
+    let (approved_account, approved_account_identifier) = ht_fund_deployer_account(
+        owner,
+        renewal_amount + HT_LEDGER_FEE,
+        renewal_amount + HT_LEDGER_FEE,
+        60_000,
+    );
+    let balance = ht_get_account_balance(approved_account_identifier.to_hex());
+
+    let (expenses_amount, block_index) = charge_certificate_renewal(
+        get_env().as_ref(),
+        &owner,
+        &deployment_id,
+        Some(approved_account.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(expenses_amount, renewal_amount);
+    assert!(block_index.is_some());
+    assert_eq!(
+        ht_get_account_balance(approved_account_identifier.to_hex()),
+        balance - renewal_amount - HT_LEDGER_FEE
+    );
+
+    // BLOCKED
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::BlockContract,
+            Permission::BlockContractTemplate,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    assert!(block_contract_template_int(contract_template_id, "template".to_string()).is_ok());
+    ht_set_test_caller(owner);
+    let result = renew_contract_certificate_int(deployment_id, None).await;
+    ht_result_err_matches!(
+        result,
+        RenewContractCertificateError::ContractTemplateBlocked
+    );
+
+    ht_set_test_caller(admin);
+    assert!(block_contracts_int(vec![deployment_id], vec![], "contract".to_string()).is_ok());
+    ht_set_test_caller(owner);
+    let result = renew_contract_certificate_int(deployment_id, None).await;
+    ht_result_err_matches!(
+        result,
+        RenewContractCertificateError::ContractBlocked { reason } if reason == "contract"
+    );
+
+    // the contract blocked during the payment is not renewed and the payment is refunded
+    let result = complete_certificate_renewal(
+        get_env().as_ref(),
+        &deployment_id,
+        owner,
+        Some(approved_account),
+        expenses_amount,
+        block_index,
+    )
+    .await;
+    ht_result_err_matches!(
+        result,
+        RenewContractCertificateError::ContractBlocked { .. }
+    );
+    assert_eq!(
+        ht_get_account_balance(approved_account_identifier.to_hex()),
+        balance - 2 * HT_LEDGER_FEE
+    );
+    read_state(|state| {
+        let deployment = state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .unwrap();
+        assert_eq!(deployment.certificate_renewals.len(), 2);
+    });
+}
+
+#[tokio::test]
+async fn test_validate_contract_certificate_blocks() {
+    let admin = ht_get_test_admin();
+    let owner = ht_get_test_user();
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        owner,
+        contract_template_id,
+        &deployment_cfg,
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+    ht_drive_to_finalized(owner, &deployment_id).await;
+    let contract_canister = read_state(|state| {
+        state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .unwrap()
+            .contract_canister
+            .unwrap()
+    });
+
+    // NOT BLOCKED
+    let (contract_blocked, contract_template_blocked) =
+        get_contract_blocks(&contract_canister, &contract_template_id);
+    assert!(contract_blocked.is_none());
+    assert!(contract_template_blocked.is_none());
+    assert!(check_contract_blocks(&contract_blocked, &contract_template_blocked).is_ok());
+
+    ht_set_test_caller(admin);
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::BlockContract,
+            Permission::BlockContractTemplate,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // TEMPLATE BLOCKED
+    ht_set_test_time(1_000);
+    assert!(block_contract_template_int(contract_template_id, "template".to_string()).is_ok());
+
+    let (contract_blocked, contract_template_blocked) =
+        get_contract_blocks(&contract_canister, &contract_template_id);
+    assert!(contract_blocked.is_none());
+    assert_eq!(
+        contract_template_blocked,
+        Some(Timestamped::new(1_000, "template".to_string()))
+    );
+    ht_result_err_matches!(
+        check_contract_blocks(&contract_blocked, &contract_template_blocked),
+        ValidateContractCertificateError::ContractTemplateBlocked { .. }
+    );
+
+    // the unknown contract is checked by the template of its certificate
+    let (contract_blocked, contract_template_blocked) =
+        get_contract_blocks(&Principal::from_slice(&[13; 29]), &contract_template_id);
+    assert!(contract_blocked.is_none());
+    assert!(contract_template_blocked.is_some());
+
+    // CONTRACT BLOCKED
+    ht_set_test_time(2_000);
+    assert!(block_contracts_int(vec![deployment_id], vec![], "contract".to_string()).is_ok());
+
+    let (contract_blocked, contract_template_blocked) =
+        get_contract_blocks(&contract_canister, &contract_template_id);
+    assert_eq!(
+        contract_blocked,
+        Some(Timestamped::new(2_000, "contract".to_string()))
+    );
+    ht_result_err_matches!(
+        check_contract_blocks(&contract_blocked, &contract_template_blocked),
+        ValidateContractCertificateError::ContractBlocked { .. }
+    );
+}
@@ -1,41 +1,71 @@
+use std::io::Write;
+
+use common_canister_types::{ChunkDef, LedgerAccount};
 use common_contract_api::get_wasm_hash;
+use flate2::{write::GzEncoder, Compression};
 use hub_canister_api::{
     add_contract_template::{AddContractTemplateError, AddContractTemplateResult},
     block_contract_template::BlockContractTemplateError,
     block_contracts::BlockContractsError,
-    get_contract_block_status::GetContractBlockStatusError,
+    deploy_contract::DeployContractError,
+    get_contract_block_status::{ContractBlockFilter, GetContractBlockStatusError},
+    get_contract_template::GetContractTemplateError,
+    get_contract_template_earnings::GetContractTemplateEarningsError,
+    get_contract_template_interface::GetContractTemplateInterfaceError,
+    get_contract_templates::{
+        ContractTemplatesFilter, GetContractTemplatesArgs, GetContractTemplatesResult,
+    },
+    review_contract_template::{ContractTemplateReviewAction, ReviewContractTemplateError},
+    set_contract_template_metadata::SetContractTemplateMetadataError,
     set_contract_template_retired::SetContractTemplateRetiredError,
+    set_contract_template_visibility::SetContractTemplateVisibilityError,
     set_upload_wasm_grant::SetUploadWasmGrantError,
     types::{
-        AccessRight, Config, ContractTemplateDefinition, HubEventType, Permission, UploadWasmGrant,
+        AccessRight, CanisterMethodType, CanisterSettings, Config, ContractBlockAction,
+        ContractBlockHistoryEntry, ContractTemplateDefinition, ContractTemplateMetadata,
+        ContractTemplateReviewStatus, ContractTemplateVisibility, HubEventType, Permission,
+        UploadWasmGrant,
     },
+    unblock_contracts::UnblockContractsError,
     upload_wasm_chunk::UploadWasmChunkError,
 };
 
 use crate::{
     ht_last_hub_event_matches, ht_result_err_matches,
+    model::templates::ContractTemplateWasmMetadata,
+    mutate_state,
     queries::{
         get_contract_block_status::get_contract_block_status_int,
         get_contract_template::get_contract_template_int,
+        get_contract_template_earnings::get_contract_template_earnings_int,
+        get_contract_template_interface::get_contract_template_interface_int,
+        get_contract_templates::get_contract_templates_int,
     },
     read_state,
     test::tests::{
         components::ic::ht_set_test_caller,
         components::time::ht_set_test_time,
         drivers::{
-            contract::ht_add_contract,
+            contract::{ht_add_contract, ht_try_add_contract},
             deployment::{get_deployment_lock_expiration, ht_drive_to_deploying, DeploymentConfig},
         },
         ht_get_test_admin, ht_get_test_user, ht_init_test_hub, ht_set_initial_config,
-        support::fixtures::{ht_get_face_contract_def, TEST_CONTRACT_INITIAL_CYCLES, TEST_WASM},
+        support::fixtures::{
+            ht_build_contract_wasm, ht_get_face_contract_def, TEST_CANDID_SERVICE,
+            TEST_CONTRACT_INITIAL_CYCLES, TEST_WASM,
+        },
     },
     updates::{
         add_contract_template::add_contract_template_int,
         block_contract_template::block_contract_template_int, block_contracts::block_contracts_int,
-        process_deployment::process_deployment_int, set_access_rights::set_access_rights_int,
-        set_config::set_config_int,
+        deploy_contract::deploy_contract_int, process_deployment::process_deployment_int,
+        review_contract_template::review_contract_template_int,
+        set_access_rights::set_access_rights_int, set_config::set_config_int,
+        set_contract_template_metadata::set_contract_template_metadata_int,
         set_contract_template_retired::set_contract_template_retired_int,
-        set_upload_wasm_grant::set_upload_wasm_grant_int, upload_wasm_chunk::upload_wasm_chunk_int,
+        set_contract_template_visibility::set_contract_template_visibility_int,
+        set_upload_wasm_grant::set_upload_wasm_grant_int, unblock_contracts::unblock_contracts_int,
+        upload_wasm_chunk::upload_wasm_chunk_int,
     },
 };
 
@@ -54,8 +84,9 @@ fn test_add_contract() {
     assert!(result.is_ok());
     ht_set_initial_config();
 
-    let wrong_wasm = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 11];
     let wasm = TEST_WASM.to_vec();
+    let mut wrong_wasm = wasm.clone();
+    *wrong_wasm.last_mut().unwrap() ^= 1;
     let wasm_hash = get_wasm_hash(&wasm);
     let contract_wasm_max_size = wasm.len();
     let contract_def = ContractTemplateDefinition {
@@ -169,6 +200,231 @@ fn test_add_contract() {
         if event_contract_id == &contract_template_id);
 }
 
+#[test]
+fn test_add_contract_wasm_validation() {
+    let admin = ht_get_test_admin();
+    let init = "canister_init";
+    let certificate_method = "canister_query get_contract_certificate";
+
+    // NOT A WASM MODULE
+    let result = ht_try_add_contract(admin, ht_get_face_contract_def(), vec![1, 2, 3, 4]);
+    ht_result_err_matches!(result, AddContractTemplateError::InvalidWasmModule { .. });
+
+    // BROKEN GZIP
+    let result = ht_try_add_contract(
+        admin,
+        ht_get_face_contract_def(),
+        vec![0x1f, 0x8b, 0x08, 0x00, 0x01],
+    );
+    ht_result_err_matches!(result, AddContractTemplateError::InvalidWasmModule { .. });
+
+    // MISSING CANISTER INIT
+    let wasm = ht_build_contract_wasm(&[certificate_method], 1, &[]);
+    let result = ht_try_add_contract(admin, ht_get_face_contract_def(), wasm);
+    ht_result_err_matches!(result, AddContractTemplateError::MissingCanisterMethod { method }
+        if method == "canister_init");
+
+    // MISSING CERTIFICATE METHOD
+    let wasm = ht_build_contract_wasm(&[init, "canister_update get_certificate"], 1, &[]);
+    let result = ht_try_add_contract(admin, ht_get_face_contract_def(), wasm);
+    ht_result_err_matches!(result, AddContractTemplateError::MissingCanisterMethod { method }
+        if method == "get_contract_certificate");
+
+    // METADATA SECTION WITHOUT VISIBILITY
+    let wasm = ht_build_contract_wasm(
+        &[init, certificate_method],
+        1,
+        &[("icp:candid:service", b"service : {}")],
+    );
+    let result = ht_try_add_contract(admin, ht_get_face_contract_def(), wasm);
+    ht_result_err_matches!(
+        result,
+        AddContractTemplateError::InvalidWasmCustomSection { .. }
+    );
+
+    // DUPLICATE METADATA SECTION
+    let wasm = ht_build_contract_wasm(
+        &[init, certificate_method],
+        1,
+        &[
+            ("icp:public candid:service", b"service : {}"),
+            ("icp:private candid:service", b"service : {}"),
+        ],
+    );
+    let result = ht_try_add_contract(admin, ht_get_face_contract_def(), wasm);
+    ht_result_err_matches!(result, AddContractTemplateError::InvalidWasmCustomSection { name, .. }
+        if name == "candid:service");
+
+    // CANDID SERVICE IS NOT A TEXT
+    let wasm = ht_build_contract_wasm(
+        &[init, certificate_method],
+        1,
+        &[("icp:public candid:service", &[0xff, 0xfe])],
+    );
+    let result = ht_try_add_contract(admin, ht_get_face_contract_def(), wasm);
+    ht_result_err_matches!(
+        result,
+        AddContractTemplateError::InvalidWasmCustomSection { .. }
+    );
+
+    // INITIAL MEMORY EXCEEDS WASM MEMORY LIMIT
+    let wasm = ht_build_contract_wasm(&[init, certificate_method], 2, &[]);
+    let contract_def = ht_get_face_contract_def();
+    let contract_def = ContractTemplateDefinition {
+        contract_canister_settings: CanisterSettings {
+            wasm_memory_limit: Some(65_536),
+            ..contract_def.contract_canister_settings
+        },
+        ..contract_def
+    };
+    let result = ht_try_add_contract(admin, contract_def, wasm.clone());
+    ht_result_err_matches!(result, AddContractTemplateError::WasmMemoryExceedsWasmMemoryLimit { initial_memory, limit }
+        if initial_memory == 131_072 && limit == 65_536);
+
+    // INITIAL MEMORY EXCEEDS MEMORY ALLOCATION
+    let contract_def = ht_get_face_contract_def();
+    let contract_def = ContractTemplateDefinition {
+        contract_canister_settings: CanisterSettings {
+            memory_allocation: Some(100_000),
+            ..contract_def.contract_canister_settings
+        },
+        ..contract_def
+    };
+    let result = ht_try_add_contract(admin, contract_def, wasm);
+    ht_result_err_matches!(result, AddContractTemplateError::WasmMemoryExceedsMemoryAllocation { initial_memory, limit }
+        if initial_memory == 131_072 && limit == 100_000);
+
+    // GZIPPED WASM SUCCESS
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&TEST_WASM).unwrap();
+    let wasm = encoder.finish().unwrap();
+
+    let contract_template_id = ht_add_contract(admin, ht_get_face_contract_def(), wasm);
+
+    read_state(|state| {
+        let wasm_metadata = state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template_wasm_metadata(&contract_template_id)
+            .unwrap();
+        assert!(wasm_metadata.compressed);
+        assert_eq!(
+            wasm_metadata.exported_methods,
+            vec![init.to_string(), certificate_method.to_string()]
+        );
+        assert_eq!(wasm_metadata.custom_sections.len(), 1);
+        let section = &wasm_metadata.custom_sections[0];
+        assert_eq!(section.name, "candid:service");
+        assert!(section.public);
+        assert_eq!(section.content, TEST_CANDID_SERVICE.as_bytes());
+    });
+}
+
+#[test]
+fn test_get_contract_template_interface() {
+    let admin = ht_get_test_admin();
+    let candid_service = "service : (InitArgs) -> { get_contract_certificate : () -> () query }";
+    let candid_args = "(InitArgs)";
+    let wasm = ht_build_contract_wasm(
+        &[
+            "canister_init",
+            "canister_query get_contract_certificate",
+            "canister_composite_query get_info",
+            "canister_update activate",
+        ],
+        1,
+        &[
+            ("icp:public candid:service", candid_service.as_bytes()),
+            ("icp:public candid:args", candid_args.as_bytes()),
+            ("icp:public git_commit_id", b"e3b0c442"),
+            ("icp:private build_secret", b"secret"),
+        ],
+    );
+
+    let contract_template_id = ht_add_contract(admin, ht_get_face_contract_def(), wasm);
+
+    // CHECK CONTRACT NOT FOUND
+    let result = get_contract_template_interface_int(contract_template_id + 1);
+    ht_result_err_matches!(
+        result,
+        GetContractTemplateInterfaceError::ContractTemplateNotFound
+    );
+
+    // GET INTERFACE SUCCESS
+    let interface = get_contract_template_interface_int(contract_template_id)
+        .unwrap()
+        .interface;
+    assert_eq!(interface.candid_service.as_deref(), Some(candid_service));
+    assert_eq!(interface.candid_args.as_deref(), Some(candid_args));
+    assert_eq!(
+        interface.metadata,
+        vec![("git_commit_id".to_string(), "e3b0c442".to_string())]
+    );
+
+    let methods = interface
+        .methods
+        .iter()
+        .map(|method| (method.name.as_str(), method.method_type.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(methods.len(), 3);
+    assert!(matches!(
+        methods[0],
+        ("get_contract_certificate", CanisterMethodType::Query)
+    ));
+    assert!(matches!(
+        methods[1],
+        ("get_info", CanisterMethodType::CompositeQuery)
+    ));
+    assert!(matches!(
+        methods[2],
+        ("activate", CanisterMethodType::Update)
+    ));
+
+    // SUMMARY IN CONTRACT TEMPLATE
+    let contract = get_contract_template_int(contract_template_id).unwrap();
+    let summary = contract.contract_template.interface_summary.unwrap();
+    assert!(summary.candid_service_available);
+    assert_eq!(summary.methods.len(), 3);
+
+    // SUMMARY IN CONTRACT TEMPLATES ONLY ON REQUEST
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        max_contract_templates_per_chunk: 10,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    let result = get_contract_templates_int(GetContractTemplatesArgs {
+        chunk_def: ChunkDef {
+            start: 0,
+            count: 10,
+        },
+        filter: None,
+        sorting: None,
+        include_interface_summary: None,
+    })
+    .unwrap();
+    assert_eq!(result.total_count, 1);
+    assert!(result.contract_templates[0].interface_summary.is_none());
+
+    let result = get_contract_templates_int(GetContractTemplatesArgs {
+        chunk_def: ChunkDef {
+            start: 0,
+            count: 10,
+        },
+        filter: None,
+        sorting: None,
+        include_interface_summary: Some(true),
+    })
+    .unwrap();
+    let summary = result.contract_templates[0]
+        .interface_summary
+        .as_ref()
+        .unwrap();
+    assert!(summary.candid_service_available);
+    assert_eq!(summary.methods.len(), 3);
+}
+
 #[test]
 fn test_block_contract() {
     let admin = ht_get_test_admin();
@@ -338,6 +594,120 @@ async fn test_block_contracts() {
     });
 }
 
+#[tokio::test]
+async fn test_unblock_contracts() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+    let deployment = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await;
+    let contract_canister =
+        drive_deployment_until_contract_canister(deployer, deployment.deployment_id).await;
+
+    let result = unblock_contracts_int(
+        vec![deployment.deployment_id],
+        vec![],
+        "appeal-1".to_string(),
+    );
+    ht_result_err_matches!(result, UnblockContractsError::PermissionDenied);
+
+    ht_set_test_caller(admin);
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![Permission::SetAccessRights, Permission::BlockContract]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    ht_set_test_time(1_000);
+    let result = block_contracts_int(
+        vec![deployment.deployment_id],
+        vec![],
+        "policy-1".to_string(),
+    );
+    assert!(result.is_ok());
+
+    // UNBLOCK BY CONTRACT CANISTER
+    ht_set_test_time(2_000);
+    let result = unblock_contracts_int(
+        vec![],
+        vec![contract_canister, contract_canister],
+        "appeal-1".to_string(),
+    );
+    assert!(result.is_ok());
+
+    ht_last_hub_event_matches!(HubEventType::ContractUnblocked { deployment_ids_count }
+        if deployment_ids_count == &1);
+
+    let status = get_contract_block_status_int(ContractBlockFilter::ByDeploymentId {
+        deployment_id: deployment.deployment_id,
+    })
+    .unwrap();
+    assert_eq!(status.blocked, None);
+    assert_eq!(
+        status.history,
+        vec![
+            ContractBlockHistoryEntry {
+                time: 1_000,
+                action: ContractBlockAction::Blocked,
+                reason: "policy-1".to_string(),
+            },
+            ContractBlockHistoryEntry {
+                time: 2_000,
+                action: ContractBlockAction::Unblocked,
+                reason: "appeal-1".to_string(),
+            },
+        ]
+    );
+
+    // UNBLOCKING AN UNBLOCKED CONTRACT IS A NO-OP
+    let result = unblock_contracts_int(
+        vec![deployment.deployment_id],
+        vec![],
+        "appeal-2".to_string(),
+    );
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ContractUnblocked { .. });
+    assert_eq!(
+        read_state(|state| state
+            .get_model()
+            .get_blocked_contracts_storage()
+            .get_deployment_block_history(&deployment.deployment_id)
+            .len()),
+        2
+    );
+
+    // BLOCK AGAIN
+    ht_set_test_time(3_000);
+    let result = block_contracts_int(
+        vec![deployment.deployment_id],
+        vec![],
+        "policy-2".to_string(),
+    );
+    assert!(result.is_ok());
+
+    let status = get_contract_block_status_int(ContractBlockFilter::ByContractCanisterId {
+        canister_id: contract_canister,
+    })
+    .unwrap();
+    assert_eq!(
+        status
+            .blocked
+            .map(|blocked| (blocked.timestamp, blocked.value)),
+        Some((3_000, "policy-2".to_string()))
+    );
+    assert_eq!(status.history.len(), 3);
+}
+
 async fn drive_deployment_until_contract_canister(
     deployer: candid::Principal,
     deployment_id: u64,
@@ -375,6 +745,8 @@ fn test_retire_contract() {
     let result = set_contract_template_retired_int(
         contract_template_id,
         Some("no longer supported".to_string()),
+        None,
+        None,
     );
     ht_result_err_matches!(result, SetContractTemplateRetiredError::PermissionDenied);
 
@@ -392,6 +764,8 @@ fn test_retire_contract() {
     let result = set_contract_template_retired_int(
         contract_template_id + 1,
         Some("no longer supported".to_string()),
+        None,
+        None,
     );
     ht_result_err_matches!(
         result,
@@ -400,7 +774,8 @@ fn test_retire_contract() {
 
     // RETIRE CONTRACT SUCCESS
     let reason = "no longer supported".to_string();
-    let result = set_contract_template_retired_int(contract_template_id, Some(reason.clone()));
+    let result =
+        set_contract_template_retired_int(contract_template_id, Some(reason.clone()), None, None);
     assert!(result.is_ok());
 
     let contract = get_contract_template_int(contract_template_id).unwrap();
@@ -408,16 +783,711 @@ fn test_retire_contract() {
     assert!(retired.is_some());
     assert_eq!(retired.unwrap().value, reason);
 
-    ht_last_hub_event_matches!(HubEventType::ContractTemplateRetired { contract_template_id: event_contract_id, retired: true }
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateRetired { contract_template_id: event_contract_id, retired: true, .. }
         if event_contract_id == &contract_template_id);
 
     // UNRETIRE CONTRACT SUCCESS
-    let result = set_contract_template_retired_int(contract_template_id, None);
+    let result = set_contract_template_retired_int(contract_template_id, None, None, None);
     assert!(result.is_ok());
 
     let contract = get_contract_template_int(contract_template_id).unwrap();
     assert!(contract.contract_template.retired.is_none());
 
-    ht_last_hub_event_matches!(HubEventType::ContractTemplateRetired { contract_template_id: event_contract_id, retired: false }
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateRetired { contract_template_id: event_contract_id, retired: false, .. }
+        if event_contract_id == &contract_template_id);
+}
+
+#[tokio::test]
+async fn test_schedule_contract_template_retirement() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let second_deployer = candid::Principal::from_slice(&[5; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    // ADD REPLACEMENT
+    let wasm = ht_build_contract_wasm(
+        &["canister_init", "canister_query get_contract_certificate"],
+        1,
+        &[],
+    );
+    let result = set_upload_wasm_grant_int(Some(UploadWasmGrant {
+        operator: deployer,
+        wasm_length: wasm.len(),
+    }));
+    assert!(result.is_ok());
+    ht_set_test_caller(deployer);
+    assert!(upload_wasm_chunk_int(true, wasm.clone()).is_ok());
+    ht_set_test_caller(admin);
+    let replacement = add_contract_template_int(ContractTemplateDefinition {
+        name: "replacement".to_string(),
+        wasm_hash: get_wasm_hash(&wasm),
+        ..ht_get_face_contract_def()
+    })
+    .unwrap()
+    .contract_template_id;
+
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetConfig,
+            Permission::RetireContractTemplate,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // INVALID REPLACEMENT
+    let reason = "superseded".to_string();
+    for (retire_reason, invalid_replacement) in [
+        (Some(reason.clone()), contract_template_id),
+        (Some(reason.clone()), replacement + 1),
+        (None, replacement),
+    ] {
+        let result = set_contract_template_retired_int(
+            contract_template_id,
+            retire_reason,
+            None,
+            Some(invalid_replacement),
+        );
+        ht_result_err_matches!(
+            result,
+            SetContractTemplateRetiredError::InvalidReplacement { .. }
+        );
+    }
+
+    // SCHEDULE RETIREMENT
+    ht_set_test_time(1_000);
+    let result = set_contract_template_retired_int(
+        contract_template_id,
+        Some(reason.clone()),
+        Some(5_000),
+        Some(replacement),
+    );
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateRetired { retired: true, retire_at: Some(5_000), replacement: Some(event_replacement), .. }
+        if event_replacement == &replacement);
+
+    let contract_template = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template;
+    assert!(contract_template.retired.is_none());
+    assert_eq!(
+        contract_template
+            .pending_retirement
+            .map(|retirement| (retirement.timestamp, retirement.value)),
+        Some((5_000, reason.clone()))
+    );
+    assert_eq!(contract_template.replacement, Some(replacement));
+
+    // the contract template is deployable until the retirement
+    ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await;
+
+    // RETIREMENT EFFECTIVE
+    ht_set_test_time(5_000);
+    ht_set_test_caller(second_deployer);
+    let result = deploy_contract_int(
+        LedgerAccount::Account {
+            owner: second_deployer,
+            subaccount: None,
+        },
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::ContractTemplateRetired { replacement: Some(result_replacement) }
+        if result_replacement == replacement);
+
+    let contract_template = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template;
+    assert_eq!(
+        contract_template.retired.map(|retired| retired.value),
+        Some(reason)
+    );
+    assert!(contract_template.pending_retirement.is_none());
+}
+
+#[tokio::test]
+async fn test_set_contract_template_visibility() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let stranger = candid::Principal::from_slice(&[5; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+    let restricted = ContractTemplateVisibility::Restricted {
+        allowed_deployers: vec![deployer],
+    };
+
+    // PERMISSION DENIED
+    ht_set_test_caller(stranger);
+    let result = set_contract_template_visibility_int(contract_template_id, restricted.clone());
+    ht_result_err_matches!(result, SetContractTemplateVisibilityError::PermissionDenied);
+
+    ht_set_test_caller(admin);
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetConfig,
+            Permission::SetContractTemplateMetadata,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // INVALID VISIBILITY
+    let result = set_contract_template_visibility_int(
+        contract_template_id,
+        ContractTemplateVisibility::Restricted {
+            allowed_deployers: vec![deployer, candid::Principal::anonymous()],
+        },
+    );
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateVisibilityError::InvalidVisibility { .. }
+    );
+
+    let result = set_contract_template_visibility_int(1_000, restricted.clone());
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateVisibilityError::ContractTemplateNotFound
+    );
+
+    // RESTRICTED
+    let result = set_contract_template_visibility_int(contract_template_id, restricted.clone());
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateVisibilitySet {
+        contract_template_id: id,
+        visibility,
+    } if *id == contract_template_id && *visibility == restricted);
+    assert_eq!(search_contract_templates(None, None, None).total_count, 1);
+
+    ht_set_test_caller(stranger);
+    assert_eq!(search_contract_templates(None, None, None).total_count, 0);
+    ht_result_err_matches!(
+        get_contract_template_int(contract_template_id),
+        GetContractTemplateError::ContractTemplateNotFound
+    );
+    ht_result_err_matches!(
+        get_contract_template_interface_int(contract_template_id),
+        GetContractTemplateInterfaceError::ContractTemplateNotFound
+    );
+    ht_result_err_matches!(
+        get_contract_template_earnings_int(contract_template_id),
+        GetContractTemplateEarningsError::ContractTemplateNotFound
+    );
+    let result = deploy_contract_int(
+        LedgerAccount::Account {
+            owner: stranger,
+            subaccount: None,
+        },
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::ContractTemplateRestricted);
+
+    ht_set_test_caller(deployer);
+    assert_eq!(search_contract_templates(None, None, None).total_count, 1);
+    assert_eq!(
+        get_contract_template_int(contract_template_id)
+            .unwrap()
+            .contract_template
+            .visibility,
+        restricted
+    );
+    assert!(get_contract_template_interface_int(contract_template_id).is_ok());
+    assert!(get_contract_template_earnings_int(contract_template_id).is_ok());
+
+    // UNLISTED
+    ht_set_test_caller(admin);
+    let result = set_contract_template_visibility_int(
+        contract_template_id,
+        ContractTemplateVisibility::Unlisted,
+    );
+    assert!(result.is_ok());
+
+    ht_set_test_caller(stranger);
+    assert_eq!(search_contract_templates(None, None, None).total_count, 0);
+    assert!(get_contract_template_int(contract_template_id).is_ok());
+
+    // the allowed deployer deploys the restricted contract template
+    ht_set_test_caller(admin);
+    let result = set_contract_template_visibility_int(contract_template_id, restricted);
+    assert!(result.is_ok());
+    ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await;
+}
+
+#[test]
+fn test_set_contract_template_metadata() {
+    let admin = ht_get_test_admin();
+    let contract_def = ht_get_face_contract_def();
+
+    let contract_template_id = ht_add_contract(admin, contract_def.clone(), TEST_WASM.to_vec());
+    let wasm_hash = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template
+        .definition
+        .wasm_hash;
+
+    let metadata = ContractTemplateMetadata {
+        name: "new name".to_string(),
+        short_description: "new short description".to_string(),
+        long_description: None,
+        documentation_url: "new_documentation_url".to_string(),
+        terms_of_use_url: "new_terms_of_use_url".to_string(),
+        categories: Some(vec!["governance".to_string()]),
+        tags: Some(vec!["dao".to_string()]),
+    };
+
+    // CHECK PERMISSION DENIED
+    let result = set_contract_template_metadata_int(contract_template_id, metadata.clone());
+    ht_result_err_matches!(result, SetContractTemplateMetadataError::PermissionDenied);
+
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetContractTemplateMetadata,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // CHECK CONTRACT NOT FOUND
+    let result = set_contract_template_metadata_int(contract_template_id + 1, metadata.clone());
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateMetadataError::ContractTemplateNotFound
+    );
+
+    // CHECK FIELD RESTRICTIONS
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_contract_template_metadata_int(
+        contract_template_id,
+        ContractTemplateMetadata {
+            name: "n".repeat(config.name_max_length + 1),
+            ..metadata.clone()
+        },
+    );
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateMetadataError::ContractNameIsTooLong { .. }
+    );
+
+    let result = set_contract_template_metadata_int(
+        contract_template_id,
+        ContractTemplateMetadata {
+            short_description: "d".repeat(config.short_description_max_length + 1),
+            ..metadata.clone()
+        },
+    );
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateMetadataError::ContractShortDescriptionIsTooLong { .. }
+    );
+
+    let result = set_contract_template_metadata_int(
+        contract_template_id,
+        ContractTemplateMetadata {
+            long_description: Some("d".repeat(config.long_description_max_length + 1)),
+            ..metadata.clone()
+        },
+    );
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateMetadataError::ContractLongDescriptionIsTooLong { .. }
+    );
+
+    // CHECK NAME OF ANOTHER TEMPLATE
+    let other_contract_template_id = mutate_state(|state| {
+        state
+            .get_model_mut()
+            .get_contract_templates_storage_mut()
+            .add_contract_template(
+                admin,
+                0,
+                ContractTemplateDefinition {
+                    name: "other name".to_string(),
+                    wasm_hash: "other_wasm_hash".to_string(),
+                    ..contract_def.clone()
+                },
+                vec![],
+                ContractTemplateWasmMetadata {
+                    compressed: false,
+                    exported_methods: vec![],
+                    custom_sections: vec![],
+                },
+            )
+    });
+    assert_ne!(other_contract_template_id, contract_template_id);
+
+    let result = set_contract_template_metadata_int(
+        contract_template_id,
+        ContractTemplateMetadata {
+            name: "other name".to_string(),
+            ..metadata.clone()
+        },
+    );
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateMetadataError::ContractTemplateNameAlreadyExists
+    );
+
+    // SET METADATA SUCCESS
+    let result = set_contract_template_metadata_int(contract_template_id, metadata.clone());
+    assert!(result.is_ok());
+
+    let definition = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template
+        .definition;
+    assert_eq!(definition.get_metadata(), metadata);
+    assert_eq!(definition.wasm_hash, wasm_hash);
+    assert_eq!(
+        definition.certificate_duration,
+        contract_def.certificate_duration
+    );
+    assert_eq!(
+        definition.contract_canister_settings,
+        contract_def.contract_canister_settings
+    );
+
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateMetadataSet { contract_template_id: event_contract_id, previous_metadata }
+        if event_contract_id == &contract_template_id && previous_metadata.as_ref() == &contract_def.get_metadata());
+
+    // SAME NAME OF THE SAME TEMPLATE IS ALLOWED
+    let result = set_contract_template_metadata_int(contract_template_id, metadata);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_get_contract_templates_facets() {
+    let admin = ht_get_test_admin();
+    let contract_def = ht_get_face_contract_def();
+
+    // CHECK LABEL RESTRICTIONS
+    let result = ht_try_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            tags: Some(vec!["Token".to_string()]),
+            ..contract_def.clone()
+        },
+        TEST_WASM.to_vec(),
+    );
+    ht_result_err_matches!(result, AddContractTemplateError::InvalidContractTag { tag } if tag == "Token");
+
+    let result = ht_try_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            categories: Some(vec!["finance".to_string(), "finance".to_string()]),
+            ..contract_def.clone()
+        },
+        TEST_WASM.to_vec(),
+    );
+    ht_result_err_matches!(
+        result,
+        AddContractTemplateError::InvalidContractCategory { .. }
+    );
+
+    let result = ht_try_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            tags: Some((0..11).map(|i| format!("tag-{i}")).collect()),
+            ..contract_def.clone()
+        },
+        TEST_WASM.to_vec(),
+    );
+    ht_result_err_matches!(
+        result,
+        AddContractTemplateError::TooManyContractTags { max_count: 10 }
+    );
+
+    let contract_template_id = ht_add_contract(admin, contract_def.clone(), TEST_WASM.to_vec());
+
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        max_contract_templates_per_chunk: 10,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    // FILTER BY CATEGORY AND TAG
+    let result = search_contract_templates(None, Some("finance"), Some("icrc"));
+    assert_eq!(result.total_count, 1);
+    assert_eq!(result.facets.categories, vec![("finance".to_string(), 1)]);
+    assert_eq!(
+        result.facets.tags,
+        vec![("icrc".to_string(), 1), ("token".to_string(), 1)]
+    );
+
+    let result = search_contract_templates(Some("SHORT_desc"), None, Some("TOKEN"));
+    assert_eq!(result.total_count, 1);
+
+    let result = search_contract_templates(None, Some("governance"), None);
+    assert_eq!(result.total_count, 0);
+    assert!(result.facets.categories.is_empty());
+    assert!(result.facets.tags.is_empty());
+
+    // SEARCH INDEX FOLLOWS METADATA
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetConfig,
+            Permission::SetContractTemplateMetadata,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    let result = set_contract_template_metadata_int(
+        contract_template_id,
+        ContractTemplateMetadata {
+            name: "dao contract".to_string(),
+            categories: Some(vec!["governance".to_string()]),
+            tags: None,
+            ..contract_def.get_metadata()
+        },
+    );
+    assert!(result.is_ok());
+
+    let result = search_contract_templates(Some("dao con"), Some("governance"), None);
+    assert_eq!(result.total_count, 1);
+    assert_eq!(
+        result.facets.categories,
+        vec![("governance".to_string(), 1)]
+    );
+    assert!(result.facets.tags.is_empty());
+
+    let result = search_contract_templates(None, Some("finance"), None);
+    assert_eq!(result.total_count, 0);
+}
+
+fn search_contract_templates(
+    text: Option<&str>,
+    category: Option<&str>,
+    tag: Option<&str>,
+) -> GetContractTemplatesResult {
+    get_contract_templates_int(GetContractTemplatesArgs {
+        chunk_def: ChunkDef {
+            start: 0,
+            count: 10,
+        },
+        filter: Some(ContractTemplatesFilter {
+            filter: text.map(str::to_string),
+            blocked: None,
+            retired: None,
+            category: category.map(str::to_string),
+            tag: tag.map(str::to_string),
+            review_status: None,
+        }),
+        sorting: None,
+        include_interface_summary: None,
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_review_contract_template() {
+    let admin = ht_get_test_admin();
+    let contract_def = ht_get_face_contract_def();
+
+    let contract_template_id = ht_try_add_contract(admin, contract_def, TEST_WASM.to_vec())
+        .unwrap()
+        .contract_template_id;
+
+    let review = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template
+        .review;
+    assert_eq!(review.status, ContractTemplateReviewStatus::Submitted);
+
+    // CHECK PERMISSION DENIED
+    ht_set_test_caller(ht_get_test_user());
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::StartReview,
+    );
+    ht_result_err_matches!(result, ReviewContractTemplateError::PermissionDenied);
+
+    // CHECK CONTRACT NOT FOUND
+    ht_set_test_caller(admin);
+    let result = review_contract_template_int(
+        contract_template_id + 1,
+        ContractTemplateReviewAction::StartReview,
+    );
+    ht_result_err_matches!(
+        result,
+        ReviewContractTemplateError::ContractTemplateNotFound
+    );
+
+    // CHECK TRANSITIONS
+    let result =
+        review_contract_template_int(contract_template_id, ContractTemplateReviewAction::Approve);
+    ht_result_err_matches!(
+        result,
+        ReviewContractTemplateError::InvalidReviewStatus {
+            status: ContractTemplateReviewStatus::Submitted
+        }
+    );
+
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::StartReview,
+    );
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateReviewStatusSet { contract_template_id: event_contract_id, status: ContractTemplateReviewStatus::UnderReview }
         if event_contract_id == &contract_template_id);
+
+    // REJECTION NEEDS A REASON
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::Reject {
+            reason: " ".to_string(),
+        },
+    );
+    ht_result_err_matches!(result, ReviewContractTemplateError::ReviewTextIsEmpty);
+
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::Reject {
+            reason: "unsafe upgrade hook".to_string(),
+        },
+    );
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateReviewStatusSet {
+        status: ContractTemplateReviewStatus::Rejected,
+        ..
+    });
+
+    let review = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template
+        .review;
+    assert_eq!(review.status, ContractTemplateReviewStatus::Rejected);
+    assert_eq!(review.reviewer, Some(admin));
+    assert_eq!(
+        review.rejection_reason,
+        Some("unsafe upgrade hook".to_string())
+    );
+
+    // NOTES AND ATTESTATIONS
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::AddNote {
+            note: "upgrade hook fixed in the sources".to_string(),
+        },
+    );
+    assert!(result.is_ok());
+
+    let wasm_hash = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template
+        .definition
+        .wasm_hash;
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::AddBuildAttestation {
+            wasm_hash: "wrong_hash".to_string(),
+            build_url: "https://ci.example.org/builds/1".to_string(),
+            description: None,
+        },
+    );
+    ht_result_err_matches!(
+        result,
+        ReviewContractTemplateError::AttestationWasmHashMismatch
+    );
+
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::AddBuildAttestation {
+            wasm_hash: wasm_hash.clone(),
+            build_url: "https://ci.example.org/builds/1".to_string(),
+            description: Some("docker build, rust 1.90".to_string()),
+        },
+    );
+    assert!(result.is_ok());
+
+    // REVIEW AGAIN AND APPROVE
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::StartReview,
+    );
+    assert!(result.is_ok());
+    let result =
+        review_contract_template_int(contract_template_id, ContractTemplateReviewAction::Approve);
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateReviewStatusSet {
+        status: ContractTemplateReviewStatus::Approved,
+        ..
+    });
+
+    let review = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template
+        .review;
+    assert_eq!(review.status, ContractTemplateReviewStatus::Approved);
+    assert!(review.rejection_reason.is_none());
+    assert_eq!(review.notes.len(), 1);
+    assert_eq!(review.attestations.len(), 1);
+    assert_eq!(review.attestations[0].wasm_hash, wasm_hash);
+
+    // FILTER BY REVIEW STATUS
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        max_contract_templates_per_chunk: 10,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    for (review_status, total_count) in [
+        (ContractTemplateReviewStatus::Approved, 1),
+        (ContractTemplateReviewStatus::Submitted, 0),
+    ] {
+        let result = get_contract_templates_int(GetContractTemplatesArgs {
+            chunk_def: ChunkDef {
+                start: 0,
+                count: 10,
+            },
+            filter: Some(ContractTemplatesFilter {
+                filter: None,
+                blocked: None,
+                retired: None,
+                category: None,
+                tag: None,
+                review_status: Some(review_status),
+            }),
+            sorting: None,
+            include_interface_summary: None,
+        })
+        .unwrap();
+        assert_eq!(result.total_count, total_count);
+    }
 }
@@ -0,0 +1,143 @@
+use candid::Principal;
+use hub_canister_api::{
+    block_contract_template::BlockContractTemplateError,
+    set_config::SetConfigError,
+    set_contract_template_retired::SetContractTemplateRetiredError,
+    types::{Config, ConfigField, HubEventType},
+};
+
+use crate::{
+    ht_last_hub_event_matches, ht_result_err_matches,
+    queries::{
+        get_contract_template::get_contract_template_int,
+        validate_add_contract_template::validate_add_contract_template_int,
+        validate_block_contract_template::validate_block_contract_template_int,
+        validate_set_config::validate_set_config_int,
+        validate_set_contract_template_retired::validate_set_contract_template_retired_int,
+    },
+    test::tests::{
+        components::ic::ht_set_test_caller,
+        drivers::contract::ht_add_contract,
+        ht_get_config, ht_get_test_admin,
+        support::fixtures::{ht_get_face_contract_def, TEST_WASM},
+    },
+    updates::{
+        execute_block_contract_template::execute_block_contract_template_int,
+        execute_set_config::execute_set_config_int,
+        execute_set_contract_template_retired::execute_set_contract_template_retired_int,
+        set_config::set_config_int,
+    },
+};
+
+#[tokio::test]
+async fn test_dao_proposals() {
+    let admin = ht_get_test_admin();
+    let governance = Principal::from_slice(&[31; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    // the DAO is not configured
+    let result = validate_add_contract_template_int(&ht_get_face_contract_def());
+    assert!(result.is_err());
+
+    ht_set_test_caller(governance);
+    let result = execute_set_config_int(ht_get_config());
+    ht_result_err_matches!(result, SetConfigError::PermissionDenied);
+
+    ht_set_test_caller(admin);
+    let result = set_config_int(Config {
+        governance_principal: Some(Principal::anonymous()),
+        ..ht_get_config()
+    });
+    ht_result_err_matches!(result, SetConfigError::WrongConfig { .. });
+
+    let result = set_config_int(Config {
+        governance_principal: Some(governance),
+        ..ht_get_config()
+    });
+    assert!(result.is_ok());
+
+    // SET CONFIG
+    let new_config = Config {
+        name_max_length: 7,
+        ..ht_get_config()
+    };
+
+    let result = validate_set_config_int(&Config {
+        contract_url_pattern: "http://localhost".to_string(),
+        ..new_config.clone()
+    });
+    assert!(result.is_err());
+
+    let result = validate_set_config_int(&new_config);
+    assert!(result.is_ok());
+
+    let result = execute_set_config_int(new_config.clone());
+    ht_result_err_matches!(result, SetConfigError::PermissionDenied);
+
+    ht_set_test_caller(governance);
+    let result = execute_set_config_int(new_config.clone());
+    assert!(result.is_ok());
+    assert_eq!(ht_get_config(), new_config);
+    ht_last_hub_event_matches!(HubEventType::ConfigPatched { changes, .. }
+        if changes == &vec![ConfigField::NameMaxLength(7)]);
+
+    // RETIRE CONTRACT TEMPLATE
+    let result = validate_set_contract_template_retired_int(
+        contract_template_id + 100,
+        Some("obsolete".to_string()),
+        None,
+        None,
+    );
+    assert!(result.is_err());
+
+    let result = validate_set_contract_template_retired_int(
+        contract_template_id,
+        Some("obsolete".to_string()),
+        None,
+        None,
+    );
+    assert!(result.is_ok());
+
+    let result = execute_set_contract_template_retired_int(
+        contract_template_id,
+        None,
+        None,
+        Some(contract_template_id),
+    );
+    ht_result_err_matches!(
+        result,
+        SetContractTemplateRetiredError::InvalidReplacement { .. }
+    );
+
+    let result = execute_set_contract_template_retired_int(
+        contract_template_id,
+        Some("obsolete".to_string()),
+        None,
+        None,
+    );
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ContractTemplateRetired { retired: true, .. });
+
+    let contract = get_contract_template_int(contract_template_id).unwrap();
+    assert!(contract.contract_template.retired.is_some());
+
+    // BLOCK CONTRACT TEMPLATE
+    let result = validate_block_contract_template_int(contract_template_id, "malware");
+    assert!(result.is_ok());
+
+    ht_set_test_caller(admin);
+    let result = execute_block_contract_template_int(contract_template_id, "malware".to_string());
+    ht_result_err_matches!(result, BlockContractTemplateError::PermissionDenied);
+
+    ht_set_test_caller(governance);
+    let result = execute_block_contract_template_int(contract_template_id, "malware".to_string());
+    assert!(result.is_ok());
+
+    let contract = get_contract_template_int(contract_template_id).unwrap();
+    assert!(contract.contract_template.blocked.is_some());
+
+    let result = validate_block_contract_template_int(contract_template_id, "malware");
+    assert!(result.is_err());
+}
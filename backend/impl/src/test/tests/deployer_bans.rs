@@ -0,0 +1,197 @@
+use candid::Principal;
+use common_canister_types::LedgerAccount;
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    set_deployer_ban::SetDeployerBanError,
+    types::{AccessRight, DeployerBanSettings, HubEventType, Permission},
+};
+
+use crate::{
+    ht_last_hub_event_matches, ht_result_err_matches,
+    queries::get_deployer_ban::get_deployer_ban_int,
+    read_state,
+    test::tests::{
+        components::{ic::ht_set_test_caller, time::ht_set_test_time},
+        drivers::{
+            contract::ht_add_contract,
+            deployment::{ht_drive_to_deploying, DeploymentConfig},
+        },
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{ht_get_face_contract_def, TEST_CONTRACT_INITIAL_CYCLES, TEST_WASM},
+    },
+    updates::{
+        deploy_contract::deploy_contract_int, set_access_rights::set_access_rights_int,
+        set_deployer_ban::set_deployer_ban_int,
+    },
+};
+
+#[tokio::test]
+async fn test_set_deployer_ban() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+
+    let settings = DeployerBanSettings {
+        reason: "abuse".to_string(),
+        expiration: Some(10_000),
+    };
+
+    // PERMISSION DENIED
+    ht_set_test_caller(admin);
+    let result = set_deployer_ban_int(deployer, Some(settings.clone()), false);
+    ht_result_err_matches!(result, SetDeployerBanError::PermissionDenied);
+
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetConfig,
+            Permission::BlockDeployer,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // blocking the contracts requires its own permission
+    let result = set_deployer_ban_int(deployer, Some(settings.clone()), true);
+    ht_result_err_matches!(result, SetDeployerBanError::PermissionDenied);
+
+    // INVALID BAN
+    let result = set_deployer_ban_int(
+        deployer,
+        Some(DeployerBanSettings {
+            reason: " ".to_string(),
+            ..settings.clone()
+        }),
+        false,
+    );
+    ht_result_err_matches!(result, SetDeployerBanError::InvalidDeployerBan { .. });
+
+    ht_set_test_time(10_000);
+    let result = set_deployer_ban_int(deployer, Some(settings.clone()), false);
+    ht_result_err_matches!(result, SetDeployerBanError::InvalidDeployerBan { .. });
+
+    let result = set_deployer_ban_int(Principal::anonymous(), Some(settings.clone()), false);
+    ht_result_err_matches!(result, SetDeployerBanError::InvalidDeployerBan { .. });
+
+    // BAN AND BLOCK CONTRACTS
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::BlockDeployer,
+            Permission::BlockContract,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    ht_set_test_time(1_000);
+    let result = set_deployer_ban_int(deployer, Some(settings.clone()), true);
+    assert!(result.is_ok());
+
+    ht_last_hub_event_matches!(HubEventType::ContractBlocked { deployment_ids_count }
+        if deployment_ids_count == &1);
+    read_state(|state| {
+        let model = state.get_model();
+        let hub_events_storage = model.get_hub_events_storage();
+        assert_eq!(
+            hub_events_storage
+                .get_hub_event(hub_events_storage.get_hub_events_len() - 2)
+                .unwrap()
+                .event
+                .clone(),
+            HubEventType::DeployerBanSet {
+                deployer,
+                settings: Some(settings.clone()),
+            }
+        );
+        assert_eq!(
+            model
+                .get_blocked_contracts_storage()
+                .find_deployment_block(&deployment_id)
+                .map(|blocked| blocked.value),
+            Some("abuse".to_string())
+        );
+    });
+
+    let ban = get_deployer_ban_int(deployer).ban.unwrap();
+    assert_eq!(ban.banned, 1_000);
+    assert_eq!(ban.banned_by, admin);
+    assert_eq!(ban.settings, settings);
+
+    // DEPLOYER BANNED
+    let deployer_account = LedgerAccount::Account {
+        owner: deployer,
+        subaccount: None,
+    };
+    ht_set_test_caller(deployer);
+    let result = deploy_contract_int(
+        deployer_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::DeployerBanned { ban }
+        if ban.settings.reason == "abuse");
+
+    // the banned deployer can not be the beneficiary
+    let sponsor = Principal::from_slice(&[14; 29]);
+    ht_set_test_caller(sponsor);
+    let result = deploy_contract_int(
+        LedgerAccount::Account {
+            owner: sponsor,
+            subaccount: None,
+        },
+        contract_template_id,
+        None,
+        None,
+        None,
+        Some(deployer),
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::BeneficiaryBanned { ban }
+        if ban.settings.reason == "abuse");
+    ht_set_test_caller(deployer);
+
+    // BAN EXPIRED
+    ht_set_test_time(10_000);
+    assert_eq!(get_deployer_ban_int(deployer).ban, None);
+    let result = deploy_contract_int(
+        deployer_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    assert!(!matches!(
+        result,
+        Err(DeployContractError::DeployerBanned { .. })
+    ));
+
+    // UNBAN
+    ht_set_test_caller(admin);
+    let result = set_deployer_ban_int(deployer, None, true);
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::DeployerBanSet { settings: None, .. });
+
+    let result = set_deployer_ban_int(deployer, None, false);
+    ht_result_err_matches!(result, SetDeployerBanError::DeployerBanNotFound);
+}
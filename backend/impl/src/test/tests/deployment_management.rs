@@ -1,25 +1,35 @@
 use crate::{
     get_env,
-    handlers::wallet::get_deployment_transit_canister_sub_account,
+    handlers::{
+        templates::check_contract_template_supply,
+        wallet::get_deployment_transit_canister_sub_account,
+    },
     ht_deployment_state_matches, ht_result_err_matches,
-    queries::get_deployment::get_deployment_int,
+    queries::{
+        get_contract_activation_code::get_contract_activation_code_int,
+        get_contract_template::get_contract_template_int,
+        get_contract_template_earnings::get_contract_template_earnings_int,
+        get_deployment::get_deployment_int,
+    },
     read_state,
     test::tests::{
         components::{
             cmc::ht_get_created_canister_over_cmc,
             ic::ht_set_test_caller,
-            ledger::{ht_get_account_balance, HT_LEDGER_FEE},
+            ledger::{
+                ht_deposit_account, ht_get_account_balance, ht_withdraw_from_account, HT_LEDGER_FEE,
+            },
             time::ht_set_test_time,
         },
         drivers::{
-            contract::ht_add_contract,
+            contract::{ht_add_contract, ht_try_add_contract},
             deployment::{
                 get_deployment_lock_expiration, ht_assert_activation_code_errors,
                 ht_assert_certificate_errors_and_initialize, ht_assert_deploying_result,
                 ht_assert_deployment_fields, ht_assert_process_deployment_errors,
                 ht_calc_expenses_amount, ht_calc_expenses_amount_buffered, ht_drive_to_deploying,
-                ht_drive_upload_to_start_finalization, ht_fund_deployer_account,
-                ht_setup_deployment_config, DeploymentConfig,
+                ht_drive_to_finalized, ht_drive_upload_to_start_finalization,
+                ht_fund_deployer_account, ht_setup_deployment_config, DeploymentConfig,
             },
         },
         ht_get_test_admin, ht_get_test_user,
@@ -29,21 +39,30 @@ use crate::{
         },
     },
     updates::{
+        accept_deployment_ownership_transfer::accept_deployment_ownership_transfer_int,
         block_contract_template::block_contract_template_int,
         cancel_deployment::cancel_deployment_int, deploy_contract::deploy_contract_int,
-        process_deployment::process_deployment_int, set_access_rights::set_access_rights_int,
+        process_deployment::process_deployment_int,
+        propose_deployment_ownership_transfer::propose_deployment_ownership_transfer_int,
+        review_contract_template::review_contract_template_int,
+        set_access_rights::set_access_rights_int, set_config::set_config_int,
         set_contract_template_retired::set_contract_template_retired_int,
     },
 };
 use candid::Principal;
+use common_canister_impl::components::ledger::to_account_identifier;
 use common_canister_types::LedgerAccount;
 use hub_canister_api::{
+    add_contract_template::AddContractTemplateError,
     cancel_deployment::CancelDeploymentError,
     deploy_contract::DeployContractError,
+    get_contract_activation_code::GetContractActivationCodeError,
     get_deployment::{DeploymentFilter, GetDeploymentError, GetDeploymentResult},
+    review_contract_template::ContractTemplateReviewAction,
     types::{
-        AccessRight, CreateContractCanisterStrategy, CyclesConvertingStrategy, DeploymentResult,
-        DeploymentState, FinalizeDeploymentState, Permission,
+        AccessRight, Config, ContractTemplateDefinition, CreateContractCanisterStrategy,
+        CyclesConvertingStrategy, DeploymentBeneficiary, DeploymentResult, DeploymentState,
+        FinalizeDeploymentState, Permission, PublisherFee, PublisherFeeAmount,
     },
 };
 use ic_ledger_types::{AccountIdentifier, DEFAULT_SUBACCOUNT};
@@ -62,7 +81,15 @@ async fn test_deploy_contract_caller_not_authorized() {
     };
 
     ht_set_test_caller(Principal::anonymous());
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::CallerNotAuthorized);
 }
 
@@ -80,7 +107,15 @@ async fn test_deploy_contract_deployment_unavailable() {
     };
 
     ht_set_test_caller(admin);
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::DeploymentUnavailable);
 }
 
@@ -101,8 +136,15 @@ async fn test_deploy_contract_contract_not_found() {
     ht_setup_deployment_config(admin, &DeploymentConfig::default());
 
     ht_set_test_caller(ht_get_test_user());
-    let result =
-        deploy_contract_int(approved_account.clone(), contract_template_id + 1, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id + 1,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::ContractTemplateNotFound);
 }
 
@@ -124,13 +166,29 @@ async fn test_deploy_contract_invalid_approved_account() {
         owner: deployer,
         subaccount: Some(vec![1]),
     };
-    let result = deploy_contract_int(approved_account, contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::InvalidApprovedAccount { .. });
 
     let approved_account = LedgerAccount::AccountIdentifier {
         slice: Principal::management_canister().as_slice().to_vec(),
     };
-    let result = deploy_contract_int(approved_account, contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::InvalidApprovedAccount { .. });
 }
 
@@ -159,7 +217,15 @@ async fn test_deploy_contract_low_balance() {
         subaccount: None,
     };
     // No funds deposited — balance is zero
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(
         result,
         DeployContractError::InsufficientApprovedAccountBalance
@@ -190,7 +256,15 @@ async fn test_deploy_contract_low_allowance() {
         deployment_cfg.deployment_allowance_expiration_timeout,
     );
 
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(
         result,
         DeployContractError::InsufficientApprovedAccountAllowance
@@ -223,7 +297,15 @@ async fn test_deploy_contract_allowance_expired() {
     // Advance time past the allowance expiration window — should trigger AllowanceExpiresTooEarly
     ht_set_test_time(1);
 
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::AllowanceExpiresTooEarly);
 }
 
@@ -339,7 +421,15 @@ async fn test_deploy_contract_with_cmc() {
     );
 
     // CHECK ACTIVE DEPLOYMENT EXISTS
-    let result = deploy_contract_int(approved_account, contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::ActiveDeploymentExists { .. });
 
     let env = get_env();
@@ -497,7 +587,15 @@ async fn test_deploy_contract_without_cmc() {
     );
 
     // CHECK ACTIVE DEPLOYMENT EXISTS
-    let result = deploy_contract_int(approved_account, contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::ActiveDeploymentExists { .. });
 
     let env = get_env();
@@ -628,7 +726,15 @@ async fn test_deploy_contract_with_after_buffer() {
     ht_set_test_time(0);
 
     ht_set_test_caller(deployer);
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     assert!(result.is_ok());
     let deployment = result.unwrap().deployment;
 
@@ -684,7 +790,15 @@ async fn test_deploy_contract_with_before_buffer() {
     ht_set_test_time(0);
 
     ht_set_test_caller(deployer);
-    let result = deploy_contract_int(approved_account, contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     assert!(result.is_ok());
     let deployment = result.unwrap().deployment;
 
@@ -736,23 +850,41 @@ async fn test_deploy_contract_template_retired() {
     let result = set_contract_template_retired_int(
         contract_template_id,
         Some("no longer supported".to_string()),
+        None,
+        None,
     );
     assert!(result.is_ok());
 
     // Deploy must fail with ContractTemplateRetired
     let deployer = ht_get_test_user();
     ht_set_test_caller(deployer);
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
-    ht_result_err_matches!(result, DeployContractError::ContractTemplateRetired);
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::ContractTemplateRetired { .. });
 
     // Unretire the template
     ht_set_test_caller(admin);
-    let result = set_contract_template_retired_int(contract_template_id, None);
+    let result = set_contract_template_retired_int(contract_template_id, None, None, None);
     assert!(result.is_ok());
 
     // After unretire — deploy proceeds past the template check (fails on balance, not on template)
     ht_set_test_caller(deployer);
-    let result = deploy_contract_int(approved_account.clone(), contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(
         result,
         DeployContractError::InsufficientApprovedAccountBalance
@@ -796,63 +928,834 @@ async fn test_deploy_blocked_contract_template() {
     // Deploy must fail with ContractTemplateBlocked
     let deployer = ht_get_test_user();
     ht_set_test_caller(deployer);
-    let result = deploy_contract_int(approved_account, contract_template_id, None).await;
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
     ht_result_err_matches!(result, DeployContractError::ContractTemplateBlocked);
 }
 
 #[tokio::test]
-async fn test_process_deployment_locked() {
+async fn test_deploy_contract_template_not_approved() {
     let admin = ht_get_test_admin();
-    let deployer = ht_get_test_user();
-
     let contract_template_id =
         ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
 
-    // ht_drive_to_deploying sets time=0 and funds the deployer account.
-    let dr = ht_drive_to_deploying(
+    let approved_account = LedgerAccount::Account {
+        owner: ht_get_test_user(),
+        subaccount: None,
+    };
+
+    // Enable deployment
+    ht_setup_deployment_config(admin, &DeploymentConfig::default());
+
+    // Grant ReviewContractTemplate permission and start a new review
+    ht_set_test_caller(admin);
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::ReviewContractTemplate,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    let result = review_contract_template_int(
+        contract_template_id,
+        ContractTemplateReviewAction::StartReview,
+    );
+    assert!(result.is_ok());
+
+    // Deploy must fail with ContractTemplateNotApproved
+    let deployer = ht_get_test_user();
+    ht_set_test_caller(deployer);
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::ContractTemplateNotApproved);
+
+    // Approve the template
+    ht_set_test_caller(admin);
+    let result =
+        review_contract_template_int(contract_template_id, ContractTemplateReviewAction::Approve);
+    assert!(result.is_ok());
+
+    // After approval — deploy proceeds past the template check
+    ht_set_test_caller(deployer);
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(
+        result,
+        DeployContractError::InsufficientApprovedAccountBalance
+    );
+}
+
+#[tokio::test]
+async fn test_deploy_contract_with_publisher_fee() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let publisher_account = LedgerAccount::Account {
+        owner: Principal::from_slice(&[1, 2, 3]),
+        subaccount: None,
+    };
+    let publisher_fee_amount = 1_000_000;
+
+    // INVALID PUBLISHER FEE
+    let result = ht_try_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            publisher_fee: Some(PublisherFee {
+                amount: PublisherFeeAmount::Share { permyriad: 10_001 },
+                payout_account: publisher_account.clone(),
+            }),
+            ..ht_get_face_contract_def()
+        },
+        TEST_WASM.to_vec(),
+    );
+    ht_result_err_matches!(result, AddContractTemplateError::InvalidPublisherFee { .. });
+
+    let publisher_fee = PublisherFee {
+        amount: PublisherFeeAmount::Flat {
+            amount: publisher_fee_amount,
+        },
+        payout_account: publisher_account.clone(),
+    };
+    let contract_template_id = ht_add_contract(
         admin,
+        ContractTemplateDefinition {
+            publisher_fee: Some(publisher_fee.clone()),
+            ..ht_get_face_contract_def()
+        },
+        TEST_WASM.to_vec(),
+    );
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    ht_setup_deployment_config(admin, &deployment_cfg);
+
+    // the publisher fee is charged on top of the deployment expenses
+    let expenses_amount = ht_calc_expenses_amount(&deployment_cfg, TEST_CONTRACT_INITIAL_CYCLES);
+    let (approved_account, _) = ht_fund_deployer_account(
         deployer,
+        expenses_amount + publisher_fee_amount - 1,
+        expenses_amount + publisher_fee_amount - 1,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
+    ht_set_test_time(0);
+    ht_set_test_caller(deployer);
+    let result = deploy_contract_int(
+        approved_account,
         contract_template_id,
-        &DeploymentConfig::default(),
-        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+        None,
+        None,
         None,
     )
     .await;
-    let deployment_id = dr.deployment_id;
+    ht_result_err_matches!(
+        result,
+        DeployContractError::InsufficientApprovedAccountBalance
+    );
 
-    // The deployment was just created — it carries an initial processing lock
-    // (verified by cancel_deployment_int returning DeploymentLocked at time=0).
-    // Calling process_deployment_int while the lock is still active must:
-    // - succeed (no error — ProcessDeploymentError has no DeploymentLocked variant)
-    // - NOT advance the state
+    // deposit the missing e8s
+    let (approved_account, _) = ht_fund_deployer_account(
+        deployer,
+        expenses_amount + publisher_fee_amount,
+        1,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
     ht_set_test_caller(deployer);
-    let result = process_deployment_int(deployment_id).await;
-    assert!(result.is_ok());
-    ht_deployment_state_matches!(
-        &deployment_id,
-        DeploymentState::TransferDeployerFundsToTransitAccount
+    let deployment = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap()
+    .deployment;
+    let deployment_id = deployment.deployment_id;
+    assert_eq!(
+        deployment.expenses_amount,
+        expenses_amount + publisher_fee_amount
+    );
+    assert_eq!(deployment.publisher_fee_amount, publisher_fee_amount);
+    assert_eq!(
+        deployment.deployment_expenses.publisher_fee,
+        Some(publisher_fee.clone())
     );
 
-    // Once the lock expires, the same call advances the state normally.
+    // PROCESS DEPLOYMENT up to finalization
+    assert!(process_deployment_int(deployment_id).await.is_ok());
     ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
-    let result = process_deployment_int(deployment_id).await;
-    assert!(result.is_ok());
-    ht_deployment_state_matches!(&deployment_id, DeploymentState::TransferTopUpFundsToCMC);
-}
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(&deployment_id, DeploymentState::GenerateContractCertificate);
 
-#[tokio::test]
-async fn test_get_deployment_not_found() {
-    let admin = ht_get_test_admin();
+    ht_assert_certificate_errors_and_initialize(admin, deployer, &deployment_id).await;
+    ht_drive_upload_to_start_finalization(deployer, &deployment_id).await;
 
-    // Initialise the hub with a contract template but create no deployments.
-    ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+    // PROCESS DEPLOYMENT TransferPublisherFee
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::FinalizeDeployment {
+            result: DeploymentResult::Success,
+            sub_state: FinalizeDeploymentState::TransferTransitFundsToExternalService
+        }
+    );
 
-    // Query by non-existent deployment id
-    let result = get_deployment_int(DeploymentFilter::ByDeploymentId { deployment_id: 0 });
-    ht_result_err_matches!(result, GetDeploymentError::DeploymentNotFound);
+    let publisher_account_hex = to_account_identifier(&publisher_account).unwrap().to_hex();
+    assert_eq!(
+        ht_get_account_balance(publisher_account_hex),
+        publisher_fee_amount - HT_LEDGER_FEE
+    );
 
-    // Query for active deployment when none exists
-    let deployer = ht_get_test_user();
-    let result = get_deployment_int(DeploymentFilter::Active { deployer });
-    ht_result_err_matches!(result, GetDeploymentError::DeploymentNotFound);
+    // PROCESS DEPLOYMENT TransferTransitFundsToExternalService
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::FinalizeDeployment {
+            result: DeploymentResult::Success,
+            sub_state: FinalizeDeploymentState::Finalized
+        }
+    );
+
+    let deployment_fallback_account =
+        AccountIdentifier::new(&Principal::management_canister(), &DEFAULT_SUBACCOUNT);
+    assert_eq!(
+        ht_get_account_balance(deployment_fallback_account.to_hex()),
+        expenses_amount - 2 * HT_LEDGER_FEE
+    );
+
+    // EARNINGS REPORT
+    let result = get_contract_template_earnings_int(contract_template_id).unwrap();
+    assert_eq!(result.publisher_fee, Some(publisher_fee));
+    assert_eq!(
+        result.earnings.total_amount,
+        publisher_fee_amount - HT_LEDGER_FEE
+    );
+    assert_eq!(result.earnings.payouts_count, 1);
+    assert!(result.earnings.last_payout.is_some());
+}
+
+#[tokio::test]
+async fn test_deploy_contract_with_beneficiaries() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    let dr = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &deployment_cfg,
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await;
+    let deployment_id = dr.deployment_id;
+
+    let first_account = LedgerAccount::Account {
+        owner: Principal::from_slice(&[1, 2, 3]),
+        subaccount: None,
+    };
+    let second_account_identifier =
+        AccountIdentifier::new(&Principal::from_slice(&[4, 5, 6]), &DEFAULT_SUBACCOUNT);
+    let second_account = LedgerAccount::AccountIdentifier {
+        slice: second_account_identifier.as_ref().to_vec(),
+    };
+
+    ht_set_test_caller(admin);
+    let result = set_config_int(Config {
+        deployment_beneficiaries: vec![
+            DeploymentBeneficiary {
+                account: first_account.clone(),
+                share_permyriad: 3_333,
+            },
+            DeploymentBeneficiary {
+                account: second_account.clone(),
+                share_permyriad: 6_667,
+            },
+        ],
+        ..dr.config.clone()
+    });
+    assert!(result.is_ok());
+
+    // PROCESS DEPLOYMENT up to finalization
+    ht_set_test_caller(deployer);
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(&deployment_id, DeploymentState::GenerateContractCertificate);
+
+    ht_assert_certificate_errors_and_initialize(admin, deployer, &deployment_id).await;
+    ht_drive_upload_to_start_finalization(deployer, &deployment_id).await;
+
+    // the remainder of rounding goes to the first beneficiary
+    let transit_amount = dr.expenses_amount - HT_LEDGER_FEE;
+    let second_amount = transit_amount * 6_667 / 10_000;
+    let first_amount = transit_amount - second_amount;
+
+    // PROCESS DEPLOYMENT first beneficiary
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::FinalizeDeployment {
+            result: DeploymentResult::Success,
+            sub_state: FinalizeDeploymentState::TransferTransitFundsToBeneficiaries {
+                payouts,
+                paid_count: 1
+            }
+        } if payouts.len() == 2
+            && payouts[0].amount == first_amount
+            && payouts[1].amount == second_amount
+    );
+
+    let first_account_hex = to_account_identifier(&first_account).unwrap().to_hex();
+    assert_eq!(
+        ht_get_account_balance(first_account_hex.clone()),
+        first_amount - HT_LEDGER_FEE
+    );
+
+    // PROCESS DEPLOYMENT second beneficiary fails, the paid beneficiary is not paid twice
+    let transit_account = get_env()
+        .get_ledger()
+        .get_canister_account(&get_deployment_transit_canister_sub_account(&deployment_id));
+    assert!(matches!(
+        ht_withdraw_from_account(transit_account.to_hex(), second_amount),
+        Ok(Ok(_))
+    ));
+
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::FinalizeDeployment {
+            sub_state: FinalizeDeploymentState::TransferTransitFundsToBeneficiaries {
+                paid_count: 1,
+                ..
+            },
+            ..
+        }
+    );
+    assert!(
+        get_deployment_int(DeploymentFilter::ByDeploymentId { deployment_id })
+            .unwrap()
+            .deployment
+            .processing_error
+            .is_some()
+    );
+
+    // PROCESS DEPLOYMENT resume from the second beneficiary
+    ht_deposit_account(&transit_account, second_amount);
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::FinalizeDeployment {
+            result: DeploymentResult::Success,
+            sub_state: FinalizeDeploymentState::Finalized
+        }
+    );
+
+    assert_eq!(
+        ht_get_account_balance(first_account_hex),
+        first_amount - HT_LEDGER_FEE
+    );
+    assert_eq!(
+        ht_get_account_balance(second_account_identifier.to_hex()),
+        second_amount - HT_LEDGER_FEE
+    );
+    assert_eq!(ht_get_account_balance(transit_account.to_hex()), 0);
+
+    let contract_template = get_contract_template_int(contract_template_id)
+        .unwrap()
+        .contract_template;
+    assert_eq!(contract_template.deployments_count, 1);
+}
+
+#[tokio::test]
+async fn test_process_deployment_locked() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    // ht_drive_to_deploying sets time=0 and funds the deployer account.
+    let dr = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await;
+    let deployment_id = dr.deployment_id;
+
+    // The deployment was just created — it carries an initial processing lock
+    // (verified by cancel_deployment_int returning DeploymentLocked at time=0).
+    // Calling process_deployment_int while the lock is still active must:
+    // - succeed (no error — ProcessDeploymentError has no DeploymentLocked variant)
+    // - NOT advance the state
+    ht_set_test_caller(deployer);
+    let result = process_deployment_int(deployment_id).await;
+    assert!(result.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::TransferDeployerFundsToTransitAccount
+    );
+
+    // Once the lock expires, the same call advances the state normally.
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    let result = process_deployment_int(deployment_id).await;
+    assert!(result.is_ok());
+    ht_deployment_state_matches!(&deployment_id, DeploymentState::TransferTopUpFundsToCMC);
+}
+
+#[tokio::test]
+async fn test_get_deployment_not_found() {
+    let admin = ht_get_test_admin();
+
+    // Initialise the hub with a contract template but create no deployments.
+    ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    // Query by non-existent deployment id
+    let result = get_deployment_int(DeploymentFilter::ByDeploymentId { deployment_id: 0 });
+    ht_result_err_matches!(result, GetDeploymentError::DeploymentNotFound);
+
+    // Query for active deployment when none exists
+    let deployer = ht_get_test_user();
+    let result = get_deployment_int(DeploymentFilter::Active { deployer });
+    ht_result_err_matches!(result, GetDeploymentError::DeploymentNotFound);
+}
+
+#[tokio::test]
+async fn test_deploy_contract_on_behalf_of_beneficiary() {
+    let admin = ht_get_test_admin();
+    let payer = ht_get_test_user();
+    let beneficiary = Principal::from_slice(&[8; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    ht_setup_deployment_config(admin, &deployment_cfg);
+
+    let expenses_amount = ht_calc_expenses_amount(&deployment_cfg, TEST_CONTRACT_INITIAL_CYCLES);
+    let (approved_account, _) = ht_fund_deployer_account(
+        payer,
+        expenses_amount,
+        expenses_amount,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
+    ht_set_test_time(0);
+    ht_set_test_caller(payer);
+
+    // INVALID BENEFICIARY
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        Some(Principal::anonymous()),
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::InvalidBeneficiary);
+
+    // DEPLOY ON BEHALF
+    let deployment = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        Some(beneficiary),
+    )
+    .await
+    .unwrap()
+    .deployment;
+    let deployment_id = deployment.deployment_id;
+    assert_eq!(deployment.deployer, payer);
+    assert_eq!(deployment.beneficiary, Some(beneficiary));
+
+    // the deployment is indexed by the beneficiary
+    let result = get_deployment_int(DeploymentFilter::Active {
+        deployer: beneficiary,
+    });
+    assert!(result.is_ok());
+    let result = get_deployment_int(DeploymentFilter::Active { deployer: payer });
+    ht_result_err_matches!(result, GetDeploymentError::DeploymentNotFound);
+
+    // only the beneficiary gets the activation code
+    let result = get_contract_activation_code_int(deployment_id);
+    ht_result_err_matches!(result, GetContractActivationCodeError::PermissionDenied);
+    ht_set_test_caller(beneficiary);
+    assert!(get_contract_activation_code_int(deployment_id).is_ok());
+
+    // PROCESS DEPLOYMENT up to the certificate generation
+    ht_set_test_caller(payer);
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(&deployment_id, DeploymentState::GenerateContractCertificate);
+
+    // the payer can not cancel after the contract canister is created
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    let result = cancel_deployment_int(deployment_id, "test".to_string()).await;
+    ht_result_err_matches!(result, CancelDeploymentError::PermissionDenied);
+
+    // the certificate names the beneficiary
+    let certificate =
+        ht_assert_certificate_errors_and_initialize(admin, payer, &deployment_id).await;
+    assert_eq!(certificate.contract_certificate.deployer, beneficiary);
+
+    // the payer can cancel before the contract canister is created
+    let other_beneficiary = Principal::from_slice(&[9; 29]);
+    let (approved_account, _) = ht_fund_deployer_account(
+        payer,
+        expenses_amount,
+        expenses_amount,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
+    ht_set_test_caller(payer);
+    let deployment = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        Some(other_beneficiary),
+    )
+    .await
+    .unwrap()
+    .deployment;
+    assert!(deployment.contract_canister.is_none());
+
+    ht_set_test_time(get_deployment_lock_expiration(&deployment.deployment_id));
+    let result = cancel_deployment_int(deployment.deployment_id, "test".to_string()).await;
+    assert!(result.is_ok());
+    ht_deployment_state_matches!(
+        &deployment.deployment_id,
+        DeploymentState::FinalizeDeployment {
+            result: DeploymentResult::Cancelled { .. },
+            ..
+        }
+    );
+
+    // the payer with an active deployment can not deploy on behalf
+    let active_deployment_id = ht_drive_to_deploying(
+        admin,
+        payer,
+        contract_template_id,
+        &deployment_cfg,
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+    let (approved_account, _) = ht_fund_deployer_account(
+        payer,
+        expenses_amount,
+        expenses_amount,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        Some(Principal::from_slice(&[10; 29])),
+    )
+    .await;
+    ht_result_err_matches!(
+        result,
+        DeployContractError::ActiveDeploymentExists { deployment } if deployment.deployment_id == active_deployment_id
+    );
+}
+
+#[tokio::test]
+async fn test_deploy_contract_with_supply_limits() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let second_deployer = Principal::from_slice(&[5; 29]);
+    let third_deployer = Principal::from_slice(&[6; 29]);
+
+    // INVALID DEPLOYMENT LIMITS
+    let result = ht_try_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            max_deployments: Some(0),
+            ..ht_get_face_contract_def()
+        },
+        TEST_WASM.to_vec(),
+    );
+    ht_result_err_matches!(
+        result,
+        AddContractTemplateError::InvalidDeploymentLimits { .. }
+    );
+
+    let result = ht_try_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            max_deployments: Some(1),
+            max_deployments_per_deployer: Some(2),
+            ..ht_get_face_contract_def()
+        },
+        TEST_WASM.to_vec(),
+    );
+    ht_result_err_matches!(
+        result,
+        AddContractTemplateError::InvalidDeploymentLimits { .. }
+    );
+
+    let contract_template_id = ht_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            max_deployments: Some(2),
+            max_deployments_per_deployer: Some(1),
+            ..ht_get_face_contract_def()
+        },
+        TEST_WASM.to_vec(),
+    );
+    let get_supply = || {
+        let contract_template = get_contract_template_int(contract_template_id)
+            .unwrap()
+            .contract_template;
+        (
+            contract_template.deployments_count,
+            contract_template.reserved_deployments_count,
+            contract_template.remaining_supply,
+        )
+    };
+    assert_eq!(get_supply(), (0, 0, Some(2)));
+
+    // THE IN-FLIGHT DEPLOYMENT RESERVES THE SUPPLY
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+    assert_eq!(get_supply(), (0, 1, Some(1)));
+
+    // DEPLOYER SUPPLY EXHAUSTED
+    let result = deploy_contract_int(
+        LedgerAccount::Account {
+            owner: deployer,
+            subaccount: None,
+        },
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(
+        result,
+        DeployContractError::DeployerSupplyExhausted { max_deployments } if max_deployments == 1
+    );
+
+    // CONTRACT TEMPLATE SUPPLY EXHAUSTED
+    ht_drive_to_deploying(
+        admin,
+        second_deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await;
+    assert_eq!(get_supply(), (0, 2, Some(0)));
+
+    ht_set_test_caller(third_deployer);
+    let result = deploy_contract_int(
+        LedgerAccount::Account {
+            owner: third_deployer,
+            subaccount: None,
+        },
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::ContractTemplateSupplyExhausted);
+
+    // THE CANCELLED DEPLOYMENT RELEASES THE SUPPLY
+    ht_set_test_caller(deployer);
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    let result = cancel_deployment_int(deployment_id, "test".to_string()).await;
+    assert!(result.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::FinalizeDeployment {result, sub_state}
+        if matches!(result, DeploymentResult::Cancelled {..}) && matches!(sub_state, FinalizeDeploymentState::Finalized)
+    );
+    // the finalized cancellation is not counted as deployed
+    assert_eq!(get_supply(), (0, 1, Some(1)));
+
+    // the cancelled deployment is not counted against the deployer
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+    assert_eq!(get_supply(), (0, 2, Some(0)));
+
+    // THE FINALIZED DEPLOYMENT TURNS THE RESERVATION INTO THE DEPLOYED CONTRACT
+    ht_drive_to_finalized(deployer, &deployment_id).await;
+    assert_eq!(get_supply(), (1, 1, Some(0)));
+}
+
+#[tokio::test]
+async fn test_deploy_contract_deployer_supply_counts_payer() {
+    let admin = ht_get_test_admin();
+    let payer = ht_get_test_user();
+    let beneficiary = Principal::from_slice(&[8; 29]);
+    let other_beneficiary = Principal::from_slice(&[9; 29]);
+    let deployer = Principal::from_slice(&[5; 29]);
+    let new_owner = Principal::from_slice(&[6; 29]);
+
+    let contract_template_id = ht_add_contract(
+        admin,
+        ContractTemplateDefinition {
+            max_deployments_per_deployer: Some(1),
+            ..ht_get_face_contract_def()
+        },
+        TEST_WASM.to_vec(),
+    );
+    let check_deployer_supply = |deployer: Principal| {
+        read_state(|state| {
+            let contract_template = state
+                .get_model()
+                .get_contract_templates_storage()
+                .get_contract_template(&contract_template_id)
+                .unwrap();
+            check_contract_template_supply(
+                state.get_model(),
+                &contract_template,
+                contract_template_id,
+                deployer,
+            )
+        })
+    };
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    ht_setup_deployment_config(admin, &deployment_cfg);
+
+    let expenses_amount = ht_calc_expenses_amount(&deployment_cfg, TEST_CONTRACT_INITIAL_CYCLES);
+    let (approved_account, _) = ht_fund_deployer_account(
+        payer,
+        expenses_amount,
+        expenses_amount,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
+    ht_set_test_time(0);
+    ht_set_test_caller(payer);
+
+    // THE DEPLOYMENT ON BEHALF IS COUNTED AGAINST THE PAYER
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        Some(beneficiary),
+    )
+    .await;
+    assert!(result.is_ok());
+
+    let result = deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        Some(other_beneficiary),
+    )
+    .await;
+    ht_result_err_matches!(
+        result,
+        DeployContractError::DeployerSupplyExhausted { max_deployments } if max_deployments == 1
+    );
+    assert!(check_deployer_supply(beneficiary).is_ok());
+
+    // THE TRANSFERRED DEPLOYMENT IS STILL COUNTED AGAINST THE PAYER
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &deployment_cfg,
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+    ht_drive_to_finalized(deployer, &deployment_id).await;
+
+    ht_set_test_caller(deployer);
+    assert!(propose_deployment_ownership_transfer_int(deployment_id, Some(new_owner)).is_ok());
+    ht_set_test_caller(new_owner);
+    assert!(accept_deployment_ownership_transfer_int(deployment_id).is_ok());
+
+    ht_result_err_matches!(
+        check_deployer_supply(deployer),
+        DeployContractError::DeployerSupplyExhausted { .. }
+    );
+    assert!(check_deployer_supply(new_owner).is_ok());
 }
@@ -2,7 +2,8 @@ use candid::Principal;
 use common_contract_api::get_wasm_hash;
 use common_contract_api::ContractTemplateId;
 use hub_canister_api::{
-    add_contract_template::AddContractTemplateResult,
+    add_contract_template::{AddContractTemplateError, AddContractTemplateResult},
+    review_contract_template::ContractTemplateReviewAction,
     types::{AccessRight, Config, ContractTemplateDefinition, Permission, UploadWasmGrant},
 };
 
@@ -13,9 +14,10 @@ use crate::{
         ht_set_initial_config,
     },
     updates::{
-        add_contract_template::add_contract_template_int, set_access_rights::set_access_rights_int,
-        set_config::set_config_int, set_upload_wasm_grant::set_upload_wasm_grant_int,
-        upload_wasm_chunk::upload_wasm_chunk_int,
+        add_contract_template::add_contract_template_int,
+        review_contract_template::review_contract_template_int,
+        set_access_rights::set_access_rights_int, set_config::set_config_int,
+        set_upload_wasm_grant::set_upload_wasm_grant_int, upload_wasm_chunk::upload_wasm_chunk_int,
     },
 };
 
@@ -33,12 +35,42 @@ use crate::{
 /// 5. Sets `contract_wasm_max_size` in config.
 /// 6. Issues an upload-wasm grant to the test-user operator.
 /// 7. Uploads the WASM as the operator.
-/// 8. Adds the contract template as `admin` and returns the id.
+/// 8. Adds the contract template as `admin`.
+/// 9. Reviews and approves the contract template as `admin` and returns the id.
 pub(crate) fn ht_add_contract(
     admin: Principal,
     contract_def: ContractTemplateDefinition,
     wasm: Vec<u8>,
 ) -> ContractTemplateId {
+    let contract_template_id = match ht_try_add_contract(admin, contract_def, wasm) {
+        Ok(AddContractTemplateResult {
+            contract_template_id,
+        }) => contract_template_id,
+        Err(error) => panic!("ht_add_contract: add_contract_template_int failed: {error:?}"),
+    };
+
+    for action in [
+        ContractTemplateReviewAction::StartReview,
+        ContractTemplateReviewAction::Approve,
+    ] {
+        let result = review_contract_template_int(contract_template_id, action);
+        assert!(
+            result.is_ok(),
+            "review_contract_template failed: {:?}",
+            result
+        );
+    }
+    contract_template_id
+}
+
+/// Same as [`ht_add_contract`], but returns the result of adding the contract
+/// template instead of panicking, so the WASM validation errors can be asserted.
+/// The added contract template remains submitted for the review.
+pub(crate) fn ht_try_add_contract(
+    admin: Principal,
+    contract_def: ContractTemplateDefinition,
+    wasm: Vec<u8>,
+) -> Result<AddContractTemplateResult, AddContractTemplateError> {
     ht_init_test_hub();
 
     ht_set_test_caller(admin);
@@ -49,6 +81,7 @@ pub(crate) fn ht_add_contract(
             Permission::SetAccessRights,
             Permission::SetConfig,
             Permission::AddContractTemplate,
+            Permission::ReviewContractTemplate,
         ]),
         description: None,
     }]);
@@ -88,10 +121,5 @@ pub(crate) fn ht_add_contract(
 
     // Add the contract template as the admin.
     ht_set_test_caller(admin);
-    match add_contract_template_int(contract_def.clone()) {
-        Ok(AddContractTemplateResult {
-            contract_template_id,
-        }) => contract_template_id,
-        Err(error) => panic!("ht_add_contract: add_contract_template_int failed: {error:?}"),
-    }
+    add_contract_template_int(contract_def)
 }
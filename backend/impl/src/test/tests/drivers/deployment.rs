@@ -171,6 +171,8 @@ pub(crate) fn ht_calc_expenses_amount_buffered(
         },
         amount_buffer_permyriad: deployment_cfg.deployment_expenses_amount_buffer_permyriad,
         amount_decimal_places: deployment_cfg.deployment_expenses_amount_decimal_places,
+        publisher_fee: None,
+        voucher_discount: None,
     });
 
     let base = calculator.get_deployment_expenses_amount().unwrap();
@@ -474,8 +476,15 @@ pub(crate) async fn ht_drive_to_deploying(
 
     // 4. Start deployment
     ht_set_test_caller(deployer);
-    let result =
-        deploy_contract_int(approved_account.clone(), contract_template_id, subnet_type).await;
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        subnet_type,
+        None,
+        None,
+        None,
+    )
+    .await;
     assert!(
         result.is_ok(),
         "ht_drive_to_deploying: deploy_contract_int failed: {:?}",
@@ -498,7 +507,6 @@ pub(crate) async fn ht_drive_to_deploying(
 }
 
 /// Drives an existing deployment all the way to the **Finalized** state.
-#[allow(dead_code)]
 ///
 /// The function processes every deployment step by:
 /// 1. Advancing the test time to just past the lock expiration.
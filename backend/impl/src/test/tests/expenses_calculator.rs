@@ -1,4 +1,7 @@
-use hub_canister_api::types::{DeploymentExpenses, IcpConversationRate};
+use common_canister_types::LedgerAccount;
+use hub_canister_api::types::{
+    DeploymentExpenses, IcpConversationRate, PublisherFee, PublisherFeeAmount, VoucherDiscount,
+};
 
 use crate::handlers::deployments::expenses_calculator::{
     round_e8s_ceil, DeploymentExpensesCalculator,
@@ -27,6 +30,8 @@ fn test_deployment_expenses_calculator_expenses() {
         },
         amount_buffer_permyriad: 0,
         amount_decimal_places: 0,
+        publisher_fee: None,
+        voucher_discount: None,
     });
     assert_eq!(calculator.get_deployment_expenses_amount().unwrap(), 105);
 
@@ -38,6 +43,8 @@ fn test_deployment_expenses_calculator_expenses() {
         },
         amount_buffer_permyriad: 0,
         amount_decimal_places: 0,
+        publisher_fee: None,
+        voucher_discount: None,
     });
     assert_eq!(calculator.get_deployment_expenses_amount().unwrap(), 66);
 }
@@ -52,6 +59,8 @@ fn test_deployment_expenses_calculator_round() {
         icp_conversation_rate: IcpConversationRate::Fixed {
             xdr_permyriad_per_icp: 0,
         },
+        publisher_fee: None,
+        voucher_discount: None,
     };
 
     // round 8
@@ -141,3 +150,87 @@ fn test_deployment_expenses_calculator_round() {
         100_000_000
     );
 }
+
+#[test]
+fn test_deployment_expenses_calculator_publisher_fee() {
+    let deployment_expenses = DeploymentExpenses {
+        contract_initial_cycles: 1_000_000,
+        deployment_cycles_cost: 50_000,
+        icp_conversation_rate: IcpConversationRate::Fixed {
+            xdr_permyriad_per_icp: 10_000,
+        },
+        amount_buffer_permyriad: 1_000,
+        amount_decimal_places: 0,
+        publisher_fee: None,
+        voucher_discount: None,
+    };
+    let payout_account = LedgerAccount::AccountIdentifier { slice: vec![0; 32] };
+
+    let calculator = DeploymentExpensesCalculator::new(deployment_expenses.clone());
+    assert_eq!(calculator.get_publisher_fee_amount(105).unwrap(), 0);
+
+    let calculator = DeploymentExpensesCalculator::new(DeploymentExpenses {
+        publisher_fee: Some(PublisherFee {
+            amount: PublisherFeeAmount::Flat { amount: 30 },
+            payout_account: payout_account.clone(),
+        }),
+        ..deployment_expenses.clone()
+    });
+    assert_eq!(calculator.get_publisher_fee_amount(105).unwrap(), 30);
+
+    // the share is rounded down
+    let calculator = DeploymentExpensesCalculator::new(DeploymentExpenses {
+        publisher_fee: Some(PublisherFee {
+            amount: PublisherFeeAmount::Share { permyriad: 2_500 },
+            payout_account,
+        }),
+        ..deployment_expenses
+    });
+    assert_eq!(calculator.get_publisher_fee_amount(105).unwrap(), 26);
+    assert_eq!(calculator.get_publisher_fee_amount(0).unwrap(), 0);
+}
+
+#[test]
+fn test_deployment_expenses_calculator_voucher_discount() {
+    let deployment_expenses = DeploymentExpenses {
+        contract_initial_cycles: 1_000_000,
+        deployment_cycles_cost: 50_000,
+        icp_conversation_rate: IcpConversationRate::Fixed {
+            xdr_permyriad_per_icp: 10_000,
+        },
+        amount_buffer_permyriad: 0,
+        amount_decimal_places: 0,
+        publisher_fee: None,
+        voucher_discount: None,
+    };
+
+    let calculator = DeploymentExpensesCalculator::new(deployment_expenses.clone());
+    assert_eq!(calculator.get_voucher_discount_amount(105).unwrap(), 0);
+    assert_eq!(calculator.get_subsidized_cycles(105, 0).unwrap(), 0);
+
+    // the percentage is rounded down
+    let calculator = DeploymentExpensesCalculator::new(DeploymentExpenses {
+        voucher_discount: Some(VoucherDiscount::Percentage { permyriad: 5_000 }),
+        ..deployment_expenses.clone()
+    });
+    assert_eq!(calculator.get_voucher_discount_amount(105).unwrap(), 52);
+    assert_eq!(calculator.get_subsidized_cycles(105, 52).unwrap(), 520_000);
+
+    // the fixed amount can not exceed the expenses amount
+    let calculator = DeploymentExpensesCalculator::new(DeploymentExpenses {
+        voucher_discount: Some(VoucherDiscount::Fixed { amount: 200 }),
+        ..deployment_expenses.clone()
+    });
+    assert_eq!(calculator.get_voucher_discount_amount(105).unwrap(), 105);
+    assert_eq!(
+        calculator.get_subsidized_cycles(105, 105).unwrap(),
+        1_050_000
+    );
+
+    let calculator = DeploymentExpensesCalculator::new(DeploymentExpenses {
+        voucher_discount: Some(VoucherDiscount::Free),
+        ..deployment_expenses
+    });
+    assert_eq!(calculator.get_voucher_discount_amount(105).unwrap(), 105);
+    assert_eq!(calculator.get_subsidized_cycles(0, 0).unwrap(), 1_050_000);
+}
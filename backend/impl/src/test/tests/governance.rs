@@ -0,0 +1,220 @@
+use candid::Principal;
+use hub_canister_api::{
+    approve_proposal::{ApproveProposalError, ApproveProposalResult},
+    block_contract_template::BlockContractTemplateError,
+    get_proposal::GetProposalError,
+    propose_operation::ProposeOperationError,
+    set_access_rights::SetAccessRightsError,
+    set_config::SetConfigError,
+    set_roles::SetRolesError,
+    types::{
+        AccessRight, Config, GovernancePolicy, GovernedOperation, GovernedOperationType,
+        HubEventType, Permission, ProposalStatus,
+    },
+};
+
+use crate::{
+    ht_last_hub_event_matches, ht_result_err_matches, ht_result_ok_matches,
+    queries::{get_contract_template::get_contract_template_int, get_proposal::get_proposal_int},
+    test::tests::{
+        components::{ic::ht_set_test_caller, time::ht_set_test_time},
+        drivers::contract::ht_add_contract,
+        ht_get_config, ht_get_test_admin,
+        support::fixtures::{ht_get_face_contract_def, TEST_WASM},
+    },
+    updates::{
+        approve_proposal::approve_proposal_int,
+        block_contract_template::block_contract_template_int,
+        propose_operation::propose_operation_int, set_access_rights::set_access_rights_int,
+        set_config::set_config_int, set_roles::set_roles_int,
+    },
+};
+
+#[tokio::test]
+async fn test_governed_operations() {
+    let admin = ht_get_test_admin();
+    let first_approver = Principal::from_slice(&[21; 29]);
+    let second_approver = Principal::from_slice(&[22; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    ht_set_test_caller(admin);
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetConfig,
+            Permission::BlockContractTemplate,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // OPERATION NOT GOVERNED
+    let result = propose_operation_int(GovernedOperation::BlockContractTemplate {
+        contract_template_id,
+        reason: "malware".to_string(),
+    });
+    ht_result_err_matches!(result, ProposeOperationError::OperationNotGoverned);
+
+    // WRONG POLICIES
+    let config_policy = GovernancePolicy {
+        operation_type: GovernedOperationType::SetConfig,
+        approvers: vec![first_approver, second_approver],
+        required_approvals: 2,
+        approval_window: 1_000,
+    };
+    let result = set_config_int(Config {
+        governance_policies: vec![GovernancePolicy {
+            required_approvals: 3,
+            ..config_policy.clone()
+        }],
+        ..ht_get_config()
+    });
+    ht_result_err_matches!(result, SetConfigError::WrongConfig { .. });
+
+    let result = set_config_int(Config {
+        governance_policies: vec![GovernancePolicy {
+            approvers: vec![first_approver, first_approver],
+            ..config_policy.clone()
+        }],
+        ..ht_get_config()
+    });
+    ht_result_err_matches!(result, SetConfigError::WrongConfig { .. });
+
+    // GOVERN OPERATIONS
+    let result = set_config_int(Config {
+        governance_policies: vec![
+            config_policy.clone(),
+            GovernancePolicy {
+                operation_type: GovernedOperationType::BlockContractTemplate,
+                approvers: vec![first_approver],
+                required_approvals: 1,
+                approval_window: 1_000,
+            },
+            GovernancePolicy {
+                operation_type: GovernedOperationType::SetAccessRights,
+                ..config_policy.clone()
+            },
+        ],
+        ..ht_get_config()
+    });
+    assert!(result.is_ok());
+
+    let result = set_config_int(ht_get_config());
+    ht_result_err_matches!(result, SetConfigError::ProposalRequired);
+
+    let result = block_contract_template_int(contract_template_id, "malware".to_string());
+    ht_result_err_matches!(result, BlockContractTemplateError::ProposalRequired);
+
+    let result = set_access_rights_int(vec![]);
+    ht_result_err_matches!(result, SetAccessRightsError::ProposalRequired);
+
+    let result = set_roles_int(vec![]);
+    ht_result_err_matches!(result, SetRolesError::ProposalRequired);
+
+    // PROPOSE
+    let new_config = Config {
+        name_max_length: 7,
+        ..ht_get_config()
+    };
+
+    ht_set_test_caller(first_approver);
+    let result = propose_operation_int(GovernedOperation::SetConfig {
+        config: Box::new(new_config.clone()),
+    });
+    ht_result_err_matches!(result, ProposeOperationError::PermissionDenied);
+
+    ht_set_test_caller(admin);
+    let result = propose_operation_int(GovernedOperation::SetConfig {
+        config: Box::new(Config {
+            contract_url_pattern: "http://localhost".to_string(),
+            ..new_config.clone()
+        }),
+    });
+    ht_result_err_matches!(result, ProposeOperationError::InvalidOperation { .. });
+
+    ht_set_test_time(1_000);
+    let result = propose_operation_int(GovernedOperation::SetConfig {
+        config: Box::new(new_config.clone()),
+    });
+    let proposal_id = result.unwrap().proposal_id;
+    ht_last_hub_event_matches!(HubEventType::ProposalCreated { proposal_id: event_proposal_id, operation: GovernedOperation::SetConfig { .. } }
+        if event_proposal_id == &proposal_id);
+
+    // APPROVE
+    let result = approve_proposal_int(proposal_id);
+    ht_result_err_matches!(result, ApproveProposalError::PermissionDenied);
+
+    ht_set_test_caller(first_approver);
+    let result = approve_proposal_int(proposal_id);
+    ht_result_ok_matches!(
+        result,
+        ApproveProposalResult {
+            status: ProposalStatus::Pending
+        }
+    );
+    ht_last_hub_event_matches!(HubEventType::ProposalApproved { proposal_id: event_proposal_id }
+        if event_proposal_id == &proposal_id);
+
+    let result = approve_proposal_int(proposal_id);
+    ht_result_err_matches!(result, ApproveProposalError::AlreadyApproved);
+    assert_ne!(ht_get_config().name_max_length, 7);
+
+    // PROPOSAL EXPIRED
+    ht_set_test_time(2_000);
+    ht_set_test_caller(second_approver);
+    let result = approve_proposal_int(proposal_id);
+    ht_result_err_matches!(result, ApproveProposalError::ProposalExpired);
+
+    let proposal = get_proposal_int(proposal_id).unwrap().proposal;
+    assert_eq!(proposal.status, ProposalStatus::Expired);
+    assert_eq!(proposal.approvals, vec![first_approver]);
+
+    // EXECUTE
+    ht_set_test_caller(admin);
+    let proposal_id = propose_operation_int(GovernedOperation::SetConfig {
+        config: Box::new(new_config.clone()),
+    })
+    .unwrap()
+    .proposal_id;
+
+    for approver in [second_approver, first_approver] {
+        ht_set_test_caller(approver);
+        assert!(approve_proposal_int(proposal_id).is_ok());
+    }
+    assert_eq!(ht_get_config(), new_config);
+    assert_eq!(
+        get_proposal_int(proposal_id).unwrap().proposal.status,
+        ProposalStatus::Executed
+    );
+    ht_last_hub_event_matches!(HubEventType::ProposalExecuted { proposal_id: event_proposal_id, error: None }
+        if event_proposal_id == &proposal_id);
+
+    let result = approve_proposal_int(proposal_id);
+    ht_result_err_matches!(result, ApproveProposalError::ProposalNotPending);
+
+    // BLOCK CONTRACT TEMPLATE
+    for _ in 0..2 {
+        ht_set_test_caller(admin);
+        let proposal_id = propose_operation_int(GovernedOperation::BlockContractTemplate {
+            contract_template_id,
+            reason: "malware".to_string(),
+        })
+        .unwrap()
+        .proposal_id;
+
+        ht_set_test_caller(first_approver);
+        assert!(approve_proposal_int(proposal_id).is_ok());
+    }
+
+    let contract = get_contract_template_int(contract_template_id).unwrap();
+    assert!(contract.contract_template.blocked.is_some());
+
+    // the second block fails, the template is already blocked
+    ht_last_hub_event_matches!(HubEventType::ProposalExecuted { error: Some(_), .. });
+
+    let result = get_proposal_int(proposal_id + 100);
+    ht_result_err_matches!(result, GetProposalError::ProposalNotFound);
+}
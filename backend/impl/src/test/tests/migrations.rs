@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use common_canister_impl::stable_structures::CBor;
+use common_canister_types::Timestamped;
+use hub_canister_api::types::{
+    Config, HubEventType, MigrationDirective, Permission, SchemaVersion,
+};
+use ic_stable_structures::{
+    memory_manager::{MemoryId, MemoryManager},
+    DefaultMemoryImpl, RestrictedMemory, StableCell, MAX_PAGES,
+};
+
+use crate::{
+    lifecycle::migrations::{migrate_data_model, LATEST_SCHEMA_VERSION},
+    model::{
+        access_rights::{AccessRights, AccessRightsStorage},
+        blocked_contracts::BlockedContractsStorage,
+        hub_events::HubEventsStorage,
+        templates::{ContractTemplateWasmMetadata, ContractTemplatesStorage},
+    },
+    mutate_state, read_state,
+    test::tests::{
+        drivers::contract::ht_add_contract,
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{ht_get_face_contract_def, TEST_WASM},
+    },
+};
+
+fn migrate(
+    directive: Option<MigrationDirective>,
+) -> Result<(SchemaVersion, SchemaVersion), String> {
+    mutate_state(|state| migrate_data_model(state.get_model_mut(), directive))
+}
+
+fn get_schema_version() -> SchemaVersion {
+    read_state(|state| state.get_model().get_schema_storage().get_version())
+}
+
+#[tokio::test]
+async fn test_migrate_data_model() {
+    let admin = ht_get_test_admin();
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    // the state stored before the versioning
+    assert_eq!(get_schema_version(), 0);
+
+    // UNKNOWN VERSION
+    let result = migrate(Some(MigrationDirective::MigrateTo {
+        version: LATEST_SCHEMA_VERSION + 1,
+    }));
+    assert!(result.is_err());
+    assert_eq!(get_schema_version(), 0);
+
+    // STEP BY STEP
+    let result = migrate(Some(MigrationDirective::MigrateTo { version: 1 }));
+    assert_eq!(result, Ok((0, 1)));
+    assert_eq!(get_schema_version(), 1);
+
+    let result = migrate(None);
+    assert_eq!(result, Ok((1, LATEST_SCHEMA_VERSION)));
+    assert_eq!(get_schema_version(), LATEST_SCHEMA_VERSION);
+
+    // the migrations keep the data
+    read_state(|state| {
+        let model = state.get_model();
+        assert!(model.get_access_rights_storage().is_access_right(
+            &admin,
+            &Permission::SetConfig,
+            0
+        ));
+        assert!(model
+            .get_contract_templates_storage()
+            .get_search_index(&contract_template_id)
+            .is_some());
+    });
+
+    // ALREADY MIGRATED
+    let result = migrate(None);
+    assert_eq!(result, Ok((LATEST_SCHEMA_VERSION, LATEST_SCHEMA_VERSION)));
+
+    // DOWNGRADE
+    let result = migrate(Some(MigrationDirective::MigrateTo { version: 0 }));
+    assert!(result.is_err());
+    assert_eq!(get_schema_version(), LATEST_SCHEMA_VERSION);
+
+    // SET VERSION
+    let result = migrate(Some(MigrationDirective::SetVersion {
+        version: LATEST_SCHEMA_VERSION + 1,
+    }));
+    assert!(result.is_err());
+
+    let result = migrate(Some(MigrationDirective::SetVersion { version: 0 }));
+    assert_eq!(result, Ok((LATEST_SCHEMA_VERSION, 0)));
+    assert_eq!(get_schema_version(), 0);
+
+    // the version can not be set forward over the migrations
+    let result = migrate(Some(MigrationDirective::SetVersion { version: 1 }));
+    assert!(result.is_err());
+    assert_eq!(get_schema_version(), 0);
+
+    // the migrations are repeatable
+    let result = migrate(None);
+    assert_eq!(result, Ok((0, LATEST_SCHEMA_VERSION)));
+}
+
+#[test]
+fn test_migrate_search_index() {
+    let memory_manager = MemoryManager::init(RestrictedMemory::new(
+        DefaultMemoryImpl::default(),
+        0..MAX_PAGES,
+    ));
+    let init_storage = |search_index_memory_id| {
+        ContractTemplatesStorage::init(
+            memory_manager.get(MemoryId::new(0)),
+            memory_manager.get(MemoryId::new(1)),
+            memory_manager.get(MemoryId::new(2)),
+            memory_manager.get(MemoryId::new(search_index_memory_id)),
+        )
+    };
+
+    let contract_template_id = init_storage(3).add_contract_template(
+        ht_get_test_admin(),
+        0,
+        ht_get_face_contract_def(),
+        TEST_WASM.to_vec(),
+        ContractTemplateWasmMetadata {
+            compressed: false,
+            exported_methods: vec![],
+            custom_sections: vec![],
+        },
+    );
+
+    // the contract template registered before the search index was introduced
+    let mut storage = init_storage(4);
+    assert!(storage.get_search_index(&contract_template_id).is_none());
+
+    storage.build_missing_search_index();
+    assert!(storage.get_search_index(&contract_template_id).is_some());
+}
+
+#[test]
+fn test_migrate_block_index() {
+    let memory_manager = MemoryManager::init(RestrictedMemory::new(
+        DefaultMemoryImpl::default(),
+        0..MAX_PAGES,
+    ));
+    let init_storage = |block_index_memory_id| {
+        BlockedContractsStorage::init(
+            memory_manager.get(MemoryId::new(0)),
+            memory_manager.get(MemoryId::new(1)),
+            memory_manager.get(MemoryId::new(block_index_memory_id)),
+            memory_manager.get(MemoryId::new(block_index_memory_id + 1)),
+        )
+    };
+
+    init_storage(2).add_contract_block_batch(1_000, "abuse".to_string(), vec![7]);
+
+    // the contract blocks registered before the block index was introduced
+    let mut storage = init_storage(4);
+    assert!(storage.find_deployment_block(&7).is_none());
+
+    storage.build_missing_block_index();
+    assert_eq!(
+        storage.find_deployment_block(&7),
+        Some(Timestamped::new(1_000, "abuse".to_string()))
+    );
+}
+
+#[test]
+fn test_migrate_config_event_index() {
+    let memory_manager = MemoryManager::init(RestrictedMemory::new(
+        DefaultMemoryImpl::default(),
+        0..MAX_PAGES,
+    ));
+    let init_storage = |config_event_index_memory_id| {
+        HubEventsStorage::init(
+            memory_manager.get(MemoryId::new(0)),
+            memory_manager.get(MemoryId::new(1)),
+            memory_manager.get(MemoryId::new(config_event_index_memory_id)),
+        )
+    };
+
+    let mut storage = init_storage(2);
+    storage.add_hub_event(
+        1_000,
+        ht_get_test_admin(),
+        HubEventType::ConfigSet {
+            config: Box::new(Config::default()),
+        },
+    );
+    assert_eq!(storage.get_config_event_ids(0), vec![0]);
+
+    // the config events registered before the config event index was introduced
+    let mut storage = init_storage(3);
+    assert!(storage.get_config_event_ids(0).is_empty());
+
+    storage.build_missing_config_event_index();
+    assert_eq!(storage.get_config_event_ids(0), vec![0]);
+}
+
+#[test]
+fn test_migrate_access_control() {
+    let memory = DefaultMemoryImpl::default();
+    let legacy: AccessRights = BTreeMap::from([
+        (ht_get_test_admin(), (None, Some("admin".to_string()))),
+        (
+            ht_get_test_user(),
+            (Some(vec![Permission::SetConfig]), None),
+        ),
+    ]);
+
+    // the access rights stored before the roles were introduced
+    StableCell::init(
+        RestrictedMemory::new(memory.clone(), 0..1),
+        CBor(legacy.clone()),
+    );
+
+    let mut storage = AccessRightsStorage::init(RestrictedMemory::new(memory.clone(), 0..1));
+    storage.migrate_access_control();
+
+    let storage = AccessRightsStorage::init(RestrictedMemory::new(memory, 0..1));
+    let access_control = storage.get_access_control();
+    assert_eq!(access_control.access_rights, legacy);
+    assert!(access_control.roles.is_empty());
+    assert!(storage.is_access_right(&ht_get_test_user(), &Permission::SetConfig, 0));
+}
@@ -0,0 +1,250 @@
+use candid::Principal;
+use common_canister_types::ChunkDef;
+use hub_canister_api::{
+    accept_deployment_ownership_transfer::AcceptDeploymentOwnershipTransferError,
+    get_deployments::{DeploymentsSelector, GetDeploymentsArgs},
+    obtain_contract_certificate::ObtainContractCertificateError,
+    propose_deployment_ownership_transfer::ProposeDeploymentOwnershipTransferError,
+    resign_contract_certificate::ResignContractCertificateError,
+    types::{
+        Config, CreateContractCanisterStrategy, CyclesConvertingStrategy, DeployerBan,
+        DeployerBanSettings,
+    },
+};
+
+use crate::{
+    ht_result_err_matches, mutate_state,
+    queries::{
+        get_deployments::get_deployments_int,
+        obtain_contract_certificate::obtain_contract_certificate_int,
+    },
+    read_state,
+    test::tests::{
+        components::ic::ht_set_test_caller,
+        drivers::{
+            contract::ht_add_contract,
+            deployment::{ht_drive_to_deploying, ht_drive_to_finalized, DeploymentConfig},
+        },
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{ht_get_face_contract_def, TEST_CONTRACT_INITIAL_CYCLES, TEST_WASM},
+    },
+    updates::{
+        accept_deployment_ownership_transfer::accept_deployment_ownership_transfer_int,
+        propose_deployment_ownership_transfer::propose_deployment_ownership_transfer_int,
+        resign_contract_certificate::resign_contract_certificate_int, set_config::set_config_int,
+    },
+};
+
+#[tokio::test]
+async fn test_transfer_deployment_ownership() {
+    let admin = ht_get_test_admin();
+    let owner = ht_get_test_user();
+    let new_owner = Principal::from_slice(&[10; 29]);
+    let stranger = Principal::from_slice(&[11; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        owner,
+        contract_template_id,
+        &deployment_cfg,
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+
+    // the deployment in progress can not be transferred
+    let result = propose_deployment_ownership_transfer_int(deployment_id, Some(new_owner));
+    ht_result_err_matches!(
+        result,
+        ProposeDeploymentOwnershipTransferError::DeploymentWrongState
+    );
+
+    ht_drive_to_finalized(owner, &deployment_id).await;
+    let contract_canister = read_state(|state| {
+        state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .unwrap()
+            .contract_canister
+            .unwrap()
+    });
+
+    // the certificate is not reissued yet
+    ht_set_test_caller(owner);
+    let result = resign_contract_certificate_int(deployment_id);
+    ht_result_err_matches!(result, ResignContractCertificateError::DeploymentWrongState);
+
+    // PROPOSE
+    ht_set_test_caller(stranger);
+    let result = propose_deployment_ownership_transfer_int(deployment_id, Some(new_owner));
+    ht_result_err_matches!(
+        result,
+        ProposeDeploymentOwnershipTransferError::PermissionDenied
+    );
+
+    ht_set_test_caller(owner);
+    let result = propose_deployment_ownership_transfer_int(deployment_id + 1, Some(new_owner));
+    ht_result_err_matches!(
+        result,
+        ProposeDeploymentOwnershipTransferError::DeploymentNotFound
+    );
+
+    let result = propose_deployment_ownership_transfer_int(deployment_id, Some(owner));
+    ht_result_err_matches!(
+        result,
+        ProposeDeploymentOwnershipTransferError::InvalidNewOwner
+    );
+
+    let result =
+        propose_deployment_ownership_transfer_int(deployment_id, Some(Principal::anonymous()));
+    ht_result_err_matches!(
+        result,
+        ProposeDeploymentOwnershipTransferError::InvalidNewOwner
+    );
+
+    let deployment = propose_deployment_ownership_transfer_int(deployment_id, Some(new_owner))
+        .unwrap()
+        .deployment;
+    assert_eq!(deployment.pending_owner, Some(new_owner));
+    assert!(deployment.ownership_history.is_empty());
+
+    // ACCEPT
+    ht_set_test_caller(stranger);
+    let result = accept_deployment_ownership_transfer_int(deployment_id);
+    ht_result_err_matches!(
+        result,
+        AcceptDeploymentOwnershipTransferError::PermissionDenied
+    );
+
+    // the banned principal can not accept the deployment
+    mutate_state(|state| {
+        state
+            .get_model_mut()
+            .get_deployer_bans_storage_mut()
+            .set_deployer_ban(
+                new_owner,
+                DeployerBan {
+                    banned: 0,
+                    banned_by: admin,
+                    settings: DeployerBanSettings {
+                        reason: "abuse".to_string(),
+                        expiration: None,
+                    },
+                },
+            )
+    });
+    ht_set_test_caller(new_owner);
+    let result = accept_deployment_ownership_transfer_int(deployment_id);
+    ht_result_err_matches!(
+        result,
+        AcceptDeploymentOwnershipTransferError::DeployerBanned { .. }
+    );
+    mutate_state(|state| {
+        state
+            .get_model_mut()
+            .get_deployer_bans_storage_mut()
+            .remove_deployer_ban(&new_owner)
+    });
+
+    let deployment = accept_deployment_ownership_transfer_int(deployment_id)
+        .unwrap()
+        .deployment;
+    assert_eq!(deployment.deployer, owner);
+    assert_eq!(deployment.pending_owner, None);
+    assert_eq!(deployment.ownership_history.len(), 1);
+    assert_eq!(deployment.ownership_history[0].previous_owner, owner);
+    assert_eq!(deployment.ownership_history[0].new_owner, new_owner);
+
+    let result = accept_deployment_ownership_transfer_int(deployment_id);
+    ht_result_err_matches!(
+        result,
+        AcceptDeploymentOwnershipTransferError::PermissionDenied
+    );
+
+    // the deployment is reindexed by the new owner
+    ht_set_test_caller(admin);
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        max_deployments_per_chunk: 10,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    let get_deployments_count = |deployer: Principal| {
+        get_deployments_int(GetDeploymentsArgs {
+            chunk_def: ChunkDef {
+                start: 0,
+                count: 10,
+            },
+            selector: DeploymentsSelector::ByDeployer {
+                deployer,
+                contract_template_id: Some(contract_template_id),
+            },
+            sorting: None,
+        })
+        .unwrap()
+        .total_count
+    };
+    assert_eq!(get_deployments_count(owner), 0);
+    assert_eq!(get_deployments_count(new_owner), 1);
+
+    // the reissued certificate names the new owner
+    ht_set_test_caller(owner);
+    let result = obtain_contract_certificate_int(deployment_id);
+    ht_result_err_matches!(result, ObtainContractCertificateError::PermissionDenied);
+
+    ht_set_test_caller(new_owner);
+    let certificate = obtain_contract_certificate_int(deployment_id)
+        .unwrap()
+        .certificate;
+    assert_eq!(certificate.contract_certificate.deployer, new_owner);
+
+    ht_set_test_caller(contract_canister);
+    let certificate = obtain_contract_certificate_int(deployment_id)
+        .unwrap()
+        .certificate;
+    assert_eq!(certificate.contract_certificate.deployer, new_owner);
+
+    // the reissued certificate is signed again, e.g. after the hub upgrade
+    assert!(resign_contract_certificate_int(deployment_id).is_ok());
+
+    ht_set_test_caller(owner);
+    let result = resign_contract_certificate_int(deployment_id);
+    ht_result_err_matches!(result, ResignContractCertificateError::PermissionDenied);
+
+    let result = resign_contract_certificate_int(deployment_id + 1);
+    ht_result_err_matches!(result, ResignContractCertificateError::DeploymentNotFound);
+
+    // the previous owner lost the ownership
+    ht_set_test_caller(owner);
+    let result = propose_deployment_ownership_transfer_int(deployment_id, Some(stranger));
+    ht_result_err_matches!(
+        result,
+        ProposeDeploymentOwnershipTransferError::PermissionDenied
+    );
+
+    // WITHDRAW PROPOSAL
+    ht_set_test_caller(new_owner);
+    assert!(propose_deployment_ownership_transfer_int(deployment_id, Some(owner)).is_ok());
+    let deployment = propose_deployment_ownership_transfer_int(deployment_id, None)
+        .unwrap()
+        .deployment;
+    assert_eq!(deployment.pending_owner, None);
+
+    ht_set_test_caller(owner);
+    let result = accept_deployment_ownership_transfer_int(deployment_id);
+    ht_result_err_matches!(
+        result,
+        AcceptDeploymentOwnershipTransferError::PermissionDenied
+    );
+}
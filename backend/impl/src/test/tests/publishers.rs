@@ -0,0 +1,345 @@
+use candid::Principal;
+use common_contract_api::get_wasm_hash;
+use hub_canister_api::{
+    add_contract_template::AddContractTemplateError,
+    block_contract_template::BlockContractTemplateError,
+    get_publisher::GetPublisherError,
+    set_contract_template_retired::SetContractTemplateRetiredError,
+    set_publisher::SetPublisherError,
+    set_upload_wasm_grant::SetUploadWasmGrantError,
+    types::{
+        AccessRight, Config, ContractTemplateDefinition, ContractTemplateMetadata,
+        ContractTemplateVisibility, HubEventType, Permission, PublisherSettings, UploadWasmGrant,
+    },
+};
+
+use crate::{
+    ht_last_hub_event_matches, ht_result_err_matches,
+    model::templates::ContractTemplateWasmMetadata,
+    mutate_state,
+    queries::{get_contract_template::get_contract_template_int, get_publisher::get_publisher_int},
+    read_state,
+    test::tests::{
+        components::{ic::ht_set_test_caller, time::ht_set_test_time},
+        drivers::contract::ht_add_contract,
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{ht_build_contract_wasm, ht_get_face_contract_def, TEST_WASM},
+    },
+    updates::{
+        add_contract_template::add_contract_template_int,
+        block_contract_template::block_contract_template_int,
+        set_access_rights::set_access_rights_int, set_config::set_config_int,
+        set_contract_template_metadata::set_contract_template_metadata_int,
+        set_contract_template_retired::set_contract_template_retired_int,
+        set_contract_template_visibility::set_contract_template_visibility_int,
+        set_publisher::set_publisher_int, set_upload_wasm_grant::set_upload_wasm_grant_int,
+        upload_wasm_chunk::upload_wasm_chunk_int,
+    },
+};
+
+#[test]
+fn test_publisher_self_service() {
+    let admin = ht_get_test_admin();
+    let publisher = Principal::from_slice(&[7; 29]);
+    let admin_contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        contract_wasm_max_size: 10_000,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    let publisher_wasm = build_publisher_wasm(2);
+    let settings = PublisherSettings {
+        max_contract_templates: 1,
+        max_total_wasm_length: publisher_wasm.len(),
+    };
+
+    // CHECK NOT A PUBLISHER
+    ht_set_test_caller(publisher);
+    let result = set_upload_wasm_grant_int(Some(UploadWasmGrant {
+        operator: publisher,
+        wasm_length: publisher_wasm.len(),
+    }));
+    ht_result_err_matches!(result, SetUploadWasmGrantError::PermissionDenied);
+
+    // CHECK PERMISSION DENIED
+    ht_set_test_caller(admin);
+    let result = set_publisher_int(publisher, Some(settings.clone()));
+    ht_result_err_matches!(result, SetPublisherError::PermissionDenied);
+
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::AddContractTemplate,
+            Permission::SetPublisher,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // SET PUBLISHER
+    let result = set_publisher_int(publisher, Some(settings.clone()));
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::PublisherSet { publisher: event_publisher, settings: Some(_) }
+        if event_publisher == &publisher);
+
+    let information = get_publisher_int(publisher).unwrap().publisher;
+    assert_eq!(information.settings, settings);
+    assert_eq!(information.contract_templates_count, 0);
+    assert!(information.contract_templates.is_empty());
+
+    // CHECK GRANT OF ANOTHER CALLER
+    let result = set_upload_wasm_grant_int(Some(UploadWasmGrant {
+        operator: ht_get_test_user(),
+        wasm_length: TEST_WASM.len(),
+    }));
+    assert!(result.is_ok());
+
+    ht_set_test_caller(publisher);
+    let result = set_upload_wasm_grant_int(Some(UploadWasmGrant {
+        operator: publisher,
+        wasm_length: publisher_wasm.len(),
+    }));
+    ht_result_err_matches!(result, SetUploadWasmGrantError::UploadWasmGrantIsBusy);
+
+    ht_set_test_caller(admin);
+    assert!(set_upload_wasm_grant_int(None).is_ok());
+
+    // CHECK WASM QUOTA
+    ht_set_test_caller(publisher);
+    let result = set_upload_wasm_grant_int(Some(UploadWasmGrant {
+        operator: publisher,
+        wasm_length: publisher_wasm.len() + 1,
+    }));
+    ht_result_err_matches!(
+        result,
+        SetUploadWasmGrantError::WasmQuotaExceeded { available_length } if available_length == publisher_wasm.len()
+    );
+
+    // ADD CONTRACT TEMPLATE BY PUBLISHER
+    let contract_def = ContractTemplateDefinition {
+        name: "publisher contract".to_string(),
+        wasm_hash: get_wasm_hash(&publisher_wasm),
+        ..ht_get_face_contract_def()
+    };
+    upload_publisher_wasm(publisher, &publisher_wasm);
+    let contract_template_id = add_contract_template_int(contract_def.clone())
+        .unwrap()
+        .contract_template_id;
+
+    let contract = get_contract_template_int(contract_template_id).unwrap();
+    assert_eq!(contract.contract_template.registrar, publisher);
+
+    let information = get_publisher_int(publisher).unwrap().publisher;
+    assert_eq!(information.contract_templates_count, 1);
+    assert_eq!(information.total_wasm_length, publisher_wasm.len());
+    assert_eq!(information.contract_templates.len(), 1);
+    assert_eq!(
+        information.contract_templates[0].contract_template_id,
+        contract_template_id
+    );
+
+    // CHECK CONTRACT TEMPLATES QUOTA
+    ht_set_test_caller(admin);
+    let result = set_publisher_int(
+        publisher,
+        Some(PublisherSettings {
+            max_contract_templates: 1,
+            max_total_wasm_length: 10_000,
+        }),
+    );
+    assert!(result.is_ok());
+
+    let next_wasm = build_publisher_wasm(3);
+    upload_publisher_wasm(publisher, &next_wasm);
+    let result = add_contract_template_int(ContractTemplateDefinition {
+        name: "next publisher contract".to_string(),
+        wasm_hash: get_wasm_hash(&next_wasm),
+        ..ht_get_face_contract_def()
+    });
+    ht_result_err_matches!(
+        result,
+        AddContractTemplateError::ContractTemplatesQuotaExceeded { max_count: 1 }
+    );
+
+    // MANAGE OWN CONTRACT TEMPLATE ONLY
+    let result = set_contract_template_retired_int(
+        admin_contract_template_id,
+        Some("not mine".to_string()),
+        None,
+        None,
+    );
+    ht_result_err_matches!(result, SetContractTemplateRetiredError::PermissionDenied);
+
+    // the scheduled retirement is pending until its time
+    ht_set_test_time(1_000);
+    let result = set_contract_template_retired_int(
+        contract_template_id,
+        Some("outdated".to_string()),
+        Some(2_000),
+        None,
+    );
+    assert!(result.is_ok());
+    let information = get_publisher_int(publisher).unwrap().publisher;
+    assert!(!information.contract_templates[0].retired);
+    assert_eq!(
+        information.contract_templates[0].pending_retirement,
+        Some(2_000)
+    );
+
+    ht_set_test_time(2_000);
+    let information = get_publisher_int(publisher).unwrap().publisher;
+    assert!(information.contract_templates[0].retired);
+    assert_eq!(information.contract_templates[0].pending_retirement, None);
+
+    let result = set_contract_template_retired_int(
+        contract_template_id,
+        Some("outdated".to_string()),
+        None,
+        None,
+    );
+    assert!(result.is_ok());
+
+    let result = set_contract_template_metadata_int(
+        contract_template_id,
+        ContractTemplateMetadata {
+            short_description: "publisher short description".to_string(),
+            ..contract_def.get_metadata()
+        },
+    );
+    assert!(result.is_ok());
+
+    // BLOCKING REMAINS ADMIN ONLY
+    let result = block_contract_template_int(contract_template_id, "policy".to_string());
+    ht_result_err_matches!(result, BlockContractTemplateError::PermissionDenied);
+
+    // REMOVE PUBLISHER
+    ht_set_test_caller(admin);
+    let result = set_publisher_int(publisher, None);
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::PublisherSet { publisher: event_publisher, settings: None }
+        if event_publisher == &publisher);
+
+    let result = set_publisher_int(publisher, None);
+    ht_result_err_matches!(result, SetPublisherError::PublisherNotFound);
+
+    ht_result_err_matches!(
+        get_publisher_int(publisher),
+        GetPublisherError::PublisherNotFound
+    );
+
+    ht_set_test_caller(publisher);
+    let result = set_contract_template_retired_int(contract_template_id, None, None, None);
+    ht_result_err_matches!(result, SetContractTemplateRetiredError::PermissionDenied);
+}
+
+#[test]
+fn test_get_publisher_restricted_contract_templates() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let stranger = Principal::from_slice(&[5; 29]);
+
+    let public_contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+    let restricted_contract_template_id = mutate_state(|state| {
+        state
+            .get_model_mut()
+            .get_contract_templates_storage_mut()
+            .add_contract_template(
+                admin,
+                0,
+                ContractTemplateDefinition {
+                    name: "restricted".to_string(),
+                    wasm_hash: "restricted_wasm_hash".to_string(),
+                    ..ht_get_face_contract_def()
+                },
+                vec![],
+                ContractTemplateWasmMetadata {
+                    compressed: false,
+                    exported_methods: vec![],
+                    custom_sections: vec![],
+                },
+            )
+    });
+
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetPublisher,
+            Permission::SetContractTemplateMetadata,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    let result = set_publisher_int(
+        admin,
+        Some(PublisherSettings {
+            max_contract_templates: 10,
+            max_total_wasm_length: 10_000,
+        }),
+    );
+    assert!(result.is_ok());
+
+    let result = set_contract_template_visibility_int(
+        restricted_contract_template_id,
+        ContractTemplateVisibility::Restricted {
+            allowed_deployers: vec![deployer],
+        },
+    );
+    assert!(result.is_ok());
+
+    let get_contract_template_ids = || {
+        get_publisher_int(admin)
+            .unwrap()
+            .publisher
+            .contract_templates
+            .iter()
+            .map(|contract_template| contract_template.contract_template_id)
+            .collect::<Vec<_>>()
+    };
+
+    // the admin and the allowed deployer see the restricted contract template
+    assert_eq!(
+        get_contract_template_ids(),
+        vec![public_contract_template_id, restricted_contract_template_id]
+    );
+
+    ht_set_test_caller(deployer);
+    assert_eq!(
+        get_contract_template_ids(),
+        vec![public_contract_template_id, restricted_contract_template_id]
+    );
+
+    // the restricted contract template is hidden from the others
+    ht_set_test_caller(stranger);
+    assert_eq!(
+        get_contract_template_ids(),
+        vec![public_contract_template_id]
+    );
+}
+
+fn build_publisher_wasm(memory_pages: u32) -> Vec<u8> {
+    ht_build_contract_wasm(
+        &["canister_init", "canister_query get_contract_certificate"],
+        memory_pages,
+        &[],
+    )
+}
+
+fn upload_publisher_wasm(publisher: Principal, wasm: &[u8]) {
+    ht_set_test_caller(publisher);
+    let result = set_upload_wasm_grant_int(Some(UploadWasmGrant {
+        operator: publisher,
+        wasm_length: wasm.len(),
+    }));
+    assert!(result.is_ok());
+
+    let result = upload_wasm_chunk_int(true, wasm.to_vec());
+    assert!(result.is_ok());
+}
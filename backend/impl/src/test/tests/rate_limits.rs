@@ -0,0 +1,228 @@
+use candid::Principal;
+use common_canister_types::LedgerAccount;
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    get_deployment_rate_limits_usage::{
+        DeploymentRateLimitUsage, GetDeploymentRateLimitsUsageError,
+    },
+    set_config::SetConfigError,
+    types::{Config, DeploymentRateLimit, DeploymentRateLimits},
+};
+use ic_stable_structures::{
+    memory_manager::{MemoryId, MemoryManager},
+    DefaultMemoryImpl, RestrictedMemory, MAX_PAGES,
+};
+
+use crate::{
+    ht_result_err_matches,
+    model::rate_limits::DeploymentRateLimitsStorage,
+    queries::get_deployment_rate_limits_usage::get_deployment_rate_limits_usage_int,
+    read_state,
+    test::tests::{
+        components::{ic::ht_set_test_caller, time::ht_set_test_time},
+        drivers::{
+            contract::ht_add_contract,
+            deployment::{ht_drive_to_deploying, ht_drive_to_finalized, DeploymentConfig},
+        },
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{ht_get_face_contract_def, TEST_CONTRACT_INITIAL_CYCLES, TEST_WASM},
+    },
+    updates::{deploy_contract::deploy_contract_int, set_config::set_config_int},
+};
+
+#[tokio::test]
+async fn test_deployment_rate_limits() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let second_deployer = Principal::from_slice(&[5; 29]);
+    let third_deployer = Principal::from_slice(&[6; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let per_deployer = DeploymentRateLimit {
+        window: 1_000,
+        max_deployments: 1,
+    };
+    let hub = DeploymentRateLimit {
+        window: 1_000,
+        max_deployments: 2,
+    };
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+
+    // WRONG CONFIG
+    ht_set_test_caller(admin);
+    let result = set_config_int(Config {
+        deployment_rate_limits: DeploymentRateLimits {
+            hub: Some(DeploymentRateLimit {
+                window: 0,
+                ..hub.clone()
+            }),
+            ..DeploymentRateLimits::default()
+        },
+        ..config.clone()
+    });
+    ht_result_err_matches!(result, SetConfigError::WrongConfig { .. });
+
+    let result = set_config_int(Config {
+        deployment_rate_limits: DeploymentRateLimits {
+            per_deployer: Some(per_deployer.clone()),
+            per_contract_template: None,
+            hub: Some(hub.clone()),
+        },
+        ..config
+    });
+    assert!(result.is_ok());
+
+    // FIRST DEPLOYMENT
+
+    let deployment_id = ht_drive_to_deploying(
+        admin,
+        deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await
+    .deployment_id;
+    ht_drive_to_finalized(deployer, &deployment_id).await;
+
+    // DEPLOYER LIMIT REACHED
+    ht_set_test_time(500);
+    ht_set_test_caller(deployer);
+    let deployer_account = LedgerAccount::Account {
+        owner: deployer,
+        subaccount: None,
+    };
+    let result = deploy_contract_int(
+        deployer_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(
+        result,
+        DeployContractError::RateLimited { retry_after } if retry_after == 1_000
+    );
+
+    // USAGE
+    let result = get_deployment_rate_limits_usage_int(deployer, Some(contract_template_id));
+    ht_result_err_matches!(result, GetDeploymentRateLimitsUsageError::PermissionDenied);
+
+    ht_set_test_caller(admin);
+    let usage = get_deployment_rate_limits_usage_int(deployer, Some(contract_template_id)).unwrap();
+    assert_eq!(
+        usage.per_deployer,
+        Some(DeploymentRateLimitUsage {
+            limit: per_deployer.clone(),
+            deployments_count: 1,
+            retry_after: Some(1_000),
+        })
+    );
+    assert_eq!(usage.per_contract_template, None);
+    assert_eq!(
+        usage.hub,
+        Some(DeploymentRateLimitUsage {
+            limit: hub.clone(),
+            deployments_count: 1,
+            retry_after: None,
+        })
+    );
+
+    // HUB LIMIT REACHED
+
+    ht_drive_to_deploying(
+        admin,
+        second_deployer,
+        contract_template_id,
+        &DeploymentConfig::default(),
+        TEST_CONTRACT_INITIAL_CYCLES,
+        None,
+    )
+    .await;
+
+    ht_set_test_time(500);
+    ht_set_test_caller(third_deployer);
+    let result = deploy_contract_int(
+        LedgerAccount::Account {
+            owner: third_deployer,
+            subaccount: None,
+        },
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(
+        result,
+        DeployContractError::RateLimited { retry_after } if retry_after == 1_000
+    );
+
+    ht_set_test_caller(admin);
+    let usage = get_deployment_rate_limits_usage_int(third_deployer, None).unwrap();
+    assert_eq!(
+        usage.per_deployer.map(|usage| usage.deployments_count),
+        Some(0)
+    );
+    assert_eq!(
+        usage.hub,
+        Some(DeploymentRateLimitUsage {
+            limit: hub,
+            deployments_count: 2,
+            retry_after: Some(1_000),
+        })
+    );
+
+    // WINDOW SLID
+    ht_set_test_time(1_000);
+    ht_set_test_caller(deployer);
+    let result = deploy_contract_int(
+        deployer_account,
+        contract_template_id,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+    assert!(!matches!(
+        result,
+        Err(DeployContractError::RateLimited { .. })
+    ));
+}
+
+#[test]
+fn test_prune_deployment_starts() {
+    let memory_manager = MemoryManager::init(RestrictedMemory::new(
+        DefaultMemoryImpl::default(),
+        0..MAX_PAGES,
+    ));
+    let mut storage = DeploymentRateLimitsStorage::init(
+        memory_manager.get(MemoryId::new(0)),
+        memory_manager.get(MemoryId::new(1)),
+        memory_manager.get(MemoryId::new(2)),
+    );
+    let deployer = ht_get_test_user();
+    let second_deployer = Principal::from_slice(&[5; 29]);
+
+    storage.deployment_started(1_000, deployer, 1, 0, 500);
+    assert_eq!(storage.get_deployer_starts(deployer, 0), vec![1_000]);
+
+    // the expired starts are forgotten by the start of any other deployer
+    storage.deployment_started(2_000, second_deployer, 2, 1, 500);
+    assert!(storage.get_deployer_starts(deployer, 0).is_empty());
+    assert!(storage.get_contract_template_starts(1, 0).is_empty());
+    assert_eq!(storage.get_hub_starts(0), vec![2_000]);
+
+    // the start is not registered without the rate limits
+    storage.deployment_started(3_000, deployer, 1, 2, 0);
+    assert!(storage.get_deployer_starts(deployer, 0).is_empty());
+    assert!(storage.get_deployer_starts(second_deployer, 0).is_empty());
+    assert!(storage.get_hub_starts(0).is_empty());
+}
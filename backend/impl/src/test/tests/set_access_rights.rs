@@ -1,14 +1,27 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use common_canister_impl::stable_structures::CBor;
 use hub_canister_api::{
     set_access_rights::SetAccessRightsError,
-    types::{AccessRight, HubEventType, Permission},
+    set_role_assignment::SetRoleAssignmentError,
+    set_roles::SetRolesError,
+    types::{AccessRight, HubEventType, Permission, Role, RoleAssignmentSettings},
 };
+use ic_stable_structures::Storable;
 
 use crate::{
-    ht_last_hub_event_matches, ht_result_err_matches, read_state,
+    ht_last_hub_event_matches, ht_result_err_matches,
+    model::access_rights::{AccessControl, AccessRights},
+    queries::get_my_permissions::get_my_permissions_int,
+    read_state,
     test::tests::{
-        components::ic::ht_set_test_caller, ht_get_test_admin, ht_get_test_user, ht_init_test_hub,
+        components::{ic::ht_set_test_caller, time::ht_set_test_time},
+        ht_get_test_admin, ht_get_test_user, ht_init_test_hub,
+    },
+    updates::{
+        set_access_rights::set_access_rights_int, set_role_assignment::set_role_assignment_int,
+        set_roles::set_roles_int,
     },
-    updates::set_access_rights::set_access_rights_int,
 };
 
 #[tokio::test]
@@ -18,16 +31,17 @@ async fn test_set_access_rights() {
     let admin = ht_get_test_admin();
     ht_set_test_caller(admin);
 
-    let result = set_access_rights_int(vec![]);
-    assert!(result.is_ok());
+    // the initial admin has all the permissions
     read_state(|state| {
-        assert!(state
-            .get_model()
-            .get_access_rights_storage()
-            .get_access_rights()
-            .is_empty())
+        let ar = state.get_model().get_access_rights_storage();
+        assert!(ar.is_access_right(&admin, &Permission::SetAccessRights, 0));
+        assert!(!ar.is_access_right(&ht_get_test_user(), &Permission::SetConfig, 0));
     });
 
+    // nobody has the access rights without the explicit grant
+    let result = set_access_rights_int(vec![]);
+    ht_result_err_matches!(result, SetAccessRightsError::LoseControlDangerous);
+
     let result = set_access_rights_int(vec![AccessRight {
         caller: ht_get_test_user(),
         permissions: None,
@@ -44,8 +58,8 @@ async fn test_set_access_rights() {
 
     read_state(|state| {
         let ar = state.get_model().get_access_rights_storage();
-        assert!(ar.is_access_right(&admin, &Permission::SetAccessRights));
-        assert!(ar.is_access_right(&admin, &Permission::BlockContractTemplate));
+        assert!(ar.is_access_right(&admin, &Permission::SetAccessRights, 0));
+        assert!(ar.is_access_right(&admin, &Permission::BlockContractTemplate, 0));
     });
 
     let access_rights = vec![AccessRight {
@@ -58,8 +72,8 @@ async fn test_set_access_rights() {
 
     read_state(|state| {
         let ar = state.get_model().get_access_rights_storage();
-        assert!(ar.is_access_right(&admin, &Permission::SetAccessRights));
-        assert!(!ar.is_access_right(&admin, &Permission::BlockContractTemplate));
+        assert!(ar.is_access_right(&admin, &Permission::SetAccessRights, 0));
+        assert!(!ar.is_access_right(&admin, &Permission::BlockContractTemplate, 0));
     });
     ht_last_hub_event_matches!(HubEventType::AccessRightsSet { access_rights: event_access_rights }
         if event_access_rights == &access_rights);
@@ -73,3 +87,134 @@ async fn test_set_access_rights() {
     }]);
     ht_result_err_matches!(result, SetAccessRightsError::PermissionDenied);
 }
+
+#[tokio::test]
+async fn test_roles() {
+    ht_init_test_hub();
+
+    let admin = ht_get_test_admin();
+    let user = ht_get_test_user();
+    ht_set_test_caller(admin);
+
+    // INVALID ROLE
+    let reviewer = Role {
+        name: "reviewer".to_string(),
+        permissions: vec![Permission::ReviewContractTemplate, Permission::SetVoucher],
+        description: Some("reviews the contract templates".to_string()),
+    };
+    let result = set_roles_int(vec![reviewer.clone(), reviewer.clone()]);
+    ht_result_err_matches!(result, SetRolesError::InvalidRole { .. });
+
+    let result = set_roles_int(vec![Role {
+        name: "Reviewer".to_string(),
+        ..reviewer.clone()
+    }]);
+    ht_result_err_matches!(result, SetRolesError::InvalidRole { .. });
+
+    // SET ROLES
+    let roles = vec![
+        reviewer.clone(),
+        Role {
+            name: "access-manager".to_string(),
+            permissions: vec![Permission::SetAccessRights],
+            description: None,
+        },
+    ];
+    let result = set_roles_int(roles.clone());
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::RolesSet { roles: event_roles }
+        if event_roles == &roles);
+
+    // ASSIGN ROLE
+    ht_set_test_time(1_000);
+    let result = set_role_assignment_int(
+        user,
+        "auditor".to_string(),
+        Some(RoleAssignmentSettings { expiration: None }),
+    );
+    ht_result_err_matches!(result, SetRoleAssignmentError::RoleNotFound);
+
+    let result = set_role_assignment_int(
+        user,
+        "reviewer".to_string(),
+        Some(RoleAssignmentSettings {
+            expiration: Some(1_000),
+        }),
+    );
+    ht_result_err_matches!(result, SetRoleAssignmentError::InvalidRoleAssignment { .. });
+
+    let settings = RoleAssignmentSettings {
+        expiration: Some(2_000),
+    };
+    let result = set_role_assignment_int(user, "reviewer".to_string(), Some(settings.clone()));
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::RoleAssignmentSet { caller, role, settings: Some(event_settings) }
+        if caller == &user && role == "reviewer" && event_settings == &settings);
+
+    ht_set_test_caller(user);
+    let my_permissions = get_my_permissions_int();
+    assert_eq!(
+        my_permissions.permissions,
+        Some(reviewer.permissions.clone())
+    );
+    assert_eq!(my_permissions.role_assignments.len(), 1);
+    assert_eq!(my_permissions.role_assignments[0].settings, settings);
+
+    // ROLE ASSIGNMENT EXPIRED
+    ht_set_test_time(2_000);
+    let my_permissions = get_my_permissions_int();
+    assert_eq!(my_permissions.permissions, Some(vec![]));
+    assert!(my_permissions.role_assignments.is_empty());
+
+    // the admin permissions can be granted by a role
+    ht_set_test_caller(admin);
+    let result = set_role_assignment_int(
+        admin,
+        "access-manager".to_string(),
+        Some(RoleAssignmentSettings { expiration: None }),
+    );
+    assert!(result.is_ok());
+
+    let result = set_access_rights_int(vec![]);
+    assert!(result.is_ok());
+    assert_eq!(
+        get_my_permissions_int().permissions,
+        Some(vec![Permission::SetAccessRights])
+    );
+
+    let result = set_roles_int(vec![reviewer]);
+    ht_result_err_matches!(result, SetRolesError::LoseControlDangerous);
+
+    let result = set_role_assignment_int(admin, "access-manager".to_string(), None);
+    ht_result_err_matches!(result, SetRoleAssignmentError::LoseControlDangerous);
+
+    // REMOVE ROLE ASSIGNMENT
+    let result = set_role_assignment_int(user, "reviewer".to_string(), None);
+    assert!(result.is_ok());
+
+    let result = set_role_assignment_int(user, "reviewer".to_string(), None);
+    ht_result_err_matches!(result, SetRoleAssignmentError::RoleAssignmentNotFound);
+}
+
+#[test]
+fn test_read_legacy_access_rights() {
+    let legacy: AccessRights = BTreeMap::from([
+        (ht_get_test_admin(), (None, Some("admin".to_string()))),
+        (
+            ht_get_test_user(),
+            (Some(vec![Permission::SetConfig]), None),
+        ),
+    ]);
+
+    let bytes = CBor(legacy.clone()).to_bytes().into_owned();
+    let access_control = CBor::<AccessControl>::from_bytes(Cow::Owned(bytes));
+    assert_eq!(access_control.access_rights, legacy);
+    assert!(access_control.roles.is_empty());
+    assert!(access_control.role_assignments.is_empty());
+
+    let bytes = CBor(AccessControl::clone(&access_control))
+        .to_bytes()
+        .into_owned();
+    let access_control = CBor::<AccessControl>::from_bytes(Cow::Owned(bytes));
+    assert_eq!(access_control.access_rights, legacy);
+}
@@ -1,21 +1,37 @@
 use candid::Principal;
+use common_canister_types::LedgerAccount;
 use hub_canister_api::{
+    get_config_at_event::GetConfigAtEventError,
+    patch_config::{PatchConfigError, PatchConfigResult},
     set_config::SetConfigError,
-    types::{AccessRight, Config, HubEventType, Permission},
+    types::{AccessRight, Config, ConfigField, DeploymentBeneficiary, HubEventType, Permission},
 };
 use ic_ledger_types::{AccountIdentifier, DEFAULT_SUBACCOUNT};
 
 use crate::{
-    ht_last_hub_event_matches, ht_result_err_matches, read_state,
+    ht_last_hub_event_matches, ht_result_err_matches, ht_result_ok_matches, mutate_state,
+    queries::get_config_at_event::get_config_at_event_int,
+    read_state,
     test::tests::{
         components::ic::ht_set_test_caller, ht_get_test_admin, ht_get_test_user, ht_init_test_hub,
+        ht_set_initial_config,
     },
     updates::{
-        set_access_rights::set_access_rights_int, set_config::set_config_int,
-        validate_contract_certificate::parse_canister_from_url,
+        patch_config::patch_config_int, set_access_rights::set_access_rights_int,
+        set_config::set_config_int, validate_contract_certificate::parse_canister_from_url,
     },
 };
 
+fn get_last_event_config() -> Config {
+    let event_id = read_state(|state| {
+        state
+            .get_model()
+            .get_hub_events_storage()
+            .get_hub_events_len()
+    }) - 1;
+    get_config_at_event_int(event_id).unwrap().config
+}
+
 #[tokio::test]
 async fn test_set_config() {
     ht_init_test_hub();
@@ -81,8 +97,8 @@ async fn test_set_config() {
             account_hex.as_bytes()
         )
     });
-    ht_last_hub_event_matches!(HubEventType::ConfigSet { config: event_config }
-        if event_config.as_ref() == &new_config);
+    ht_last_hub_event_matches!(HubEventType::ConfigPatched { .. });
+    assert_eq!(get_last_event_config(), new_config);
 }
 
 #[tokio::test]
@@ -145,3 +161,192 @@ async fn test_parse_principal() {
     let result = parse_canister_from_url("http://be2us-64aaa-aaaaa-qaabq-cai.icp0.io");
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_set_config_deployment_beneficiaries() {
+    ht_init_test_hub();
+
+    let admin = ht_get_test_admin();
+    ht_set_test_caller(admin);
+
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![Permission::SetAccessRights, Permission::SetConfig]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let beneficiary = |share_permyriad| DeploymentBeneficiary {
+        account: LedgerAccount::Account {
+            owner: ht_get_test_user(),
+            subaccount: None,
+        },
+        share_permyriad,
+    };
+
+    // Check shares sum
+    let result = set_config_int(Config {
+        deployment_beneficiaries: vec![beneficiary(5_000), beneficiary(4_000)],
+        ..config.clone()
+    });
+    ht_result_err_matches!(result, SetConfigError::WrongConfig { reason }
+        if reason.contains("shares sum"));
+
+    // Check zero share
+    let result = set_config_int(Config {
+        deployment_beneficiaries: vec![beneficiary(10_000), beneficiary(0)],
+        ..config.clone()
+    });
+    ht_result_err_matches!(result, SetConfigError::WrongConfig { .. });
+
+    // Check wrong account
+    let result = set_config_int(Config {
+        deployment_beneficiaries: vec![DeploymentBeneficiary {
+            account: LedgerAccount::AccountIdentifier {
+                slice: vec![1, 2, 3],
+            },
+            share_permyriad: 10_000,
+        }],
+        ..config.clone()
+    });
+    ht_result_err_matches!(result, SetConfigError::WrongConfig { .. });
+
+    // Check ok
+    let new_config = Config {
+        deployment_beneficiaries: vec![
+            beneficiary(3_333),
+            DeploymentBeneficiary {
+                account: LedgerAccount::AccountIdentifier {
+                    slice: AccountIdentifier::new(&admin, &DEFAULT_SUBACCOUNT)
+                        .as_ref()
+                        .to_vec(),
+                },
+                share_permyriad: 6_667,
+            },
+        ],
+        ..config
+    };
+    let result = set_config_int(new_config.clone());
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::ConfigPatched { .. });
+    assert_eq!(get_last_event_config(), new_config);
+}
+
+#[tokio::test]
+async fn test_patch_config() {
+    ht_init_test_hub();
+
+    let admin = ht_get_test_admin();
+    ht_set_test_caller(admin);
+    ht_set_initial_config();
+
+    let config_version =
+        read_state(|state| state.get_model().get_config_storage().get_config_version());
+    let config_event_id = read_state(|state| {
+        state
+            .get_model()
+            .get_hub_events_storage()
+            .get_hub_events_len()
+    }) - 1;
+
+    // Check patch denied
+    ht_set_test_caller(ht_get_test_user());
+    let result = patch_config_int(config_version, vec![ConfigField::NameMaxLength(7)]);
+    ht_result_err_matches!(result, PatchConfigError::PermissionDenied);
+
+    ht_set_test_caller(admin);
+
+    // Check wrong patches
+    let result = patch_config_int(
+        config_version,
+        vec![ConfigField::NameMaxLength(7), ConfigField::NameMaxLength(8)],
+    );
+    ht_result_err_matches!(result, PatchConfigError::WrongConfig { .. });
+
+    let result = patch_config_int(
+        config_version,
+        vec![ConfigField::DeploymentFallbackAccountHex(
+            "not_a_hex".to_string(),
+        )],
+    );
+    ht_result_err_matches!(result, PatchConfigError::WrongConfig { .. });
+
+    // Check ok
+    let result = patch_config_int(
+        config_version,
+        vec![
+            ConfigField::NameMaxLength(7),
+            ConfigField::IsDeploymentAvailable(true),
+        ],
+    );
+    assert_eq!(result.unwrap().config_version, config_version + 1);
+    ht_last_hub_event_matches!(HubEventType::ConfigPatched { config_version: event_config_version, changes, previous }
+        if event_config_version == &(config_version + 1)
+            && changes == &vec![ConfigField::IsDeploymentAvailable(true), ConfigField::NameMaxLength(7)]
+            && previous == &vec![ConfigField::IsDeploymentAvailable(false), ConfigField::NameMaxLength(100)]);
+
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    assert_eq!(config.name_max_length, 7);
+    assert!(config.is_deployment_available);
+
+    // Check concurrent patch rejected
+    let result = patch_config_int(
+        config_version,
+        vec![ConfigField::ShortDescriptionMaxLength(10)],
+    );
+    ht_result_err_matches!(result, PatchConfigError::ConfigVersionMismatch { config_version: current_version }
+        if current_version == config_version + 1);
+
+    // Check set config changes the version too
+    let result = set_config_int(Config {
+        short_description_max_length: 10,
+        ..config.clone()
+    });
+    assert!(result.is_ok());
+    let result = patch_config_int(
+        config_version + 1,
+        vec![ConfigField::LongDescriptionMaxLength(10)],
+    );
+    ht_result_err_matches!(result, PatchConfigError::ConfigVersionMismatch { .. });
+
+    let result = patch_config_int(
+        config_version + 2,
+        vec![ConfigField::LongDescriptionMaxLength(10)],
+    );
+    ht_result_ok_matches!(result, PatchConfigResult { config_version: new_version }
+        if new_version == config_version + 3);
+
+    // Check config history
+    let config_at_event = get_config_at_event_int(config_event_id).unwrap().config;
+    assert_eq!(config_at_event.name_max_length, 100);
+    assert!(!config_at_event.is_deployment_available);
+
+    let config_at_event = get_config_at_event_int(config_event_id + 1).unwrap().config;
+    assert_eq!(config_at_event, config);
+
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    assert_eq!(get_last_event_config(), config);
+
+    let result = get_config_at_event_int(config_event_id + 4);
+    ht_result_err_matches!(result, GetConfigAtEventError::EventNotFound);
+
+    // the replay starts from the latest full config set
+    let snapshot = Config {
+        name_max_length: 55,
+        ..config.clone()
+    };
+    mutate_state(|state| {
+        state
+            .get_model_mut()
+            .get_hub_events_storage_mut()
+            .add_hub_event(
+                0,
+                admin,
+                HubEventType::ConfigSet {
+                    config: Box::new(snapshot.clone()),
+                },
+            )
+    });
+    assert_eq!(get_last_event_config(), snapshot);
+}
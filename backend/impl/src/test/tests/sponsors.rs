@@ -0,0 +1,241 @@
+use candid::Principal;
+use common_canister_impl::components::ledger::to_account_identifier;
+use common_canister_types::{ChunkDef, LedgerAccount};
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    get_deployments::{DeploymentsSelector, GetDeploymentsArgs},
+    get_sponsorship::GetSponsorshipError,
+    set_sponsorship::SetSponsorshipError,
+    types::{
+        Config, CreateContractCanisterStrategy, CyclesConvertingStrategy, DeploymentState,
+        HubEventType, SponsorshipSettings,
+    },
+};
+
+use crate::{
+    ht_deployment_state_matches, ht_last_hub_event_matches, ht_result_err_matches, mutate_state,
+    queries::{get_deployments::get_deployments_int, get_sponsorship::get_sponsorship_int},
+    read_state,
+    test::tests::{
+        components::{
+            ic::ht_set_test_caller, ledger::ht_get_account_balance, time::ht_set_test_time,
+        },
+        drivers::{
+            contract::ht_add_contract,
+            deployment::{
+                get_deployment_lock_expiration, ht_calc_expenses_amount, ht_fund_deployer_account,
+                ht_setup_deployment_config, DeploymentConfig,
+            },
+        },
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{ht_get_face_contract_def, TEST_CONTRACT_INITIAL_CYCLES, TEST_WASM},
+    },
+    updates::{
+        deploy_contract::deploy_contract_int, process_deployment::process_deployment_int,
+        set_config::set_config_int, set_sponsorship::set_sponsorship_int,
+    },
+};
+
+#[tokio::test]
+async fn test_deploy_contract_sponsored() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let sponsor = Principal::from_slice(&[5; 29]);
+    let stranger = Principal::from_slice(&[6; 29]);
+
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    ht_setup_deployment_config(admin, &deployment_cfg);
+
+    let expenses_amount = ht_calc_expenses_amount(&deployment_cfg, TEST_CONTRACT_INITIAL_CYCLES);
+    let (sponsor_account, sponsor_account_identifier) = ht_fund_deployer_account(
+        sponsor,
+        2 * expenses_amount,
+        2 * expenses_amount,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
+    let settings = SponsorshipSettings {
+        approved_account: sponsor_account.clone(),
+        budget: expenses_amount,
+        deployers: Some(vec![deployer]),
+        contract_template_ids: None,
+    };
+
+    // SET SPONSORSHIP
+    ht_set_test_caller(Principal::anonymous());
+    let result = set_sponsorship_int(Some(settings.clone()));
+    ht_result_err_matches!(result, SetSponsorshipError::CallerNotAuthorized);
+
+    ht_set_test_caller(sponsor);
+    let result = set_sponsorship_int(Some(SponsorshipSettings {
+        budget: 0,
+        ..settings.clone()
+    }));
+    ht_result_err_matches!(result, SetSponsorshipError::InvalidSponsorship { .. });
+
+    let result = set_sponsorship_int(Some(SponsorshipSettings {
+        deployers: None,
+        ..settings.clone()
+    }));
+    ht_result_err_matches!(result, SetSponsorshipError::InvalidSponsorship { .. });
+
+    let result = set_sponsorship_int(None);
+    ht_result_err_matches!(result, SetSponsorshipError::SponsorshipNotFound);
+    ht_result_err_matches!(
+        get_sponsorship_int(sponsor),
+        GetSponsorshipError::SponsorshipNotFound
+    );
+
+    // the deployer pays nothing, so its own account is not funded
+    let deployer_account = LedgerAccount::Account {
+        owner: deployer,
+        subaccount: None,
+    };
+    ht_set_test_time(0);
+    ht_set_test_caller(deployer);
+    let result = deploy_contract_int(
+        deployer_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        Some(sponsor),
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::SponsorshipNotFound);
+
+    ht_set_test_caller(sponsor);
+    assert!(set_sponsorship_int(Some(settings.clone())).is_ok());
+    ht_last_hub_event_matches!(HubEventType::SponsorshipSet {
+        settings: Some(_),
+        ..
+    });
+
+    // SPONSORSHIP NOT APPLICABLE
+    ht_set_test_caller(stranger);
+    let result = deploy_contract_int(
+        deployer_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        Some(sponsor),
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::SponsorshipNotApplicable);
+
+    // DEPLOY SPONSORED
+    ht_set_test_caller(deployer);
+    let deployment = deploy_contract_int(
+        deployer_account.clone(),
+        contract_template_id,
+        None,
+        None,
+        Some(sponsor),
+        None,
+    )
+    .await
+    .unwrap()
+    .deployment;
+    let deployment_id = deployment.deployment_id;
+    assert_eq!(deployment.sponsor, Some(sponsor));
+    assert_eq!(deployment.approved_account, sponsor_account);
+    assert_eq!(deployment.expenses_amount, expenses_amount);
+
+    let sponsorship = get_sponsorship_int(sponsor).unwrap().sponsorship;
+    assert_eq!(sponsorship.deployments_count, 1);
+    assert_eq!(
+        sponsorship.reserved_amount + sponsorship.charged_amount,
+        expenses_amount
+    );
+
+    // PROCESS DEPLOYMENT up to the certificate generation
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    assert!(process_deployment_int(deployment_id).await.is_ok());
+    ht_deployment_state_matches!(&deployment_id, DeploymentState::GenerateContractCertificate);
+
+    // the funds are taken from the sponsor
+    assert_eq!(
+        ht_get_account_balance(sponsor_account_identifier.to_hex()),
+        expenses_amount
+    );
+    let deployer_account_hex = to_account_identifier(&deployer_account).unwrap().to_hex();
+    assert_eq!(ht_get_account_balance(deployer_account_hex), 0);
+
+    let sponsorship = get_sponsorship_int(sponsor).unwrap().sponsorship;
+    assert_eq!(sponsorship.reserved_amount, 0);
+    assert_eq!(sponsorship.charged_amount, expenses_amount);
+
+    // BUDGET EXHAUSTED
+    let result = deploy_contract_int(
+        deployer_account,
+        contract_template_id,
+        None,
+        None,
+        Some(sponsor),
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::SponsorshipBudgetExhausted);
+
+    // AUDIT SPONSORED DEPLOYMENTS
+    ht_set_test_caller(admin);
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        max_deployments_per_chunk: 10,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    let result = get_deployments_int(GetDeploymentsArgs {
+        chunk_def: ChunkDef {
+            start: 0,
+            count: 10,
+        },
+        selector: DeploymentsSelector::BySponsor { sponsor },
+        sorting: None,
+    })
+    .unwrap();
+    assert_eq!(result.total_count, 1);
+    assert_eq!(result.deployments[0].deployment_id, deployment_id);
+    assert_eq!(result.deployments[0].deployer, deployer);
+
+    // REMOVE SPONSORSHIP
+    ht_set_test_caller(sponsor);
+
+    // the sponsorship with an in-flight reservation can not be removed
+    let in_flight_deployment_id = deployment_id + 1;
+    mutate_state(|state| {
+        state
+            .get_model_mut()
+            .get_sponsors_storage_mut()
+            .deployment_sponsored(sponsor, in_flight_deployment_id, 100)
+    });
+    let result = set_sponsorship_int(None);
+    ht_result_err_matches!(
+        result,
+        SetSponsorshipError::SponsoredDeploymentsInProgress {
+            reserved_amount: 100
+        }
+    );
+    mutate_state(|state| {
+        state
+            .get_model_mut()
+            .get_sponsors_storage_mut()
+            .sponsored_deployment_released(sponsor, in_flight_deployment_id)
+    });
+
+    assert!(set_sponsorship_int(None).is_ok());
+    ht_last_hub_event_matches!(HubEventType::SponsorshipSet { settings: None, .. });
+    ht_result_err_matches!(
+        get_sponsorship_int(sponsor),
+        GetSponsorshipError::SponsorshipNotFound
+    );
+}
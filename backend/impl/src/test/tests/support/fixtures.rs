@@ -1,7 +1,18 @@
+use std::sync::LazyLock;
+
 use hub_canister_api::types::{CanisterSettings, ContractTemplateDefinition};
 
-/// Default WASM bytes used in tests (10 bytes).
-pub(crate) const TEST_WASM: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// Candid service stored in the `icp:public candid:service` section of [`TEST_WASM`].
+pub(crate) const TEST_CANDID_SERVICE: &str = "service : {}";
+
+/// Default WASM used in tests: a minimal valid contract module.
+pub(crate) static TEST_WASM: LazyLock<Vec<u8>> = LazyLock::new(|| {
+    ht_build_contract_wasm(
+        &["canister_init", "canister_query get_contract_certificate"],
+        1,
+        &[("icp:public candid:service", TEST_CANDID_SERVICE.as_bytes())],
+    )
+});
 
 /// Initial cycles provisioned to each deployed contract canister in tests.
 pub(crate) const TEST_CONTRACT_INITIAL_CYCLES: u128 = 9_000_000_000;
@@ -40,5 +51,85 @@ pub(crate) fn ht_get_face_contract_def() -> ContractTemplateDefinition {
         },
         documentation_url: "documentation_url".to_string(),
         terms_of_use_url: "terms_of_use_url".to_string(),
+        categories: Some(vec!["finance".to_string()]),
+        tags: Some(vec!["token".to_string(), "icrc".to_string()]),
+        publisher_fee: None,
+        max_deployments: None,
+        max_deployments_per_deployer: None,
+    }
+}
+
+/// Builds a minimal WASM module with the given function exports (empty bodies),
+/// one memory of `memory_pages` initial pages and the given custom sections.
+pub(crate) fn ht_build_contract_wasm(
+    exports: &[&str],
+    memory_pages: u32,
+    custom_sections: &[(&str, &[u8])],
+) -> Vec<u8> {
+    fn leb128(mut value: u32, out: &mut Vec<u8>) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    fn name(value: &str, out: &mut Vec<u8>) {
+        leb128(value.len() as u32, out);
+        out.extend_from_slice(value.as_bytes());
+    }
+
+    fn section(id: u8, content: Vec<u8>, out: &mut Vec<u8>) {
+        out.push(id);
+        leb128(content.len() as u32, out);
+        out.extend(content);
+    }
+
+    let count = exports.len() as u32;
+    let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+    // type section: single `() -> ()` function type
+    section(1, vec![0x01, 0x60, 0x00, 0x00], &mut wasm);
+
+    // function section: one function per export
+    let mut functions = vec![];
+    leb128(count, &mut functions);
+    functions.extend(exports.iter().map(|_| 0x00));
+    section(3, functions, &mut wasm);
+
+    // memory section: no maximum
+    let mut memory = vec![0x01, 0x00];
+    leb128(memory_pages, &mut memory);
+    section(5, memory, &mut wasm);
+
+    // export section
+    let mut export_entries = vec![];
+    leb128(count, &mut export_entries);
+    for (index, export) in exports.iter().enumerate() {
+        name(export, &mut export_entries);
+        export_entries.push(0x00);
+        leb128(index as u32, &mut export_entries);
     }
+    section(7, export_entries, &mut wasm);
+
+    // code section: empty bodies
+    let mut code = vec![];
+    leb128(count, &mut code);
+    for _ in exports {
+        code.extend([0x02, 0x00, 0x0b]);
+    }
+    section(10, code, &mut wasm);
+
+    for (section_name, content) in custom_sections {
+        let mut custom = vec![];
+        name(section_name, &mut custom);
+        custom.extend_from_slice(content);
+        section(0, custom, &mut wasm);
+    }
+
+    wasm
 }
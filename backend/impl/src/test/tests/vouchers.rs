@@ -0,0 +1,300 @@
+use hub_canister_api::{
+    deploy_contract::DeployContractError,
+    get_voucher::GetVoucherError,
+    set_voucher::SetVoucherError,
+    types::{
+        AccessRight, Config, CreateContractCanisterStrategy, CyclesConvertingStrategy,
+        DeploymentResult, DeploymentState, FinalizeDeploymentState, HubEventType, Permission,
+        VoucherDefinition, VoucherDiscount,
+    },
+};
+
+use crate::{
+    ht_deployment_state_matches, ht_last_hub_event_matches, ht_result_err_matches,
+    queries::get_voucher::get_voucher_int,
+    read_state,
+    test::tests::{
+        components::{ic::ht_set_test_caller, time::ht_set_test_time},
+        drivers::{
+            contract::ht_add_contract,
+            deployment::{
+                get_deployment_lock_expiration, ht_calc_expenses_amount, ht_fund_deployer_account,
+                ht_setup_deployment_config, DeploymentConfig,
+            },
+        },
+        ht_get_test_admin, ht_get_test_user,
+        support::fixtures::{
+            ht_get_face_contract_def, TEST_CONTRACT_INITIAL_CYCLES, TEST_DEPLOYMENT_CYCLES_COST,
+            TEST_WASM,
+        },
+    },
+    updates::{
+        cancel_deployment::cancel_deployment_int, deploy_contract::deploy_contract_int,
+        set_access_rights::set_access_rights_int, set_config::set_config_int,
+        set_voucher::set_voucher_int,
+    },
+};
+
+#[test]
+fn test_set_voucher() {
+    let admin = ht_get_test_admin();
+    let user = ht_get_test_user();
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let code = "SPRING-2025".to_string();
+    let definition = VoucherDefinition {
+        discount: VoucherDiscount::Percentage { permyriad: 2_500 },
+        max_redemptions: 10,
+        expiration: None,
+        contract_template_ids: Some(vec![contract_template_id]),
+    };
+
+    // PERMISSION DENIED
+    ht_set_test_caller(user);
+    let result = set_voucher_int(code.clone(), Some(definition.clone()));
+    ht_result_err_matches!(result, SetVoucherError::PermissionDenied);
+
+    ht_set_test_caller(admin);
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![Permission::SetAccessRights, Permission::SetVoucher]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    // INVALID CODE
+    let result = set_voucher_int("a b".to_string(), Some(definition.clone()));
+    ht_result_err_matches!(result, SetVoucherError::InvalidVoucherCode { .. });
+
+    // INVALID DEFINITION
+    let result = set_voucher_int(
+        code.clone(),
+        Some(VoucherDefinition {
+            discount: VoucherDiscount::Percentage { permyriad: 10_001 },
+            ..definition.clone()
+        }),
+    );
+    ht_result_err_matches!(result, SetVoucherError::InvalidVoucherDefinition { .. });
+
+    let result = set_voucher_int(
+        code.clone(),
+        Some(VoucherDefinition {
+            max_redemptions: 0,
+            ..definition.clone()
+        }),
+    );
+    ht_result_err_matches!(result, SetVoucherError::InvalidVoucherDefinition { .. });
+
+    // NOT FOUND
+    let result = set_voucher_int(code.clone(), None);
+    ht_result_err_matches!(result, SetVoucherError::VoucherNotFound);
+    ht_result_err_matches!(
+        get_voucher_int(code.clone()),
+        GetVoucherError::VoucherNotFound
+    );
+
+    // SET VOUCHER
+    let result = set_voucher_int(code.clone(), Some(definition.clone()));
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::VoucherSet {
+        definition: Some(_),
+        ..
+    });
+
+    let voucher = get_voucher_int(code.clone()).unwrap().voucher;
+    assert_eq!(voucher.code, code);
+    assert_eq!(voucher.definition, definition);
+    assert_eq!(voucher.redemptions_count, 0);
+    assert_eq!(voucher.subsidized_cycles, 0);
+
+    // REMOVE VOUCHER
+    let result = set_voucher_int(code.clone(), None);
+    assert!(result.is_ok());
+    ht_last_hub_event_matches!(HubEventType::VoucherSet {
+        definition: None,
+        ..
+    });
+    ht_result_err_matches!(get_voucher_int(code), GetVoucherError::VoucherNotFound);
+}
+
+#[tokio::test]
+async fn test_deploy_contract_with_voucher() {
+    let admin = ht_get_test_admin();
+    let deployer = ht_get_test_user();
+    let contract_template_id =
+        ht_add_contract(admin, ht_get_face_contract_def(), TEST_WASM.to_vec());
+
+    let deployment_cfg = DeploymentConfig {
+        cycles_converting_strategy: CyclesConvertingStrategy::Skip,
+        contract_canister_creation_strategy: CreateContractCanisterStrategy::OverManagementCanister,
+        ..DeploymentConfig::default()
+    };
+    ht_setup_deployment_config(admin, &deployment_cfg);
+
+    let result = set_access_rights_int(vec![AccessRight {
+        caller: admin,
+        permissions: Some(vec![
+            Permission::SetAccessRights,
+            Permission::SetConfig,
+            Permission::SetVoucher,
+        ]),
+        description: None,
+    }]);
+    assert!(result.is_ok());
+
+    let code = "FREE-DEPLOY".to_string();
+    let foreign_code = "FOREIGN-DEPLOY".to_string();
+    let definition = VoucherDefinition {
+        discount: VoucherDiscount::Free,
+        max_redemptions: 1,
+        expiration: Some(1_000),
+        contract_template_ids: Some(vec![contract_template_id]),
+    };
+    assert!(set_voucher_int(code.clone(), Some(definition.clone())).is_ok());
+    assert!(set_voucher_int(
+        foreign_code.clone(),
+        Some(VoucherDefinition {
+            contract_template_ids: Some(vec![contract_template_id + 1]),
+            ..definition
+        }),
+    )
+    .is_ok());
+
+    // the free deployment needs no funds on the approved account
+    let (approved_account, _) = ht_fund_deployer_account(
+        deployer,
+        0,
+        0,
+        deployment_cfg.deployment_allowance_expiration_timeout,
+    );
+    ht_set_test_time(0);
+    ht_set_test_caller(deployer);
+
+    // VOUCHER NOT FOUND
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        Some("UNKNOWN-CODE".to_string()),
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::VoucherNotFound);
+
+    // VOUCHER NOT APPLICABLE
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        Some(foreign_code),
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::VoucherNotApplicable);
+
+    // VOUCHER EXPIRED
+    ht_set_test_time(1_001);
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        Some(code.clone()),
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::VoucherExpired);
+    ht_set_test_time(0);
+
+    // BUDGET EXHAUSTED
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        Some(code.clone()),
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::VouchersCyclesBudgetExhausted);
+
+    let subsidized_cycles = TEST_DEPLOYMENT_CYCLES_COST + TEST_CONTRACT_INITIAL_CYCLES;
+    ht_set_test_caller(admin);
+    let config = read_state(|state| state.get_model().get_config_storage().get_config().clone());
+    let result = set_config_int(Config {
+        vouchers_cycles_budget: subsidized_cycles,
+        ..config
+    });
+    assert!(result.is_ok());
+
+    // DEPLOY FREE
+    ht_set_test_caller(deployer);
+    let deployment = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        Some(code.clone()),
+        None,
+        None,
+    )
+    .await
+    .unwrap()
+    .deployment;
+    let deployment_id = deployment.deployment_id;
+    assert_eq!(deployment.expenses_amount, 0);
+    assert_eq!(
+        deployment.deployment_expenses.voucher_discount,
+        Some(VoucherDiscount::Free)
+    );
+    let redemption = deployment.voucher_redemption.unwrap();
+    assert_eq!(redemption.code, code);
+    assert_eq!(
+        redemption.discount_amount,
+        ht_calc_expenses_amount(&deployment_cfg, TEST_CONTRACT_INITIAL_CYCLES)
+    );
+    assert_eq!(redemption.subsidized_cycles, subsidized_cycles);
+
+    let voucher = get_voucher_int(code.clone()).unwrap().voucher;
+    assert_eq!(voucher.redemptions_count, 1);
+    assert_eq!(voucher.subsidized_cycles, subsidized_cycles);
+
+    // REDEMPTIONS EXHAUSTED
+    let result = deploy_contract_int(
+        approved_account.clone(),
+        contract_template_id,
+        None,
+        Some(code.clone()),
+        None,
+        None,
+    )
+    .await;
+    ht_result_err_matches!(result, DeployContractError::VoucherRedemptionsExhausted);
+
+    // the cancelled deployment gives the redemption back
+    ht_set_test_time(get_deployment_lock_expiration(&deployment_id));
+    let result = cancel_deployment_int(deployment_id, "test".to_string()).await;
+    assert!(result.is_ok());
+    ht_deployment_state_matches!(
+        &deployment_id,
+        DeploymentState::FinalizeDeployment {
+            result: DeploymentResult::Cancelled { .. },
+            sub_state: FinalizeDeploymentState::Finalized
+        }
+    );
+
+    let voucher = get_voucher_int(code).unwrap().voucher;
+    assert_eq!(voucher.redemptions_count, 0);
+    assert_eq!(voucher.subsidized_cycles, 0);
+    read_state(|state| {
+        assert_eq!(
+            state
+                .get_model()
+                .get_vouchers_storage()
+                .get_subsidized_cycles(),
+            0
+        );
+    });
+}
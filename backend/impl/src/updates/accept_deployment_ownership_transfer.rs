@@ -0,0 +1,62 @@
+use crate::handlers::deployments::build_deployment_information_with_load;
+use crate::handlers::deployments::states::generate_contract_certificate::reissue_contract_certificate;
+use crate::{get_env, log_info, mutate_state};
+use hub_canister_api::accept_deployment_ownership_transfer::*;
+use hub_canister_api::types::DeploymentId;
+use ic_cdk_macros::update;
+
+#[update]
+fn accept_deployment_ownership_transfer(Args { deployment_id }: Args) -> Response {
+    accept_deployment_ownership_transfer_int(deployment_id).into()
+}
+
+pub(crate) fn accept_deployment_ownership_transfer_int(
+    deployment_id: DeploymentId,
+) -> Result<AcceptDeploymentOwnershipTransferResult, AcceptDeploymentOwnershipTransferError> {
+    let env = get_env();
+    let caller = env.get_ic().get_caller();
+    let time = env.get_time().get_current_unix_epoch_time_millis();
+
+    mutate_state(|state| {
+        let deployment = state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .ok_or(AcceptDeploymentOwnershipTransferError::DeploymentNotFound)?;
+
+        if deployment.pending_owner != Some(caller) {
+            return Err(AcceptDeploymentOwnershipTransferError::PermissionDenied);
+        }
+
+        if !deployment.is_successfully_finalized() {
+            return Err(AcceptDeploymentOwnershipTransferError::DeploymentWrongState);
+        }
+
+        if let Some(ban) = state
+            .get_model()
+            .get_deployer_bans_storage()
+            .find_active_deployer_ban(&caller, time)
+        {
+            return Err(AcceptDeploymentOwnershipTransferError::DeployerBanned { ban });
+        }
+
+        state
+            .get_model_mut()
+            .get_deployments_storage_mut()
+            .transfer_ownership(time, &deployment_id, caller);
+        Ok(())
+    })?;
+
+    // reissue the certificate naming the new owner
+    reissue_contract_certificate(&env, &deployment_id);
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': ownership transferred to '{}'.",
+        caller.to_text()
+    );
+
+    Ok(AcceptDeploymentOwnershipTransferResult {
+        deployment: build_deployment_information_with_load(&deployment_id).unwrap(),
+    })
+}
@@ -1,4 +1,14 @@
-use crate::{is_caller_has_access_right, log_info, mutate_state, read_state};
+use crate::{
+    get_env,
+    handlers::templates::{
+        check_contract_template_deployment_limits, check_contract_template_labels,
+        check_publisher_fee, wasm::inspect_contract_wasm,
+    },
+    is_caller_has_access_right, log_info,
+    model::templates::ContractTemplateWasmMetadata,
+    mutate_state, read_state,
+};
+use candid::Principal;
 use common_contract_api::get_wasm_hash;
 use hub_canister_api::{
     add_contract_template::*,
@@ -18,30 +28,72 @@ fn add_contract_template(
 pub(crate) fn add_contract_template_int(
     contract_template_definition: ContractTemplateDefinition,
 ) -> Result<AddContractTemplateResult, AddContractTemplateError> {
-    if !is_caller_has_access_right(&Permission::AddContractTemplate) {
+    let caller = get_env().get_ic().get_caller();
+    let admin = is_caller_has_access_right(&Permission::AddContractTemplate);
+    if !admin && !is_publisher(&caller) {
         return Err(AddContractTemplateError::PermissionDenied);
     }
 
+    let (wasm, wasm_metadata) =
+        check_contract_template_addition(&caller, admin, &contract_template_definition)?;
+    Ok(register_contract_template(
+        caller,
+        contract_template_definition,
+        wasm,
+        wasm_metadata,
+    ))
+}
+
+/// Checks the uploaded wasm and the definition of the contract template added by the caller,
+/// returns the wasm and its metadata.
+pub(crate) fn check_contract_template_addition(
+    caller: &Principal,
+    admin: bool,
+    contract_template_definition: &ContractTemplateDefinition,
+) -> Result<(Vec<u8>, ContractTemplateWasmMetadata), AddContractTemplateError> {
     let grant = get_upload_wasm_grant()?;
     let wasm = validate_uploaded_wasm(&grant)?;
-    check_contract_template_definition(&contract_template_definition, &wasm)?;
+    check_publisher_quotas(caller, admin, wasm.len())?;
+    check_contract_template_definition(contract_template_definition, &wasm)?;
+    let wasm_metadata = inspect_contract_wasm(
+        &wasm,
+        &contract_template_definition.contract_canister_settings,
+    )?;
+    Ok((wasm, wasm_metadata))
+}
 
-    // add contract template
+pub(crate) fn register_contract_template(
+    registrar: Principal,
+    contract_template_definition: ContractTemplateDefinition,
+    wasm: Vec<u8>,
+    wasm_metadata: ContractTemplateWasmMetadata,
+) -> AddContractTemplateResult {
     mutate_state(|state| {
         let env = state.get_env();
         let model = state.get_model_mut();
 
-        let registrar = env.get_ic().get_caller();
         let registered = env.get_time().get_current_unix_epoch_time_millis();
 
+        model
+            .get_publishers_storage_mut()
+            .contract_template_added(&registrar, wasm.len());
+
         let contract_template_id = model
             .get_contract_templates_storage_mut()
-            .add_contract_template(registrar, registered, contract_template_definition, wasm);
+            .add_contract_template(
+                registrar,
+                registered,
+                contract_template_definition,
+                wasm,
+                wasm_metadata,
+            );
 
-        model.get_wasm_storage_mut().set_upload_wasm_grant(None);
+        model
+            .get_wasm_storage_mut()
+            .set_upload_wasm_grant(registrar, None);
         model.get_hub_events_storage_mut().add_hub_event(
-            env.get_time().get_current_unix_epoch_time_millis(),
-            env.get_ic().get_caller(),
+            registered,
+            registrar,
             HubEventType::ContractTemplateAdded {
                 contract_template_id,
             },
@@ -52,9 +104,48 @@ pub(crate) fn add_contract_template_int(
             "Contract template '{contract_template_id}' has been added successfully!"
         );
 
-        Ok(AddContractTemplateResult {
+        AddContractTemplateResult {
             contract_template_id,
-        })
+        }
+    })
+}
+
+fn is_publisher(caller: &Principal) -> bool {
+    read_state(|state| {
+        state
+            .get_model()
+            .get_publishers_storage()
+            .get_publisher(caller)
+            .is_some()
+    })
+}
+
+/// Publisher adds the wasm uploaded by its own grant only and within its quotas.
+fn check_publisher_quotas(
+    caller: &Principal,
+    admin: bool,
+    wasm_length: usize,
+) -> Result<(), AddContractTemplateError> {
+    read_state(|state| {
+        let model = state.get_model();
+        let Some(publisher) = model.get_publishers_storage().get_publisher(caller) else {
+            return Ok(());
+        };
+
+        if !admin && model.get_wasm_storage().get_upload_wasm_grantor() != Some(caller) {
+            return Err(AddContractTemplateError::PermissionDenied);
+        }
+
+        let max_count = publisher.settings.max_contract_templates;
+        if publisher.contract_templates_count >= max_count {
+            return Err(AddContractTemplateError::ContractTemplatesQuotaExceeded { max_count });
+        }
+
+        let available_length = publisher.get_available_wasm_length();
+        if wasm_length > available_length {
+            return Err(AddContractTemplateError::WasmQuotaExceeded { available_length });
+        }
+        Ok(())
     })
 }
 
@@ -125,6 +216,28 @@ fn check_contract_template_definition(
                 max_length: config.long_description_max_length,
             });
         }
+        check_contract_template_labels(
+            contract_template_definition
+                .categories
+                .as_deref()
+                .unwrap_or_default(),
+            |max_count| AddContractTemplateError::TooManyContractCategories { max_count },
+            |category| AddContractTemplateError::InvalidContractCategory { category },
+        )?;
+        check_contract_template_labels(
+            contract_template_definition
+                .tags
+                .as_deref()
+                .unwrap_or_default(),
+            |max_count| AddContractTemplateError::TooManyContractTags { max_count },
+            |tag| AddContractTemplateError::InvalidContractTag { tag },
+        )?;
+        if let Some(publisher_fee) = &contract_template_definition.publisher_fee {
+            check_publisher_fee(publisher_fee)
+                .map_err(|reason| AddContractTemplateError::InvalidPublisherFee { reason })?;
+        }
+        check_contract_template_deployment_limits(contract_template_definition)
+            .map_err(|reason| AddContractTemplateError::InvalidDeploymentLimits { reason })?;
 
         // check contract template uniqueness
         if let Some(error) = state
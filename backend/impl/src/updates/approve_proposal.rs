@@ -0,0 +1,111 @@
+use crate::{
+    get_env, handlers::governance::execute_governed_operation, log_info, mutate_state, read_state,
+};
+use hub_canister_api::{
+    approve_proposal::*,
+    types::{HubEventType, ProposalId, ProposalStatus},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn approve_proposal(Args { proposal_id }: Args) -> Response {
+    approve_proposal_int(proposal_id).into()
+}
+
+/// The last required approval executes the proposed operation on behalf of the proposer.
+pub(crate) fn approve_proposal_int(
+    proposal_id: ProposalId,
+) -> Result<ApproveProposalResult, ApproveProposalError> {
+    let env = get_env();
+    let caller = env.get_ic().get_caller();
+    let time = env.get_time().get_current_unix_epoch_time_millis();
+
+    let mut proposal = read_state(|state| {
+        state
+            .get_model()
+            .get_proposals_storage()
+            .get_proposal(&proposal_id)
+    })
+    .ok_or(ApproveProposalError::ProposalNotFound)?;
+
+    if !proposal.approvers.contains(&caller) {
+        return Err(ApproveProposalError::PermissionDenied);
+    }
+
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ApproveProposalError::ProposalNotPending);
+    }
+
+    if proposal.is_expired(time) {
+        return Err(ApproveProposalError::ProposalExpired);
+    }
+
+    if proposal.approvals.contains(&caller) {
+        return Err(ApproveProposalError::AlreadyApproved);
+    }
+
+    proposal.approvals.push(caller);
+    let approved = proposal.approvals.len() >= proposal.required_approvals as usize;
+    if approved {
+        // the proposal leaves the pending status before the execution to be executed once
+        proposal.status = ProposalStatus::Executed;
+    }
+
+    mutate_state(|state| {
+        let model = state.get_model_mut();
+        model
+            .get_proposals_storage_mut()
+            .set_proposal(proposal_id, proposal.clone());
+        model.get_hub_events_storage_mut().add_hub_event(
+            time,
+            caller,
+            HubEventType::ProposalApproved { proposal_id },
+        );
+    });
+
+    log_info!(
+        env,
+        "Proposal '{proposal_id}' approved by caller '{}' ({}/{})",
+        caller.to_text(),
+        proposal.approvals.len(),
+        proposal.required_approvals
+    );
+
+    if !approved {
+        return Ok(ApproveProposalResult {
+            status: proposal.status,
+        });
+    }
+
+    let error = execute_governed_operation(proposal.proposer, proposal.operation.clone()).err();
+    if let Some(reason) = &error {
+        proposal.status = ProposalStatus::ExecutionFailed {
+            reason: reason.clone(),
+        };
+    }
+
+    mutate_state(|state| {
+        let model = state.get_model_mut();
+        model
+            .get_proposals_storage_mut()
+            .set_proposal(proposal_id, proposal.clone());
+        model.get_hub_events_storage_mut().add_hub_event(
+            time,
+            caller,
+            HubEventType::ProposalExecuted {
+                proposal_id,
+                error: error.clone(),
+            },
+        );
+    });
+
+    log_info!(
+        env,
+        "Proposal '{proposal_id}' executed: {:?}",
+        proposal.status
+    );
+
+    Ok(ApproveProposalResult {
+        status: proposal.status,
+    })
+}
@@ -1,8 +1,12 @@
-use crate::{is_caller_has_access_right, log_info, mutate_state};
+use crate::{
+    get_env, handlers::governance::is_operation_governed, is_caller_has_access_right, log_info,
+    model::DataModel, mutate_state,
+};
+use candid::Principal;
 use common_contract_api::ContractTemplateId;
 use hub_canister_api::{
     block_contract_template::*,
-    types::{HubEventType, Permission},
+    types::{GovernedOperationType, HubEventType, Permission},
 };
 use ic_cdk_macros::update;
 
@@ -24,19 +28,24 @@ pub(crate) fn block_contract_template_int(
         return Err(BlockContractTemplateError::PermissionDenied);
     }
 
+    if is_operation_governed(GovernedOperationType::BlockContractTemplate) {
+        return Err(BlockContractTemplateError::ProposalRequired);
+    }
+
+    apply_contract_template_block(
+        get_env().get_ic().get_caller(),
+        contract_template_id,
+        reason,
+    )
+}
+
+pub(crate) fn apply_contract_template_block(
+    caller: Principal,
+    contract_template_id: ContractTemplateId,
+    reason: String,
+) -> Result<(), BlockContractTemplateError> {
     mutate_state(|state| {
-        state
-            .get_model()
-            .get_contract_templates_storage()
-            .get_contract_template(&contract_template_id)
-            .ok_or(BlockContractTemplateError::ContractTemplateNotFound)
-            .and_then(|contract_template| {
-                if contract_template.blocked.is_some() {
-                    Err(BlockContractTemplateError::ContractTemplateAlreadyBlocked)
-                } else {
-                    Ok(())
-                }
-            })?;
+        check_contract_template_block(state.get_model(), &contract_template_id)?;
 
         let env = state.get_env();
 
@@ -48,7 +57,7 @@ pub(crate) fn block_contract_template_int(
 
         model.get_hub_events_storage_mut().add_hub_event(
             time,
-            env.get_ic().get_caller(),
+            caller,
             HubEventType::ContractTemplateBlocked {
                 contract_template_id,
             },
@@ -57,9 +66,26 @@ pub(crate) fn block_contract_template_int(
         log_info!(
             env,
             "Contract template '{contract_template_id}' blocked by caller '{}' ({reason})",
-            env.get_ic().get_caller().to_text()
+            caller.to_text()
         );
 
         Ok(())
     })
 }
+
+pub(crate) fn check_contract_template_block(
+    model: &DataModel,
+    contract_template_id: &ContractTemplateId,
+) -> Result<(), BlockContractTemplateError> {
+    model
+        .get_contract_templates_storage()
+        .get_contract_template(contract_template_id)
+        .ok_or(BlockContractTemplateError::ContractTemplateNotFound)
+        .and_then(|contract_template| {
+            if contract_template.blocked.is_some() {
+                Err(BlockContractTemplateError::ContractTemplateAlreadyBlocked)
+            } else {
+                Ok(())
+            }
+        })
+}
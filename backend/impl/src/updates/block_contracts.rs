@@ -92,7 +92,9 @@ pub(crate) fn block_contracts_int(
     })
 }
 
-fn deduplicate_contract_canister_ids(contract_canister_ids: Vec<Principal>) -> Vec<Principal> {
+pub(crate) fn deduplicate_contract_canister_ids(
+    contract_canister_ids: Vec<Principal>,
+) -> Vec<Principal> {
     let mut seen = BTreeSet::new();
 
     contract_canister_ids
@@ -101,7 +103,7 @@ fn deduplicate_contract_canister_ids(contract_canister_ids: Vec<Principal>) -> V
         .collect()
 }
 
-fn deduplicate_deployment_ids(deployment_ids: Vec<DeploymentId>) -> Vec<DeploymentId> {
+pub(crate) fn deduplicate_deployment_ids(deployment_ids: Vec<DeploymentId>) -> Vec<DeploymentId> {
     let mut seen = BTreeSet::new();
 
     deployment_ids
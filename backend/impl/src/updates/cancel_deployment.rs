@@ -59,7 +59,13 @@ pub fn validate_permission(
             .get_deployment(deployment_id)
             .ok_or(CancelDeploymentError::DeploymentNotFound)?;
 
-        if deployment.deployer != env.get_ic().get_caller() {
+        let caller = env.get_ic().get_caller();
+        if !deployment.is_deployer_or_beneficiary(&caller) {
+            return Err(CancelDeploymentError::PermissionDenied);
+        }
+
+        // the payer on behalf of the beneficiary can cancel until the contract canister is created
+        if deployment.get_owner() != caller && deployment.contract_canister.is_some() {
             return Err(CancelDeploymentError::PermissionDenied);
         }
 
@@ -1,21 +1,27 @@
 use std::cmp::min;
 
-use crate::components::Environment;
 use crate::handlers::deployments::expenses_calculator::DeploymentExpensesCalculator;
 use crate::handlers::deployments::processor::process_deployment;
 use crate::handlers::deployments::{
     build_deployment_information, build_deployment_information_with_load, find_active_deployment,
+    find_active_deployment_id, get_icp_conversation_rate,
 };
+use crate::handlers::rate_limits::{check_deployment_rate_limits, register_deployment_start};
+use crate::handlers::sponsors::check_sponsorship;
+use crate::handlers::templates::{
+    check_contract_template_supply, is_contract_template_deployable_by,
+};
+use crate::handlers::vouchers::check_voucher_redemption;
+use crate::model::DataModel;
 use crate::{get_env, log_info, mutate_state, read_state};
 use candid::Principal;
-use common_canister_impl::components::cmc::interface::CallWrapperError;
 use common_canister_impl::components::icrc2_ledger::to_icrc1_account;
 use common_canister_impl::components::ledger::to_account_identifier;
 use common_canister_types::{millis_to_nanos, LedgerAccount, TimestampMillis, TokenE8s};
 use common_contract_api::ContractTemplateId;
 use hub_canister_api::deploy_contract::*;
 use hub_canister_api::types::{
-    DeploymentExpenses, IcpConversationRate, IcpXdrConversionRateStrategy,
+    ContractTemplateReviewStatus, DeploymentExpenses, VoucherCode, VoucherRedemption,
 };
 use ic_cdk_macros::update;
 use icrc_ledger_types::icrc1::account::{principal_to_subaccount, Account};
@@ -29,17 +35,30 @@ async fn deploy_contract(
         approved_account,
         contract_template_id,
         subnet_type,
+        voucher,
+        sponsor,
+        beneficiary,
     }: Args,
 ) -> Response {
-    deploy_contract_int(approved_account, contract_template_id, subnet_type)
-        .await
-        .into()
+    deploy_contract_int(
+        approved_account,
+        contract_template_id,
+        subnet_type,
+        voucher,
+        sponsor,
+        beneficiary,
+    )
+    .await
+    .into()
 }
 
 pub(crate) async fn deploy_contract_int(
     approved_account: LedgerAccount,
     contract_template_id: ContractTemplateId,
     subnet_type: Option<String>,
+    voucher: Option<VoucherCode>,
+    sponsor: Option<Principal>,
+    beneficiary: Option<Principal>,
 ) -> Result<DeployContractResult, DeployContractError> {
     let env = get_env();
     if env.get_ic().is_caller_anonymous() {
@@ -48,6 +67,39 @@ pub(crate) async fn deploy_contract_int(
 
     let deployer = env.get_ic().get_caller();
 
+    if let Some(ban) = read_state(|state| {
+        state
+            .get_model()
+            .get_deployer_bans_storage()
+            .find_active_deployer_ban(
+                &deployer,
+                env.get_time().get_current_unix_epoch_time_millis(),
+            )
+    }) {
+        return Err(DeployContractError::DeployerBanned { ban });
+    }
+
+    if beneficiary == Some(Principal::anonymous()) {
+        return Err(DeployContractError::InvalidBeneficiary);
+    }
+    let beneficiary = beneficiary.filter(|beneficiary| *beneficiary != deployer);
+    let owner = beneficiary.unwrap_or(deployer);
+
+    // the banned principal can not get the contract over a beneficiary
+    if let Some(ban) = beneficiary.and_then(|beneficiary| {
+        read_state(|state| {
+            state
+                .get_model()
+                .get_deployer_bans_storage()
+                .find_active_deployer_ban(
+                    &beneficiary,
+                    env.get_time().get_current_unix_epoch_time_millis(),
+                )
+        })
+    }) {
+        return Err(DeployContractError::BeneficiaryBanned { ban });
+    }
+
     // validate deployment
 
     let (
@@ -58,6 +110,9 @@ pub(crate) async fn deploy_contract_int(
         icp_xdr_conversion_rate_strategy,
         contract_activation_required,
         contract_initial_cycles,
+        publisher_fee,
+        voucher_discount,
+        sponsored_account,
     ) = read_state(|state| {
         let config = &state.get_model().get_config_storage().get_config();
 
@@ -75,10 +130,58 @@ pub(crate) async fn deploy_contract_int(
             return Err(DeployContractError::ContractTemplateBlocked);
         }
 
-        if contract.retired.is_some() {
-            return Err(DeployContractError::ContractTemplateRetired);
+        if contract.is_retired(env.get_time().get_current_unix_epoch_time_millis()) {
+            return Err(DeployContractError::ContractTemplateRetired {
+                replacement: contract.replacement,
+            });
+        }
+
+        if contract.review.status != ContractTemplateReviewStatus::Approved {
+            return Err(DeployContractError::ContractTemplateNotApproved);
         }
 
+        if !is_contract_template_deployable_by(&contract, &deployer) {
+            return Err(DeployContractError::ContractTemplateRestricted);
+        }
+
+        check_contract_template_supply(
+            state.get_model(),
+            &contract,
+            contract_template_id,
+            deployer,
+        )?;
+
+        check_deployment_rate_limits(
+            state.get_model(),
+            deployer,
+            contract_template_id,
+            env.get_time().get_current_unix_epoch_time_millis(),
+        )?;
+
+        let voucher_discount = voucher
+            .as_ref()
+            .map(|code| {
+                check_voucher_redemption(
+                    state.get_model(),
+                    code,
+                    &contract_template_id,
+                    env.get_time().get_current_unix_epoch_time_millis(),
+                )
+            })
+            .transpose()?;
+
+        let sponsored_account = sponsor
+            .map(|sponsor| {
+                check_sponsorship(
+                    state.get_model(),
+                    &sponsor,
+                    &deployer,
+                    &contract_template_id,
+                )
+            })
+            .transpose()?
+            .map(|sponsorship| sponsorship.settings.approved_account.clone());
+
         Ok((
             config.deployment_cycles_cost,
             config.deployment_expenses_amount_buffer_permyriad,
@@ -90,21 +193,33 @@ pub(crate) async fn deploy_contract_int(
                 .definition
                 .contract_canister_settings
                 .initial_cycles,
+            contract.definition.publisher_fee.clone(),
+            voucher_discount,
+            sponsored_account,
         ))
     })?;
 
-    // check active deployment
+    // the sponsor pays from own approved account
+
+    let payer = sponsor.unwrap_or(deployer);
+    let approved_account = sponsored_account.unwrap_or(approved_account);
 
-    if let Some(deployment) = find_active_deployment(&deployer, build_deployment_information) {
-        return Err(DeployContractError::ActiveDeploymentExists {
-            deployment: Box::new(deployment),
-        });
+    // check active deployment of the payer and of the owner
+
+    for principal in std::iter::once(deployer).chain(beneficiary) {
+        if let Some(deployment) = find_active_deployment(&principal, build_deployment_information) {
+            return Err(DeployContractError::ActiveDeploymentExists {
+                deployment: Box::new(deployment),
+            });
+        }
     }
 
     // get icp conversion rate
 
     let icp_conversation_rate =
-        get_icp_conversation_rate(env.as_ref(), icp_xdr_conversion_rate_strategy).await?;
+        get_icp_conversation_rate(env.as_ref(), icp_xdr_conversion_rate_strategy)
+            .await
+            .map_err(|reason| DeployContractError::GetIcpXdrConversionRateError { reason })?;
 
     // get deployment expenses calculator
 
@@ -114,6 +229,8 @@ pub(crate) async fn deploy_contract_int(
         amount_buffer_permyriad,
         amount_decimal_places,
         icp_conversation_rate,
+        publisher_fee,
+        voucher_discount,
     };
 
     let deployment_expenses_calculator =
@@ -121,14 +238,30 @@ pub(crate) async fn deploy_contract_int(
 
     // calculate expenses amount and check approved account
 
-    let expenses_amount = calculate_expenses_amount(
-        deployer,
+    let (expenses_amount, publisher_fee_amount, discount_amount) = calculate_expenses_amount(
+        payer,
         &deployment_expenses_calculator,
         &approved_account,
         allowance_expiration_timeout,
     )
     .await?;
 
+    let voucher_redemption = voucher
+        .map(|code| {
+            deployment_expenses_calculator
+                .get_deployment_expenses_amount()
+                .and_then(|amount| {
+                    deployment_expenses_calculator.get_subsidized_cycles(amount, discount_amount)
+                })
+                .map(|subsidized_cycles| VoucherRedemption {
+                    code,
+                    discount_amount,
+                    subsidized_cycles,
+                })
+                .map_err(|reason| DeployContractError::CalculateDeploymentExpensesError { reason })
+        })
+        .transpose()?;
+
     // generate activation code
 
     let activation_code = if contract_activation_required {
@@ -147,25 +280,95 @@ pub(crate) async fn deploy_contract_int(
 
     let deployment_id = mutate_state(|state| {
         let time = env.get_time().get_current_unix_epoch_time_millis();
-        Ok(state
-            .get_model_mut()
-            .get_deployments_storage_mut()
-            .create_new_deployment(
-                deployer,
-                time,
-                contract_template_id,
-                deployment_expenses.clone(),
+
+        // the voucher, the sponsorship, the rate limits and the supply could be used meanwhile,
+        // the active deployment could be started too
+
+        if let Some(active_deployment_id) = std::iter::once(deployer)
+            .chain(beneficiary)
+            .find_map(|principal| find_active_deployment_id(state.get_model(), &principal))
+        {
+            return Ok(Err(active_deployment_id));
+        }
+
+        check_deployment_rate_limits(state.get_model(), deployer, contract_template_id, time)?;
+
+        let contract = state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            .ok_or(DeployContractError::ContractTemplateNotFound)?;
+        check_contract_template_supply(
+            state.get_model(),
+            &contract,
+            contract_template_id,
+            deployer,
+        )?;
+
+        if let Some(redemption) = &voucher_redemption {
+            check_voucher_budget(state.get_model(), redemption, &contract_template_id, time)?;
+        }
+
+        if let Some(sponsor) = &sponsor {
+            let sponsorship =
+                check_sponsorship(state.get_model(), sponsor, &deployer, &contract_template_id)?;
+            if sponsorship.get_available_budget() < expenses_amount {
+                return Err(DeployContractError::SponsorshipBudgetExhausted);
+            }
+        }
+
+        let model = state.get_model_mut();
+
+        if let Some(redemption) = &voucher_redemption {
+            model
+                .get_vouchers_storage_mut()
+                .voucher_redeemed(&redemption.code, redemption.subsidized_cycles);
+        }
+
+        let deployment_id = model.get_deployments_storage_mut().create_new_deployment(
+            deployer,
+            time,
+            contract_template_id,
+            deployment_expenses.clone(),
+            expenses_amount,
+            publisher_fee_amount,
+            voucher_redemption.clone(),
+            sponsor,
+            beneficiary,
+            approved_account,
+            subnet_type,
+            activation_code,
+        );
+
+        register_deployment_start(model, deployer, contract_template_id, deployment_id, time);
+        model
+            .get_contract_templates_storage_mut()
+            .deployment_reserved(&contract_template_id);
+
+        if let Some(sponsor) = sponsor {
+            model.get_sponsors_storage_mut().deployment_sponsored(
+                sponsor,
+                deployment_id,
                 expenses_amount,
-                approved_account,
-                subnet_type,
-                activation_code,
-            ))
-    })?;
+            );
+        }
+
+        Ok(Ok(deployment_id))
+    })?
+    .map_err(
+        |active_deployment_id| DeployContractError::ActiveDeploymentExists {
+            deployment: Box::new(
+                build_deployment_information_with_load(&active_deployment_id).unwrap(),
+            ),
+        },
+    )?;
 
     log_info!(
         env,
-        "Deployment '{deployment_id}': created. Expenses: {deployment_expenses:?}, expenses amount: {expenses_amount:?}, deployer: {}.",
-        deployer.to_text()
+        "Deployment '{deployment_id}': created. Expenses: {deployment_expenses:?}, expenses amount: {expenses_amount:?}, publisher fee amount: {publisher_fee_amount:?}, voucher redemption: {voucher_redemption:?}, deployer: {}, payer: {}, owner: {}.",
+        deployer.to_text(),
+        payer.to_text(),
+        owner.to_text()
     );
 
     process_deployment(env.as_ref(), &deployment_id).await;
@@ -175,39 +378,35 @@ pub(crate) async fn deploy_contract_int(
     })
 }
 
-async fn get_icp_conversation_rate(
-    env: &Environment,
-    strategy: IcpXdrConversionRateStrategy,
-) -> Result<IcpConversationRate, DeployContractError> {
-    match strategy {
-        IcpXdrConversionRateStrategy::Fixed {
-            xdr_permyriad_per_icp,
-        } => Ok(IcpConversationRate::Fixed {
-            xdr_permyriad_per_icp,
-        }),
-        IcpXdrConversionRateStrategy::CMC { cmc_canister } => env
-            .get_cmc()
-            .get_icp_xdr_conversion_rate(cmc_canister)
-            .await
-            .map(|rate| IcpConversationRate::CMC {
-                xdr_permyriad_per_icp: rate.xdr_permyriad_per_icp,
-                timestamp_seconds: rate.timestamp_seconds,
-            })
-            .map_err(|reason| match reason {
-                CallWrapperError::CallError { reason } => {
-                    DeployContractError::GetIcpXdrConversionRateError { reason }
-                }
-                CallWrapperError::WrappedError { .. } => panic!(),
-            }),
+fn check_voucher_budget(
+    model: &DataModel,
+    redemption: &VoucherRedemption,
+    contract_template_id: &ContractTemplateId,
+    time: TimestampMillis,
+) -> Result<(), DeployContractError> {
+    check_voucher_redemption(model, &redemption.code, contract_template_id, time)?;
+
+    let budget = model
+        .get_config_storage()
+        .get_config()
+        .vouchers_cycles_budget;
+    if model
+        .get_vouchers_storage()
+        .get_subsidized_cycles()
+        .saturating_add(redemption.subsidized_cycles)
+        > budget
+    {
+        return Err(DeployContractError::VouchersCyclesBudgetExhausted);
     }
+    Ok(())
 }
 
 async fn calculate_expenses_amount(
-    deployer: Principal,
+    payer: Principal,
     deployment_expenses_calculator: &DeploymentExpensesCalculator,
     approved_account: &LedgerAccount,
     allowance_expiration_timeout: TimestampMillis,
-) -> Result<TokenE8s, DeployContractError> {
+) -> Result<(TokenE8s, TokenE8s, TokenE8s), DeployContractError> {
     let env = get_env();
 
     let approved_account_identifier = to_account_identifier(approved_account)
@@ -220,10 +419,36 @@ async fn calculate_expenses_amount(
         .get_deployment_expenses_amount()
         .map_err(|reason| DeployContractError::CalculateDeploymentExpensesError { reason })?;
 
+    // the voucher discount reduces the amount charged from the deployer
+
+    let discount_amount = deployment_expenses_calculator
+        .get_voucher_discount_amount(expenses_amount)
+        .map_err(|reason| DeployContractError::CalculateDeploymentExpensesError { reason })?;
+    let expenses_amount = expenses_amount - discount_amount;
+
+    // the publisher fee is not covered by the buffer, so it is required in full
+
+    let publisher_fee_amount = deployment_expenses_calculator
+        .get_publisher_fee_amount(expenses_amount)
+        .map_err(|reason| DeployContractError::CalculateDeploymentExpensesError { reason })?;
+
     let buffered_expenses_amount = deployment_expenses_calculator
         .get_reserved_deployment_expenses_amount(expenses_amount)
         .map_err(|reason| DeployContractError::CalculateDeploymentExpensesError { reason })?;
 
+    let (expenses_amount, buffered_expenses_amount) = expenses_amount
+        .checked_add(publisher_fee_amount)
+        .zip(buffered_expenses_amount.checked_add(publisher_fee_amount))
+        .ok_or_else(|| DeployContractError::CalculateDeploymentExpensesError {
+            reason: "failed to add publisher fee amount".to_owned(),
+        })?;
+
+    // nothing to charge for the free deployment
+
+    if expenses_amount == 0 {
+        return Ok((0, 0, discount_amount));
+    }
+
     let balance = env
         .get_ledger()
         .get_account_balance(approved_account_identifier)
@@ -240,7 +465,7 @@ async fn calculate_expenses_amount(
             account: approved_account_icrc1,
             spender: Account {
                 owner: env.get_ic().get_canister(),
-                subaccount: Some(principal_to_subaccount(deployer)),
+                subaccount: Some(principal_to_subaccount(payer)),
             },
         })
         .await
@@ -260,8 +485,9 @@ async fn calculate_expenses_amount(
         }
     }
 
-    Ok(min(
-        min(balance, allowance_amount),
-        buffered_expenses_amount,
+    Ok((
+        min(min(balance, allowance_amount), buffered_expenses_amount),
+        publisher_fee_amount,
+        discount_amount,
     ))
 }
@@ -0,0 +1,43 @@
+use crate::{
+    get_env,
+    handlers::governance::is_caller_governance_principal,
+    updates::add_contract_template::{
+        check_contract_template_addition, register_contract_template,
+    },
+};
+use hub_canister_api::{
+    add_contract_template::{AddContractTemplateError, AddContractTemplateResult},
+    execute_add_contract_template::*,
+    types::ContractTemplateDefinition,
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn execute_add_contract_template(
+    Args {
+        contract_template_definition,
+    }: Args,
+) -> Response {
+    if let Err(error) = execute_add_contract_template_int(contract_template_definition) {
+        ic_cdk::trap(&format!("Add contract template failed: {error:?}"));
+    }
+}
+
+/// The contract template is registered by the DAO with the admin rights.
+pub(crate) fn execute_add_contract_template_int(
+    contract_template_definition: ContractTemplateDefinition,
+) -> Result<AddContractTemplateResult, AddContractTemplateError> {
+    if !is_caller_governance_principal() {
+        return Err(AddContractTemplateError::PermissionDenied);
+    }
+
+    let caller = get_env().get_ic().get_caller();
+    let (wasm, wasm_metadata) =
+        check_contract_template_addition(&caller, true, &contract_template_definition)?;
+    Ok(register_contract_template(
+        caller,
+        contract_template_definition,
+        wasm,
+        wasm_metadata,
+    ))
+}
@@ -0,0 +1,36 @@
+use crate::{
+    get_env, handlers::governance::is_caller_governance_principal,
+    updates::block_contract_template::apply_contract_template_block,
+};
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    block_contract_template::BlockContractTemplateError, execute_block_contract_template::*,
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn execute_block_contract_template(
+    Args {
+        contract_template_id,
+        reason,
+    }: Args,
+) -> Response {
+    if let Err(error) = execute_block_contract_template_int(contract_template_id, reason) {
+        ic_cdk::trap(&format!("Block contract template failed: {error:?}"));
+    }
+}
+
+pub(crate) fn execute_block_contract_template_int(
+    contract_template_id: ContractTemplateId,
+    reason: String,
+) -> Result<(), BlockContractTemplateError> {
+    if !is_caller_governance_principal() {
+        return Err(BlockContractTemplateError::PermissionDenied);
+    }
+
+    apply_contract_template_block(
+        get_env().get_ic().get_caller(),
+        contract_template_id,
+        reason,
+    )
+}
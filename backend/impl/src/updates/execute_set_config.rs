@@ -0,0 +1,22 @@
+use crate::{
+    get_env, handlers::governance::is_caller_governance_principal,
+    updates::set_config::apply_config,
+};
+use hub_canister_api::{execute_set_config::*, set_config::SetConfigError, types::Config};
+use ic_cdk_macros::update;
+
+#[update]
+fn execute_set_config(Args { config }: Args) -> Response {
+    if let Err(error) = execute_set_config_int(config) {
+        ic_cdk::trap(&format!("Set config failed: {error:?}"));
+    }
+}
+
+/// The DAO changes the config regardless of the governance policies.
+pub(crate) fn execute_set_config_int(config: Config) -> Result<(), SetConfigError> {
+    if !is_caller_governance_principal() {
+        return Err(SetConfigError::PermissionDenied);
+    }
+
+    apply_config(get_env().get_ic().get_caller(), config)
+}
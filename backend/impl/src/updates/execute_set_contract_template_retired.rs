@@ -0,0 +1,49 @@
+use crate::{
+    get_env, handlers::governance::is_caller_governance_principal,
+    updates::set_contract_template_retired::apply_contract_template_retirement,
+};
+use common_canister_types::TimestampMillis;
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    execute_set_contract_template_retired::*,
+    set_contract_template_retired::SetContractTemplateRetiredError,
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn execute_set_contract_template_retired(
+    Args {
+        contract_template_id,
+        reason,
+        retire_at,
+        replacement,
+    }: Args,
+) -> Response {
+    if let Err(error) = execute_set_contract_template_retired_int(
+        contract_template_id,
+        reason,
+        retire_at,
+        replacement,
+    ) {
+        ic_cdk::trap(&format!("Set contract template retired failed: {error:?}"));
+    }
+}
+
+pub(crate) fn execute_set_contract_template_retired_int(
+    contract_template_id: ContractTemplateId,
+    reason: Option<String>,
+    retire_at: Option<TimestampMillis>,
+    replacement: Option<ContractTemplateId>,
+) -> Result<(), SetContractTemplateRetiredError> {
+    if !is_caller_governance_principal() {
+        return Err(SetContractTemplateRetiredError::PermissionDenied);
+    }
+
+    apply_contract_template_retirement(
+        get_env().get_ic().get_caller(),
+        contract_template_id,
+        reason,
+        retire_at,
+        replacement,
+    )
+}
@@ -35,7 +35,7 @@ pub(crate) async fn initialize_contract_certificate_int(
             .get_deployment(&deployment_id)
             .ok_or(InitializeContractCertificateError::DeploymentNotFound)?;
 
-        if deployment.deployer != env.get_ic().get_caller() {
+        if !deployment.is_deployer_or_beneficiary(&env.get_ic().get_caller()) {
             return Err(InitializeContractCertificateError::PermissionDenied);
         }
 
@@ -1,15 +1,36 @@
+pub mod accept_deployment_ownership_transfer;
 pub mod add_contract_template;
+pub mod approve_proposal;
 pub mod block_contract_template;
 pub mod block_contracts;
 pub mod cancel_deployment;
 pub mod deploy_contract;
+pub mod execute_add_contract_template;
+pub mod execute_block_contract_template;
+pub mod execute_set_config;
+pub mod execute_set_contract_template_retired;
 pub mod initialize_contract_certificate;
+pub mod patch_config;
 pub mod process_deployment;
+pub mod propose_deployment_ownership_transfer;
+pub mod propose_operation;
+pub mod renew_contract_certificate;
+pub mod resign_contract_certificate;
 pub mod retry_generate_contract_certificate;
+pub mod review_contract_template;
 pub mod set_access_rights;
 pub mod set_config;
+pub mod set_contract_template_metadata;
 pub mod set_contract_template_retired;
+pub mod set_contract_template_visibility;
+pub mod set_deployer_ban;
+pub mod set_publisher;
+pub mod set_role_assignment;
+pub mod set_roles;
+pub mod set_sponsorship;
 pub mod set_upload_wasm_grant;
+pub mod set_voucher;
+pub mod unblock_contracts;
 pub mod upload_wasm_chunk;
 pub mod validate_contract_certificate;
 
@@ -0,0 +1,76 @@
+use crate::{
+    get_env, handlers::governance::is_operation_governed, is_caller_has_access_right, read_state,
+    updates::set_config::apply_config,
+};
+use hub_canister_api::{
+    patch_config::*,
+    set_config::SetConfigError,
+    types::{ConfigField, ConfigVersion, GovernedOperationType, Permission},
+};
+use ic_cdk_macros::update;
+use std::mem::discriminant;
+
+#[update]
+fn patch_config(
+    Args {
+        config_version,
+        changes,
+    }: Args,
+) -> Response {
+    patch_config_int(config_version, changes).into()
+}
+
+/// The changes are rejected if the config was changed since the expected version,
+/// so the concurrent changes of the config are not overwritten.
+pub(crate) fn patch_config_int(
+    config_version: ConfigVersion,
+    changes: Vec<ConfigField>,
+) -> Result<PatchConfigResult, PatchConfigError> {
+    if !is_caller_has_access_right(&Permission::SetConfig) {
+        return Err(PatchConfigError::PermissionDenied);
+    }
+
+    if is_operation_governed(GovernedOperationType::SetConfig) {
+        return Err(PatchConfigError::ProposalRequired);
+    }
+
+    for (index, change) in changes.iter().enumerate() {
+        if changes[..index]
+            .iter()
+            .any(|other| discriminant(other) == discriminant(change))
+        {
+            return Err(PatchConfigError::WrongConfig {
+                reason: format!("duplicate config field change {change:?}"),
+            });
+        }
+    }
+
+    let (current_version, mut config) = read_state(|state| {
+        let config_storage = state.get_model().get_config_storage();
+        (
+            config_storage.get_config_version(),
+            config_storage.get_config().clone(),
+        )
+    });
+    if current_version != config_version {
+        return Err(PatchConfigError::ConfigVersionMismatch {
+            config_version: current_version,
+        });
+    }
+
+    for change in changes {
+        config.set_field(change);
+    }
+
+    apply_config(get_env().get_ic().get_caller(), config).map_err(|error| match error {
+        SetConfigError::PermissionDenied => PatchConfigError::PermissionDenied,
+        SetConfigError::WrongConfig { reason } => PatchConfigError::WrongConfig { reason },
+        SetConfigError::ProposalRequired => PatchConfigError::ProposalRequired,
+    })?;
+
+    Ok(PatchConfigResult {
+        config_version: read_state(|state| {
+            state.get_model().get_config_storage().get_config_version()
+        }),
+    })
+}
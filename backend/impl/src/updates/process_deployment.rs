@@ -23,7 +23,7 @@ pub(crate) async fn process_deployment_int(
             .get_deployment(&deployment_id)
             .ok_or(ProcessDeploymentError::DeploymentNotFound)?;
 
-        if deployment.deployer != env.get_ic().get_caller() {
+        if !deployment.is_deployer_or_beneficiary(&env.get_ic().get_caller()) {
             return Err(ProcessDeploymentError::PermissionDenied);
         }
 
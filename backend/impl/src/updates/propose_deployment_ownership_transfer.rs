@@ -0,0 +1,70 @@
+use crate::handlers::deployments::build_deployment_information_with_load;
+use crate::{get_env, log_info, mutate_state};
+use candid::Principal;
+use hub_canister_api::propose_deployment_ownership_transfer::*;
+use hub_canister_api::types::DeploymentId;
+use ic_cdk_macros::update;
+
+#[update]
+fn propose_deployment_ownership_transfer(
+    Args {
+        deployment_id,
+        new_owner,
+    }: Args,
+) -> Response {
+    propose_deployment_ownership_transfer_int(deployment_id, new_owner).into()
+}
+
+pub(crate) fn propose_deployment_ownership_transfer_int(
+    deployment_id: DeploymentId,
+    new_owner: Option<Principal>,
+) -> Result<ProposeDeploymentOwnershipTransferResult, ProposeDeploymentOwnershipTransferError> {
+    let env = get_env();
+    let caller = env.get_ic().get_caller();
+
+    mutate_state(|state| {
+        let deployment = state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .ok_or(ProposeDeploymentOwnershipTransferError::DeploymentNotFound)?;
+
+        let owner = deployment.get_owner();
+        if owner != caller {
+            return Err(ProposeDeploymentOwnershipTransferError::PermissionDenied);
+        }
+
+        if !deployment.is_successfully_finalized() {
+            return Err(ProposeDeploymentOwnershipTransferError::DeploymentWrongState);
+        }
+
+        if new_owner
+            .is_some_and(|new_owner| new_owner == Principal::anonymous() || new_owner == owner)
+        {
+            return Err(ProposeDeploymentOwnershipTransferError::InvalidNewOwner);
+        }
+
+        state
+            .get_model_mut()
+            .get_deployments_storage_mut()
+            .set_pending_owner(&deployment_id, new_owner);
+        Ok(())
+    })?;
+
+    if let Some(new_owner) = new_owner {
+        log_info!(
+            env,
+            "Deployment '{deployment_id}': ownership transfer to '{}' proposed.",
+            new_owner.to_text()
+        );
+    } else {
+        log_info!(
+            env,
+            "Deployment '{deployment_id}': ownership transfer proposal withdrawn."
+        );
+    }
+
+    Ok(ProposeDeploymentOwnershipTransferResult {
+        deployment: build_deployment_information_with_load(&deployment_id).unwrap(),
+    })
+}
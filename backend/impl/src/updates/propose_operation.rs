@@ -0,0 +1,79 @@
+use crate::{
+    get_env,
+    handlers::governance::{get_governance_policy, get_operation_permission},
+    is_caller_has_access_right, log_info, mutate_state,
+    updates::{set_config::validate_config, set_roles::check_roles},
+};
+use hub_canister_api::{
+    propose_operation::*,
+    types::{GovernedOperation, HubEventType, Proposal, ProposalStatus},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn propose_operation(Args { operation }: Args) -> Response {
+    propose_operation_int(operation).into()
+}
+
+pub(crate) fn propose_operation_int(
+    operation: GovernedOperation,
+) -> Result<ProposeOperationResult, ProposeOperationError> {
+    let operation_type = operation.get_operation_type();
+    if !is_caller_has_access_right(&get_operation_permission(operation_type)) {
+        return Err(ProposeOperationError::PermissionDenied);
+    }
+
+    let policy =
+        get_governance_policy(operation_type).ok_or(ProposeOperationError::OperationNotGoverned)?;
+
+    check_governed_operation(&operation)
+        .map_err(|reason| ProposeOperationError::InvalidOperation { reason })?;
+
+    let env = get_env();
+    let caller = env.get_ic().get_caller();
+    let time = env.get_time().get_current_unix_epoch_time_millis();
+
+    mutate_state(|state| {
+        let model = state.get_model_mut();
+        let proposal_id = model.get_proposals_storage_mut().add_proposal(Proposal {
+            proposer: caller,
+            operation: operation.clone(),
+            created: time,
+            expiration: time.saturating_add(policy.approval_window),
+            approvers: policy.approvers,
+            required_approvals: policy.required_approvals,
+            approvals: vec![],
+            status: ProposalStatus::Pending,
+        });
+
+        model.get_hub_events_storage_mut().add_hub_event(
+            time,
+            caller,
+            HubEventType::ProposalCreated {
+                proposal_id,
+                operation,
+            },
+        );
+
+        log_info!(
+            env,
+            "Proposal '{proposal_id}' of {operation_type:?} created by caller '{}'",
+            caller.to_text()
+        );
+
+        Ok(ProposeOperationResult { proposal_id })
+    })
+}
+
+/// Rejects the proposals, which can not be executed regardless of the hub state.
+fn check_governed_operation(operation: &GovernedOperation) -> Result<(), String> {
+    match operation {
+        GovernedOperation::SetConfig { config } => {
+            validate_config(config).map_err(|error| format!("{error:?}"))
+        }
+        GovernedOperation::SetRoles { roles } => check_roles(roles),
+        GovernedOperation::SetAccessRights { .. }
+        | GovernedOperation::SetRoleAssignment { .. }
+        | GovernedOperation::BlockContractTemplate { .. } => Ok(()),
+    }
+}
@@ -0,0 +1,353 @@
+use crate::components::Environment;
+use crate::handlers::deployments::expenses_calculator::DeploymentExpensesCalculator;
+use crate::handlers::deployments::states::generate_contract_certificate::reissue_contract_certificate;
+use crate::handlers::deployments::states::get_config;
+use crate::handlers::deployments::{
+    build_deployment_information_with_load, get_icp_conversation_rate,
+};
+use crate::state::CanisterState;
+use crate::{get_env, log_error, log_info, mutate_state, read_state};
+use candid::Principal;
+use common_canister_impl::components::icrc2_ledger::{to_icrc1_account, Account, TransferFromArgs};
+use common_canister_impl::components::ledger::to_account_identifier;
+use common_canister_types::{LedgerAccount, TokenE8s};
+use hub_canister_api::renew_contract_certificate::*;
+use hub_canister_api::types::{CertificateRenewal, DeploymentExpenses, DeploymentId};
+use ic_cdk::management_canister::{canister_info, CanisterInfoArgs};
+use ic_cdk_macros::update;
+use ic_ledger_types::{BlockIndex, Memo, DEFAULT_SUBACCOUNT};
+use icrc_ledger_types::icrc1::account::principal_to_subaccount;
+use icrc_ledger_types::icrc1::transfer::Memo as Icrc1Memo;
+use num_traits::ToPrimitive;
+use serde_bytes::ByteBuf;
+
+/// The certificate is renewable within the last tenth of the certificate duration.
+const CERTIFICATE_RENEWAL_WINDOW_DIVISOR: u64 = 10;
+
+#[update]
+async fn renew_contract_certificate(
+    Args {
+        deployment_id,
+        approved_account,
+    }: Args,
+) -> Response {
+    renew_contract_certificate_int(deployment_id, approved_account)
+        .await
+        .into()
+}
+
+pub(crate) async fn renew_contract_certificate_int(
+    deployment_id: DeploymentId,
+    approved_account: Option<LedgerAccount>,
+) -> Result<RenewContractCertificateResult, RenewContractCertificateError> {
+    let env = get_env();
+    let caller = env.get_ic().get_caller();
+
+    let (contract_canister, wasm_hash) =
+        read_state(|state| check_certificate_renewal(state, &caller, &deployment_id))?;
+
+    // the contract controls itself, so its module hash is read from the canister info
+
+    let contract_info = canister_info(&CanisterInfoArgs {
+        canister_id: contract_canister,
+        num_requested_changes: None,
+    })
+    .await
+    .map_err(|_| RenewContractCertificateError::ContractInfoUnavailable)?;
+
+    if !contract_info
+        .module_hash
+        .is_some_and(|hash| hex::encode(hash) == wasm_hash)
+    {
+        return Err(RenewContractCertificateError::ContractWasmHashChanged);
+    }
+
+    let (expenses_amount, block_index) = charge_certificate_renewal(
+        env.as_ref(),
+        &caller,
+        &deployment_id,
+        approved_account.clone(),
+    )
+    .await?;
+
+    let renewal = complete_certificate_renewal(
+        env.as_ref(),
+        &deployment_id,
+        caller,
+        approved_account,
+        expenses_amount,
+        block_index,
+    )
+    .await?;
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': certificate renewed by '{}': {renewal:?}",
+        caller.to_text()
+    );
+
+    Ok(RenewContractCertificateResult {
+        deployment: build_deployment_information_with_load(&deployment_id).unwrap(),
+    })
+}
+
+/// Returns the contract canister and the wasm hash it must run to get the renewal.
+/// The certificate can be renewed only within the renewal window before its expiration.
+pub(crate) fn check_certificate_renewal(
+    state: &CanisterState,
+    caller: &Principal,
+    deployment_id: &DeploymentId,
+) -> Result<(Principal, String), RenewContractCertificateError> {
+    let deployment = state
+        .get_model()
+        .get_deployments_storage()
+        .get_deployment(deployment_id)
+        .ok_or(RenewContractCertificateError::DeploymentNotFound)?;
+
+    if deployment.get_owner() != *caller && deployment.contract_canister.as_ref() != Some(caller) {
+        return Err(RenewContractCertificateError::PermissionDenied);
+    }
+
+    if !deployment.is_successfully_finalized() {
+        return Err(RenewContractCertificateError::DeploymentWrongState);
+    }
+
+    if let Some(blocked) = state
+        .get_model()
+        .get_blocked_contracts_storage()
+        .find_deployment_block(deployment_id)
+    {
+        return Err(RenewContractCertificateError::ContractBlocked {
+            reason: blocked.value,
+        });
+    }
+
+    let contract_template = state
+        .get_model()
+        .get_contract_templates_storage()
+        .get_contract_template(&deployment.contract_template_id)
+        .unwrap();
+
+    if contract_template.blocked.is_some() {
+        return Err(RenewContractCertificateError::ContractTemplateBlocked);
+    }
+
+    let certificate_duration = contract_template.definition.certificate_duration;
+    let renewable_from = deployment
+        .get_certificate_expiration(certificate_duration)
+        .saturating_sub(certificate_duration / CERTIFICATE_RENEWAL_WINDOW_DIVISOR);
+    if state
+        .get_env()
+        .get_time()
+        .get_current_unix_epoch_time_millis()
+        < renewable_from
+    {
+        return Err(RenewContractCertificateError::CertificateRenewalTooEarly { renewable_from });
+    }
+
+    Ok((
+        deployment.contract_canister.unwrap(),
+        contract_template.definition.wasm_hash.clone(),
+    ))
+}
+
+/// The renewal cost is converted with the deployment expenses calculator
+/// and transferred from the approved account to the hub account.
+pub(crate) async fn charge_certificate_renewal(
+    env: &Environment,
+    payer: &Principal,
+    deployment_id: &DeploymentId,
+    approved_account: Option<LedgerAccount>,
+) -> Result<(TokenE8s, Option<u64>), RenewContractCertificateError> {
+    let (certificate_renewal_cycles_cost, amount_decimal_places, icp_xdr_conversion_rate_strategy) =
+        get_config(|_, config| {
+            (
+                config.certificate_renewal_cycles_cost,
+                config.deployment_expenses_amount_decimal_places,
+                config.icp_xdr_conversion_rate_strategy.clone(),
+            )
+        });
+
+    if certificate_renewal_cycles_cost == 0 {
+        return Ok((0, None));
+    }
+
+    let approved_account =
+        approved_account.ok_or_else(|| RenewContractCertificateError::InvalidApprovedAccount {
+            reason: "approved account is required".to_owned(),
+        })?;
+    let approved_account_icrc1 = to_icrc1_account(&approved_account)
+        .map_err(|reason| RenewContractCertificateError::InvalidApprovedAccount { reason })?;
+
+    let icp_conversation_rate = get_icp_conversation_rate(env, icp_xdr_conversion_rate_strategy)
+        .await
+        .map_err(|reason| RenewContractCertificateError::GetIcpXdrConversionRateError { reason })?;
+
+    let calculator = DeploymentExpensesCalculator::new(DeploymentExpenses {
+        deployment_cycles_cost: certificate_renewal_cycles_cost,
+        contract_initial_cycles: 0,
+        amount_buffer_permyriad: 0,
+        amount_decimal_places,
+        icp_conversation_rate,
+        publisher_fee: None,
+        voucher_discount: None,
+    });
+
+    let expenses_amount = calculator
+        .get_deployment_expenses_amount()
+        .and_then(|amount| calculator.get_reserved_deployment_expenses_amount(amount))
+        .map_err(
+            |reason| RenewContractCertificateError::CalculateRenewalExpensesError { reason },
+        )?;
+
+    let block_index = env
+        .get_icrc2_ledger()
+        .icrc2_transfer_from(TransferFromArgs {
+            spender_subaccount: Some(principal_to_subaccount(*payer)),
+            from: approved_account_icrc1,
+            to: Account {
+                owner: env.get_ic().get_canister(),
+                subaccount: None,
+            },
+            amount: expenses_amount.into(),
+            fee: None,
+            memo: Some(Icrc1Memo(ByteBuf::from(
+                deployment_id.to_be_bytes().to_vec(),
+            ))),
+            created_at_time: None,
+        })
+        .await
+        .map_err(|error| RenewContractCertificateError::LedgerUnavailable {
+            reason: format!("{error:?}"),
+        })?
+        .map_err(
+            |error| RenewContractCertificateError::RenewalPaymentFailed {
+                reason: format!("{error:?}"),
+            },
+        )?;
+
+    Ok((expenses_amount, block_index.0.to_u64()))
+}
+
+/// Renews the paid certificate. The renewal is checked again after the payment,
+/// so the payment is refunded to the approved account if the renewal is rejected.
+pub(crate) async fn complete_certificate_renewal(
+    env: &Environment,
+    deployment_id: &DeploymentId,
+    renewed_by: Principal,
+    approved_account: Option<LedgerAccount>,
+    expenses_amount: TokenE8s,
+    block_index: Option<u64>,
+) -> Result<CertificateRenewal, RenewContractCertificateError> {
+    let error =
+        match renew_certificate(env, deployment_id, renewed_by, expenses_amount, block_index) {
+            Ok(renewal) => return Ok(renewal),
+            Err(error) => error,
+        };
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': certificate renewal paid by '{}' (amount: {expenses_amount}, block index: {block_index:?}) rejected: {error:?}",
+        renewed_by.to_text()
+    );
+
+    if let Some(approved_account) = approved_account.filter(|_| expenses_amount > 0) {
+        match refund_certificate_renewal(env, deployment_id, &approved_account, expenses_amount)
+            .await
+        {
+            Ok(refund_block_index) => log_info!(
+                env,
+                "Deployment '{deployment_id}': certificate renewal payment refunded at block index: {refund_block_index:?}."
+            ),
+            Err(reason) => log_error!(
+                env,
+                "Deployment '{deployment_id}': certificate renewal payment refund failed: {reason}"
+            ),
+        }
+    }
+
+    Err(error)
+}
+
+/// Returns the renewal payment less the ledger fee from the hub account.
+async fn refund_certificate_renewal(
+    env: &Environment,
+    deployment_id: &DeploymentId,
+    approved_account: &LedgerAccount,
+    expenses_amount: TokenE8s,
+) -> Result<Option<BlockIndex>, String> {
+    let ledger = env.get_ledger();
+    let ledger_fee = ledger.get_ledger_fee().await?;
+
+    if expenses_amount <= ledger_fee {
+        return Ok(None);
+    }
+
+    let block_index = ledger
+        .transfer_from_canister(
+            Memo(*deployment_id),
+            DEFAULT_SUBACCOUNT,
+            to_account_identifier(approved_account)?,
+            expenses_amount - ledger_fee,
+            ledger_fee,
+            None,
+        )
+        .await?
+        .map_err(|error| format!("{error:?}"))?;
+
+    Ok(Some(block_index))
+}
+
+/// Extends the certificate expiration by the certificate duration of the contract
+/// template and signs the certificate naming the new expiration. The renewal is
+/// checked again, the contract could be blocked or renewed during the payment.
+pub(crate) fn renew_certificate(
+    env: &Environment,
+    deployment_id: &DeploymentId,
+    renewed_by: Principal,
+    expenses_amount: TokenE8s,
+    block_index: Option<u64>,
+) -> Result<CertificateRenewal, RenewContractCertificateError> {
+    let renewal = mutate_state(|state| {
+        check_certificate_renewal(state, &renewed_by, deployment_id)?;
+
+        let deployment = state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(deployment_id)
+            .unwrap();
+
+        let certificate_duration = state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template(&deployment.contract_template_id)
+            .unwrap()
+            .definition
+            .certificate_duration;
+
+        // the expired certificate is renewed from now on
+        let renewed = env.get_time().get_current_unix_epoch_time_millis();
+        let expiration = deployment
+            .get_certificate_expiration(certificate_duration)
+            .max(renewed)
+            .saturating_add(certificate_duration);
+
+        let renewal = CertificateRenewal {
+            renewed,
+            renewed_by,
+            expiration,
+            expenses_amount,
+            block_index,
+        };
+
+        state
+            .get_model_mut()
+            .get_deployments_storage_mut()
+            .add_certificate_renewal(deployment_id, renewal.clone());
+        Ok(renewal)
+    })?;
+
+    reissue_contract_certificate(env, deployment_id);
+
+    Ok(renewal)
+}
@@ -0,0 +1,44 @@
+use crate::handlers::deployments::states::generate_contract_certificate::reissue_contract_certificate;
+use crate::{get_env, log_info, read_state};
+use hub_canister_api::resign_contract_certificate::*;
+use hub_canister_api::types::DeploymentId;
+use ic_cdk_macros::update;
+
+#[update]
+fn resign_contract_certificate(Args { deployment_id }: Args) -> Response {
+    resign_contract_certificate_int(deployment_id).into()
+}
+
+/// The signatures are kept in the heap only, so the reissued certificate is signed
+/// again on demand, e.g. after the hub upgrade, to be obtained by the query call.
+pub(crate) fn resign_contract_certificate_int(
+    deployment_id: DeploymentId,
+) -> Result<(), ResignContractCertificateError> {
+    let env = get_env();
+    let caller = env.get_ic().get_caller();
+
+    read_state(|state| {
+        let deployment = state
+            .get_model()
+            .get_deployments_storage()
+            .get_deployment(&deployment_id)
+            .ok_or(ResignContractCertificateError::DeploymentNotFound)?;
+
+        if deployment.get_owner() != caller && deployment.contract_canister != Some(caller) {
+            return Err(ResignContractCertificateError::PermissionDenied);
+        }
+
+        if !deployment.has_reissued_certificate() {
+            return Err(ResignContractCertificateError::DeploymentWrongState);
+        }
+        Ok(())
+    })?;
+
+    reissue_contract_certificate(&env, &deployment_id);
+
+    log_info!(
+        env,
+        "Deployment '{deployment_id}': reissued certificate signed again."
+    );
+    Ok(())
+}
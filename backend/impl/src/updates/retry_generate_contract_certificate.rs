@@ -58,7 +58,7 @@ fn validate_permission(
             .get_deployment(deployment_id)
             .ok_or(RetryGenerateContractCertificateError::DeploymentNotFound)?;
 
-        if deployment.deployer != env.get_ic().get_caller() {
+        if !deployment.is_deployer_or_beneficiary(&env.get_ic().get_caller()) {
             return Err(RetryGenerateContractCertificateError::PermissionDenied);
         }
 
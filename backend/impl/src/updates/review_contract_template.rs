@@ -0,0 +1,155 @@
+use crate::{is_caller_has_access_right, log_info, mutate_state};
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    review_contract_template::*,
+    types::{
+        ContractTemplateReview, ContractTemplateReviewNote, ContractTemplateReviewStatus,
+        HubEventType, Permission, ReproducibleBuildAttestation,
+    },
+};
+use ic_cdk_macros::update;
+
+const MAX_REVIEW_TEXT_LENGTH: usize = 2000;
+
+#[update]
+fn review_contract_template(
+    Args {
+        contract_template_id,
+        action,
+    }: Args,
+) -> Response {
+    review_contract_template_int(contract_template_id, action).into()
+}
+
+pub(crate) fn review_contract_template_int(
+    contract_template_id: ContractTemplateId,
+    action: ContractTemplateReviewAction,
+) -> Result<(), ReviewContractTemplateError> {
+    if !is_caller_has_access_right(&Permission::ReviewContractTemplate) {
+        return Err(ReviewContractTemplateError::PermissionDenied);
+    }
+
+    mutate_state(|state| {
+        let contract_template = state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            .ok_or(ReviewContractTemplateError::ContractTemplateNotFound)?;
+
+        let env = state.get_env();
+        let time = env.get_time().get_current_unix_epoch_time_millis();
+        let reviewer = env.get_ic().get_caller();
+
+        let mut review = contract_template.review.clone();
+        let status = match action {
+            ContractTemplateReviewAction::StartReview => {
+                // approved or rejected template can be reviewed again
+                check_review_status(
+                    &review,
+                    &[
+                        ContractTemplateReviewStatus::Submitted,
+                        ContractTemplateReviewStatus::Approved,
+                        ContractTemplateReviewStatus::Rejected,
+                    ],
+                )?;
+                review.rejection_reason = None;
+                Some(ContractTemplateReviewStatus::UnderReview)
+            }
+            ContractTemplateReviewAction::Approve => {
+                check_review_status(&review, &[ContractTemplateReviewStatus::UnderReview])?;
+                Some(ContractTemplateReviewStatus::Approved)
+            }
+            ContractTemplateReviewAction::Reject { reason } => {
+                check_review_status(&review, &[ContractTemplateReviewStatus::UnderReview])?;
+                check_review_text(&reason)?;
+                review.rejection_reason = Some(reason);
+                Some(ContractTemplateReviewStatus::Rejected)
+            }
+            ContractTemplateReviewAction::AddNote { note } => {
+                check_review_text(&note)?;
+                review.notes.push(ContractTemplateReviewNote {
+                    reviewer,
+                    time,
+                    note,
+                });
+                None
+            }
+            ContractTemplateReviewAction::AddBuildAttestation {
+                wasm_hash,
+                build_url,
+                description,
+            } => {
+                if wasm_hash != contract_template.definition.wasm_hash {
+                    return Err(ReviewContractTemplateError::AttestationWasmHashMismatch);
+                }
+                check_review_text(&build_url)?;
+                if let Some(description) = description.as_ref() {
+                    check_review_text(description)?;
+                }
+                review.attestations.push(ReproducibleBuildAttestation {
+                    reviewer,
+                    time,
+                    wasm_hash,
+                    build_url,
+                    description,
+                });
+                None
+            }
+        };
+
+        if let Some(status) = status.as_ref() {
+            review.status = status.clone();
+            review.updated = time;
+            review.reviewer = Some(reviewer);
+        }
+
+        let model = state.get_model_mut();
+        model
+            .get_contract_templates_storage_mut()
+            .set_review(&contract_template_id, review);
+
+        if let Some(status) = status {
+            log_info!(
+                env,
+                "Contract template '{contract_template_id}' review status set to '{status:?}' by caller '{}'",
+                reviewer.to_text()
+            );
+
+            model.get_hub_events_storage_mut().add_hub_event(
+                time,
+                reviewer,
+                HubEventType::ContractTemplateReviewStatusSet {
+                    contract_template_id,
+                    status,
+                },
+            );
+        }
+
+        Ok(())
+    })
+}
+
+fn check_review_status(
+    review: &ContractTemplateReview,
+    expected: &[ContractTemplateReviewStatus],
+) -> Result<(), ReviewContractTemplateError> {
+    if expected.contains(&review.status) {
+        Ok(())
+    } else {
+        Err(ReviewContractTemplateError::InvalidReviewStatus {
+            status: review.status.clone(),
+        })
+    }
+}
+
+fn check_review_text(text: &str) -> Result<(), ReviewContractTemplateError> {
+    if text.trim().is_empty() {
+        return Err(ReviewContractTemplateError::ReviewTextIsEmpty);
+    }
+    if text.len() > MAX_REVIEW_TEXT_LENGTH {
+        return Err(ReviewContractTemplateError::ReviewTextIsTooLong {
+            max_length: MAX_REVIEW_TEXT_LENGTH,
+        });
+    }
+    Ok(())
+}
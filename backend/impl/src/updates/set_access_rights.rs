@@ -1,7 +1,9 @@
+use crate::handlers::governance::is_operation_governed;
 use crate::is_caller_has_access_right;
-use crate::model::access_rights::{check_is_access_right, AccessRights};
-use crate::{get_env, log_info, mutate_state};
-use hub_canister_api::types::{AccessRight, Permission};
+use crate::model::access_rights::{AccessControl, AccessRights};
+use crate::{get_env, log_info, mutate_state, read_state};
+use candid::Principal;
+use hub_canister_api::types::{AccessRight, GovernedOperationType, Permission};
 use hub_canister_api::{set_access_rights::*, types::HubEventType};
 use ic_cdk_macros::update;
 
@@ -17,6 +19,18 @@ pub(crate) fn set_access_rights_int(
         return Err(SetAccessRightsError::PermissionDenied);
     }
 
+    if is_operation_governed(GovernedOperationType::SetAccessRights) {
+        return Err(SetAccessRightsError::ProposalRequired);
+    }
+
+    apply_access_rights(get_env().get_ic().get_caller(), access_rights)
+}
+
+/// Replaces the access rights, unless the caller loses the control over them.
+pub(crate) fn apply_access_rights(
+    caller: Principal,
+    access_rights: Vec<AccessRight>,
+) -> Result<(), SetAccessRightsError> {
     let mut new_access_rights = AccessRights::default();
     for access_right in &access_rights {
         new_access_rights.insert(
@@ -29,27 +43,36 @@ pub(crate) fn set_access_rights_int(
     }
 
     let env = get_env();
-    let caller = env.get_ic().get_caller();
+    let time = env.get_time().get_current_unix_epoch_time_millis();
+
+    let access_control = read_state(|state| AccessControl {
+        access_rights: new_access_rights,
+        ..state
+            .get_model()
+            .get_access_rights_storage()
+            .get_access_control()
+            .clone()
+    });
 
-    if !check_is_access_right(&new_access_rights, &caller, &Permission::SetAccessRights) {
+    if !access_control.is_access_right(&caller, &Permission::SetAccessRights, time) {
         return Err(SetAccessRightsError::LoseControlDangerous);
     }
 
     log_info!(
         env,
         "New access rights received for apply: {:?}",
-        new_access_rights
+        access_control.access_rights
     );
 
     mutate_state(|state| {
         let model = state.get_model_mut();
         model
             .get_access_rights_storage_mut()
-            .set_access_rights(new_access_rights);
+            .set_access_control(access_control);
 
         model.get_hub_events_storage_mut().add_hub_event(
-            env.get_time().get_current_unix_epoch_time_millis(),
-            env.get_ic().get_caller(),
+            time,
+            caller,
             HubEventType::AccessRightsSet { access_rights },
         );
         Ok(())
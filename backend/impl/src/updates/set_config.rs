@@ -1,16 +1,24 @@
 use crate::{
-    get_env, is_caller_has_access_right, log_info, mutate_state,
+    get_env,
+    handlers::{
+        governance::{check_governance_policies, is_operation_governed},
+        rate_limits::check_deployment_rate_limit,
+    },
+    is_caller_has_access_right, log_info, mutate_state,
     updates::validate_contract_certificate::parse_canister_from_url_by_regexs,
 };
 use candid::Principal;
+use common_canister_impl::components::ledger::to_account_identifier;
 use hub_canister_api::{
     set_config::*,
-    types::{Config, HubEventType, Permission},
+    types::{Config, DeploymentBeneficiary, GovernedOperationType, HubEventType, Permission},
 };
 use ic_cdk_macros::update;
 use ic_ledger_types::AccountIdentifier;
 use regex::Regex;
 
+const MAX_DEPLOYMENT_BENEFICIARIES: usize = 10;
+
 #[update]
 fn set_config(Args { config }: Args) -> Response {
     set_config_int(config).into()
@@ -21,6 +29,16 @@ pub(crate) fn set_config_int(config: Config) -> Result<(), SetConfigError> {
         return Err(SetConfigError::PermissionDenied);
     }
 
+    if is_operation_governed(GovernedOperationType::SetConfig) {
+        return Err(SetConfigError::ProposalRequired);
+    }
+
+    apply_config(get_env().get_ic().get_caller(), config)
+}
+
+/// Validates and applies the config on behalf of the caller, the hub event records
+/// the changed fields only.
+pub(crate) fn apply_config(caller: Principal, config: Config) -> Result<(), SetConfigError> {
     let env = get_env();
     validate_config(&config)?;
 
@@ -28,20 +46,28 @@ pub(crate) fn set_config_int(config: Config) -> Result<(), SetConfigError> {
 
     mutate_state(|state| {
         let model = state.get_model_mut();
-        model.get_config_storage_mut().set_config(config.clone());
+        let current_config = model.get_config_storage().get_config();
+        let changes = current_config.get_changed_fields(&config);
+        let previous = changes
+            .iter()
+            .map(|field| current_config.get_field(field))
+            .collect();
+        let config_version = model.get_config_storage_mut().set_config(config);
 
         model.get_hub_events_storage_mut().add_hub_event(
             env.get_time().get_current_unix_epoch_time_millis(),
-            env.get_ic().get_caller(),
-            HubEventType::ConfigSet {
-                config: Box::new(config),
+            caller,
+            HubEventType::ConfigPatched {
+                config_version,
+                changes,
+                previous,
             },
         );
         Ok(())
     })
 }
 
-fn validate_config(config: &Config) -> Result<(), SetConfigError> {
+pub(crate) fn validate_config(config: &Config) -> Result<(), SetConfigError> {
     let regexes = &config.regex_for_contract_principal_parsing;
     validate_regexes(regexes)?;
     validate_contract_url_pattern(regexes, &config.contract_url_pattern)?;
@@ -52,6 +78,71 @@ fn validate_config(config: &Config) -> Result<(), SetConfigError> {
         }
     })?;
 
+    validate_deployment_beneficiaries(&config.deployment_beneficiaries)?;
+
+    check_governance_policies(&config.governance_policies)
+        .map_err(|reason| SetConfigError::WrongConfig { reason })?;
+
+    if config.governance_principal == Some(Principal::anonymous()) {
+        return Err(SetConfigError::WrongConfig {
+            reason: "governance_principal can not be anonymous".to_owned(),
+        });
+    }
+
+    let rate_limits = &config.deployment_rate_limits;
+    for limit in [
+        &rate_limits.per_deployer,
+        &rate_limits.per_contract_template,
+        &rate_limits.hub,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        check_deployment_rate_limit(limit)
+            .map_err(|reason| SetConfigError::WrongConfig { reason })?;
+    }
+
+    Ok(())
+}
+
+fn validate_deployment_beneficiaries(
+    beneficiaries: &[DeploymentBeneficiary],
+) -> Result<(), SetConfigError> {
+    if beneficiaries.is_empty() {
+        return Ok(());
+    }
+
+    if beneficiaries.len() > MAX_DEPLOYMENT_BENEFICIARIES {
+        return Err(SetConfigError::WrongConfig {
+            reason: format!(
+                "deployment_beneficiaries count exceeds {MAX_DEPLOYMENT_BENEFICIARIES}"
+            ),
+        });
+    }
+
+    let mut total_share_permyriad: u64 = 0;
+    for beneficiary in beneficiaries {
+        to_account_identifier(&beneficiary.account).map_err(|error| {
+            SetConfigError::WrongConfig {
+                reason: format!("deployment beneficiary account is wrong: {error}"),
+            }
+        })?;
+
+        if beneficiary.share_permyriad == 0 {
+            return Err(SetConfigError::WrongConfig {
+                reason: "deployment beneficiary share must be positive".to_owned(),
+            });
+        }
+        total_share_permyriad = total_share_permyriad.saturating_add(beneficiary.share_permyriad);
+    }
+
+    if total_share_permyriad != 10_000 {
+        return Err(SetConfigError::WrongConfig {
+            reason: format!(
+                "deployment beneficiary shares sum is {total_share_permyriad}, expected 10000"
+            ),
+        });
+    }
     Ok(())
 }
 
@@ -0,0 +1,118 @@
+use crate::{
+    handlers::{
+        publishers::is_caller_contract_template_publisher,
+        templates::check_contract_template_labels,
+    },
+    is_caller_has_access_right, log_info, mutate_state,
+};
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    set_contract_template_metadata::*,
+    types::{ContractTemplateMetadata, HubEventType, Permission},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn set_contract_template_metadata(
+    Args {
+        contract_template_id,
+        metadata,
+    }: Args,
+) -> Response {
+    set_contract_template_metadata_int(contract_template_id, metadata).into()
+}
+
+pub(crate) fn set_contract_template_metadata_int(
+    contract_template_id: ContractTemplateId,
+    metadata: ContractTemplateMetadata,
+) -> Result<(), SetContractTemplateMetadataError> {
+    if !is_caller_has_access_right(&Permission::SetContractTemplateMetadata)
+        && !is_caller_contract_template_publisher(&contract_template_id)
+    {
+        return Err(SetContractTemplateMetadataError::PermissionDenied);
+    }
+
+    mutate_state(|state| {
+        let contract_template = state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            .ok_or(SetContractTemplateMetadataError::ContractTemplateNotFound)?;
+
+        let config = state.get_model().get_config_storage().get_config();
+
+        // check field restrictions
+        if metadata.name.len() > config.name_max_length {
+            return Err(SetContractTemplateMetadataError::ContractNameIsTooLong {
+                max_length: config.name_max_length,
+            });
+        }
+        if metadata.short_description.len() > config.short_description_max_length {
+            return Err(
+                SetContractTemplateMetadataError::ContractShortDescriptionIsTooLong {
+                    max_length: config.short_description_max_length,
+                },
+            );
+        }
+        if metadata
+            .long_description
+            .as_ref()
+            .filter(|desc| desc.len() > config.long_description_max_length)
+            .is_some()
+        {
+            return Err(
+                SetContractTemplateMetadataError::ContractLongDescriptionIsTooLong {
+                    max_length: config.long_description_max_length,
+                },
+            );
+        }
+        check_contract_template_labels(
+            metadata.categories.as_deref().unwrap_or_default(),
+            |max_count| SetContractTemplateMetadataError::TooManyContractCategories { max_count },
+            |category| SetContractTemplateMetadataError::InvalidContractCategory { category },
+        )?;
+        check_contract_template_labels(
+            metadata.tags.as_deref().unwrap_or_default(),
+            |max_count| SetContractTemplateMetadataError::TooManyContractTags { max_count },
+            |tag| SetContractTemplateMetadataError::InvalidContractTag { tag },
+        )?;
+
+        // check contract template name uniqueness
+        if state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_iter()
+            .any(|entry| {
+                *entry.key() != contract_template_id
+                    && entry.value().definition.name == metadata.name
+            })
+        {
+            return Err(SetContractTemplateMetadataError::ContractTemplateNameAlreadyExists);
+        }
+
+        let previous_metadata = contract_template.definition.get_metadata();
+
+        let env = state.get_env();
+        let model = state.get_model_mut();
+        model
+            .get_contract_templates_storage_mut()
+            .set_metadata(&contract_template_id, metadata);
+
+        model.get_hub_events_storage_mut().add_hub_event(
+            env.get_time().get_current_unix_epoch_time_millis(),
+            env.get_ic().get_caller(),
+            HubEventType::ContractTemplateMetadataSet {
+                contract_template_id,
+                previous_metadata: Box::new(previous_metadata),
+            },
+        );
+
+        log_info!(
+            env,
+            "Contract template '{contract_template_id}' metadata set by caller '{}'",
+            env.get_ic().get_caller().to_text()
+        );
+
+        Ok(())
+    })
+}
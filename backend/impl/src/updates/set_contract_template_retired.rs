@@ -1,5 +1,9 @@
-use crate::{is_caller_has_access_right, log_info, mutate_state};
-use common_canister_types::Timestamped;
+use crate::{
+    get_env, handlers::publishers::is_caller_contract_template_publisher,
+    is_caller_has_access_right, log_info, model::DataModel, mutate_state,
+};
+use candid::Principal;
+use common_canister_types::{TimestampMillis, Timestamped};
 use common_contract_api::ContractTemplateId;
 use hub_canister_api::{
     set_contract_template_retired::*,
@@ -12,52 +16,133 @@ fn set_contract_template_retired(
     Args {
         contract_template_id,
         reason,
+        retire_at,
+        replacement,
     }: Args,
 ) -> Response {
-    set_contract_template_retired_int(contract_template_id, reason).into()
+    set_contract_template_retired_int(contract_template_id, reason, retire_at, replacement).into()
 }
 
 pub(crate) fn set_contract_template_retired_int(
     contract_template_id: ContractTemplateId,
     reason: Option<String>,
+    retire_at: Option<TimestampMillis>,
+    replacement: Option<ContractTemplateId>,
 ) -> Result<(), SetContractTemplateRetiredError> {
-    if !is_caller_has_access_right(&Permission::RetireContractTemplate) {
+    if !is_caller_has_access_right(&Permission::RetireContractTemplate)
+        && !is_caller_contract_template_publisher(&contract_template_id)
+    {
         return Err(SetContractTemplateRetiredError::PermissionDenied);
     }
 
+    apply_contract_template_retirement(
+        get_env().get_ic().get_caller(),
+        contract_template_id,
+        reason,
+        retire_at,
+        replacement,
+    )
+}
+
+pub(crate) fn check_contract_template_retirement(
+    model: &DataModel,
+    contract_template_id: &ContractTemplateId,
+    reason: &Option<String>,
+    replacement: &Option<ContractTemplateId>,
+) -> Result<(), SetContractTemplateRetiredError> {
+    model
+        .get_contract_templates_storage()
+        .get_contract_template(contract_template_id)
+        .ok_or(SetContractTemplateRetiredError::ContractTemplateNotFound)?;
+
+    if let Some(replacement) = replacement {
+        if reason.is_none() {
+            return Err(SetContractTemplateRetiredError::InvalidReplacement {
+                reason: "replacement requires retirement".to_owned(),
+            });
+        }
+        check_replacement(model, contract_template_id, replacement)
+            .map_err(|reason| SetContractTemplateRetiredError::InvalidReplacement { reason })?;
+    }
+    Ok(())
+}
+
+pub(crate) fn apply_contract_template_retirement(
+    caller: Principal,
+    contract_template_id: ContractTemplateId,
+    reason: Option<String>,
+    retire_at: Option<TimestampMillis>,
+    replacement: Option<ContractTemplateId>,
+) -> Result<(), SetContractTemplateRetiredError> {
     mutate_state(|state| {
-        state
-            .get_model()
-            .get_contract_templates_storage()
-            .get_contract_template(&contract_template_id)
-            .ok_or(SetContractTemplateRetiredError::ContractTemplateNotFound)?;
+        check_contract_template_retirement(
+            state.get_model(),
+            &contract_template_id,
+            &reason,
+            &replacement,
+        )?;
 
         let env = state.get_env();
         let time = env.get_time().get_current_unix_epoch_time_millis();
 
-        let retired_value = reason.as_ref().map(|r| Timestamped::new(time, r.clone()));
+        // the retirement time in the past means the immediate retirement
+        let retire_at = retire_at.filter(|retire_at| *retire_at > time);
+        let retired_value = reason
+            .as_ref()
+            .map(|r| Timestamped::new(retire_at.unwrap_or(time), r.clone()));
 
         let model = state.get_model_mut();
-        model
-            .get_contract_templates_storage_mut()
-            .set_retired(&contract_template_id, retired_value);
+        model.get_contract_templates_storage_mut().set_retired(
+            &contract_template_id,
+            retired_value,
+            replacement,
+        );
 
         model.get_hub_events_storage_mut().add_hub_event(
             time,
-            env.get_ic().get_caller(),
+            caller,
             HubEventType::ContractTemplateRetired {
                 contract_template_id,
                 retired: reason.is_some(),
+                retire_at,
+                replacement,
             },
         );
 
         log_info!(
             env,
-            "Contract template '{contract_template_id}' retired flag set to '{}' by caller '{}'",
+            "Contract template '{contract_template_id}' retired flag set to '{}' (retire at: {retire_at:?}, replacement: {replacement:?}) by caller '{}'",
             reason.is_some(),
-            env.get_ic().get_caller().to_text()
+            caller.to_text()
         );
 
         Ok(())
     })
 }
+
+/// The replacement is another contract template, which is neither blocked nor retired,
+/// the scheduled retirement disqualifies it as well.
+fn check_replacement(
+    model: &DataModel,
+    contract_template_id: &ContractTemplateId,
+    replacement: &ContractTemplateId,
+) -> Result<(), String> {
+    if replacement == contract_template_id {
+        return Err("contract template can not replace itself".to_owned());
+    }
+
+    let replacement_model = model
+        .get_contract_templates_storage()
+        .get_contract_template(replacement)
+        .ok_or_else(|| format!("contract template '{replacement}' not found"))?;
+
+    if replacement_model.blocked.is_some() {
+        return Err(format!("contract template '{replacement}' is blocked"));
+    }
+    if replacement_model.retired.is_some() {
+        return Err(format!(
+            "contract template '{replacement}' is retired or has a scheduled retirement"
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::{
+    handlers::{
+        publishers::is_caller_contract_template_publisher,
+        templates::check_contract_template_visibility,
+    },
+    is_caller_has_access_right, log_info, mutate_state,
+};
+use common_contract_api::ContractTemplateId;
+use hub_canister_api::{
+    set_contract_template_visibility::*,
+    types::{ContractTemplateVisibility, HubEventType, Permission},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn set_contract_template_visibility(
+    Args {
+        contract_template_id,
+        visibility,
+    }: Args,
+) -> Response {
+    set_contract_template_visibility_int(contract_template_id, visibility).into()
+}
+
+pub(crate) fn set_contract_template_visibility_int(
+    contract_template_id: ContractTemplateId,
+    visibility: ContractTemplateVisibility,
+) -> Result<(), SetContractTemplateVisibilityError> {
+    if !is_caller_has_access_right(&Permission::SetContractTemplateMetadata)
+        && !is_caller_contract_template_publisher(&contract_template_id)
+    {
+        return Err(SetContractTemplateVisibilityError::PermissionDenied);
+    }
+
+    check_contract_template_visibility(&visibility)
+        .map_err(|reason| SetContractTemplateVisibilityError::InvalidVisibility { reason })?;
+
+    mutate_state(|state| {
+        state
+            .get_model()
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            .ok_or(SetContractTemplateVisibilityError::ContractTemplateNotFound)?;
+
+        let env = state.get_env();
+        let time = env.get_time().get_current_unix_epoch_time_millis();
+
+        let model = state.get_model_mut();
+        model
+            .get_contract_templates_storage_mut()
+            .set_visibility(&contract_template_id, visibility.clone());
+
+        log_info!(
+            env,
+            "Contract template '{contract_template_id}' visibility set to {visibility:?} by caller '{}'",
+            env.get_ic().get_caller().to_text()
+        );
+
+        model.get_hub_events_storage_mut().add_hub_event(
+            time,
+            env.get_ic().get_caller(),
+            HubEventType::ContractTemplateVisibilitySet {
+                contract_template_id,
+                visibility,
+            },
+        );
+
+        Ok(())
+    })
+}
@@ -0,0 +1,129 @@
+use crate::{
+    get_env, is_caller_has_access_right, log_info, mutate_state, read_state,
+    updates::block_contracts::block_contracts_int,
+};
+use candid::Principal;
+use common_canister_types::TimestampMillis;
+use hub_canister_api::{
+    set_deployer_ban::*,
+    types::{DeployerBan, DeployerBanSettings, HubEventType, Permission},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn set_deployer_ban(
+    Args {
+        deployer,
+        settings,
+        block_contracts,
+    }: Args,
+) -> Response {
+    set_deployer_ban_int(deployer, settings, block_contracts.unwrap_or_default()).into()
+}
+
+pub(crate) fn set_deployer_ban_int(
+    deployer: Principal,
+    settings: Option<DeployerBanSettings>,
+    block_contracts: bool,
+) -> Result<(), SetDeployerBanError> {
+    if !is_caller_has_access_right(&Permission::BlockDeployer) {
+        return Err(SetDeployerBanError::PermissionDenied);
+    }
+
+    let block_contracts = block_contracts && settings.is_some();
+    if block_contracts && !is_caller_has_access_right(&Permission::BlockContract) {
+        return Err(SetDeployerBanError::PermissionDenied);
+    }
+
+    let env = get_env();
+    let time = env.get_time().get_current_unix_epoch_time_millis();
+    let caller = env.get_ic().get_caller();
+
+    if let Some(settings) = &settings {
+        check_deployer_ban_settings(&deployer, settings, time)
+            .map_err(|reason| SetDeployerBanError::InvalidDeployerBan { reason })?;
+    }
+
+    mutate_state(|state| {
+        let deployer_bans_storage = state.get_model_mut().get_deployer_bans_storage_mut();
+        match settings.clone() {
+            Some(settings) => {
+                deployer_bans_storage.set_deployer_ban(
+                    deployer,
+                    DeployerBan {
+                        banned: time,
+                        banned_by: caller,
+                        settings,
+                    },
+                );
+            }
+            None => {
+                if !deployer_bans_storage.remove_deployer_ban(&deployer) {
+                    return Err(SetDeployerBanError::DeployerBanNotFound);
+                }
+            }
+        }
+
+        log_info!(
+            env,
+            "Deployer ban of '{}' set: {settings:?}, by caller '{}'",
+            deployer.to_text(),
+            caller.to_text()
+        );
+
+        state
+            .get_model_mut()
+            .get_hub_events_storage_mut()
+            .add_hub_event(
+                time,
+                caller,
+                HubEventType::DeployerBanSet {
+                    deployer,
+                    settings: settings.clone(),
+                },
+            );
+
+        Ok(())
+    })?;
+
+    if let Some(settings) = settings.filter(|_| block_contracts) {
+        let mut deployment_ids = vec![];
+        read_state(|state| {
+            state
+                .get_model()
+                .get_deployments_storage()
+                .iterate_by_deployer(deployer, false, |deployment_id| {
+                    deployment_ids.push(deployment_id);
+                    true
+                });
+        });
+
+        block_contracts_int(deployment_ids, vec![], settings.reason)
+            .map_err(|_| SetDeployerBanError::PermissionDenied)?;
+    }
+
+    Ok(())
+}
+
+fn check_deployer_ban_settings(
+    deployer: &Principal,
+    settings: &DeployerBanSettings,
+    time: TimestampMillis,
+) -> Result<(), String> {
+    if *deployer == Principal::anonymous() {
+        return Err("anonymous principal can not be banned".to_string());
+    }
+
+    if settings.reason.trim().is_empty() {
+        return Err("reason is empty".to_string());
+    }
+
+    if settings
+        .expiration
+        .is_some_and(|expiration| expiration <= time)
+    {
+        return Err("expiration is in the past".to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::{
+    handlers::publishers::calculate_publisher_usage, is_caller_has_access_right, log_info,
+    model::publishers::PublisherModel, mutate_state,
+};
+use candid::Principal;
+use hub_canister_api::{
+    set_publisher::*,
+    types::{HubEventType, Permission, PublisherSettings},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn set_publisher(
+    Args {
+        publisher,
+        settings,
+    }: Args,
+) -> Response {
+    set_publisher_int(publisher, settings).into()
+}
+
+pub(crate) fn set_publisher_int(
+    publisher: Principal,
+    settings: Option<PublisherSettings>,
+) -> Result<(), SetPublisherError> {
+    if !is_caller_has_access_right(&Permission::SetPublisher) {
+        return Err(SetPublisherError::PermissionDenied);
+    }
+
+    mutate_state(|state| {
+        let env = state.get_env();
+        let time = env.get_time().get_current_unix_epoch_time_millis();
+
+        let exists = state
+            .get_model()
+            .get_publishers_storage()
+            .get_publisher(&publisher)
+            .is_some();
+
+        match settings.clone() {
+            Some(settings) if exists => {
+                state
+                    .get_model_mut()
+                    .get_publishers_storage_mut()
+                    .set_publisher_settings(&publisher, settings);
+            }
+            Some(settings) => {
+                // templates registered before, count towards the quotas
+                let (contract_templates_count, total_wasm_length) =
+                    calculate_publisher_usage(state.get_model(), &publisher);
+                state
+                    .get_model_mut()
+                    .get_publishers_storage_mut()
+                    .add_publisher(
+                        publisher,
+                        PublisherModel {
+                            registered: time,
+                            settings,
+                            contract_templates_count,
+                            total_wasm_length,
+                        },
+                    );
+            }
+            None => {
+                if !state
+                    .get_model_mut()
+                    .get_publishers_storage_mut()
+                    .remove_publisher(&publisher)
+                {
+                    return Err(SetPublisherError::PublisherNotFound);
+                }
+            }
+        }
+
+        log_info!(
+            env,
+            "Publisher '{}' set: {settings:?}, by caller '{}'",
+            publisher.to_text(),
+            env.get_ic().get_caller().to_text()
+        );
+
+        state
+            .get_model_mut()
+            .get_hub_events_storage_mut()
+            .add_hub_event(
+                time,
+                env.get_ic().get_caller(),
+                HubEventType::PublisherSet {
+                    publisher,
+                    settings,
+                },
+            );
+
+        Ok(())
+    })
+}
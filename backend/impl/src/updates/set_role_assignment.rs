@@ -0,0 +1,119 @@
+use crate::{
+    get_env, handlers::governance::is_operation_governed, is_caller_has_access_right, log_info,
+    mutate_state, read_state,
+};
+use candid::Principal;
+use hub_canister_api::{
+    set_role_assignment::*,
+    types::{GovernedOperationType, HubEventType, Permission, RoleAssignmentSettings},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn set_role_assignment(
+    Args {
+        caller,
+        role,
+        settings,
+    }: Args,
+) -> Response {
+    set_role_assignment_int(caller, role, settings).into()
+}
+
+pub(crate) fn set_role_assignment_int(
+    assignee: Principal,
+    role: String,
+    settings: Option<RoleAssignmentSettings>,
+) -> Result<(), SetRoleAssignmentError> {
+    if !is_caller_has_access_right(&Permission::SetAccessRights) {
+        return Err(SetRoleAssignmentError::PermissionDenied);
+    }
+
+    if is_operation_governed(GovernedOperationType::SetAccessRights) {
+        return Err(SetRoleAssignmentError::ProposalRequired);
+    }
+
+    apply_role_assignment(get_env().get_ic().get_caller(), assignee, role, settings)
+}
+
+/// Assigns the role or removes the assignment, unless the caller loses the control
+/// over the access rights.
+pub(crate) fn apply_role_assignment(
+    caller: Principal,
+    assignee: Principal,
+    role: String,
+    settings: Option<RoleAssignmentSettings>,
+) -> Result<(), SetRoleAssignmentError> {
+    let env = get_env();
+    let time = env.get_time().get_current_unix_epoch_time_millis();
+
+    let mut access_control = read_state(|state| {
+        state
+            .get_model()
+            .get_access_rights_storage()
+            .get_access_control()
+            .clone()
+    });
+
+    if !access_control.roles.contains_key(&role) {
+        return Err(SetRoleAssignmentError::RoleNotFound);
+    }
+
+    match &settings {
+        Some(settings) => {
+            if assignee == Principal::anonymous() {
+                return Err(SetRoleAssignmentError::InvalidRoleAssignment {
+                    reason: "anonymous principal can not be assigned".to_owned(),
+                });
+            }
+            if settings
+                .expiration
+                .is_some_and(|expiration| expiration <= time)
+            {
+                return Err(SetRoleAssignmentError::InvalidRoleAssignment {
+                    reason: "expiration is in the past".to_owned(),
+                });
+            }
+        }
+        None => {
+            if !access_control
+                .role_assignments
+                .get(&assignee)
+                .is_some_and(|assignments| assignments.contains_key(&role))
+            {
+                return Err(SetRoleAssignmentError::RoleAssignmentNotFound);
+            }
+        }
+    }
+
+    access_control.set_role_assignment(assignee, role.clone(), settings.clone());
+
+    if !access_control.is_access_right(&caller, &Permission::SetAccessRights, time) {
+        return Err(SetRoleAssignmentError::LoseControlDangerous);
+    }
+
+    log_info!(
+        env,
+        "Role '{role}' assignment of '{}' set to {settings:?} by caller '{}'",
+        assignee.to_text(),
+        caller.to_text()
+    );
+
+    mutate_state(|state| {
+        let model = state.get_model_mut();
+        model
+            .get_access_rights_storage_mut()
+            .set_access_control(access_control);
+
+        model.get_hub_events_storage_mut().add_hub_event(
+            time,
+            caller,
+            HubEventType::RoleAssignmentSet {
+                caller: assignee,
+                role,
+                settings,
+            },
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,90 @@
+use crate::{
+    get_env, handlers::governance::is_operation_governed, is_caller_has_access_right, log_info,
+    mutate_state, read_state,
+};
+use candid::Principal;
+use hub_canister_api::{
+    set_roles::*,
+    types::{GovernedOperationType, HubEventType, Permission, Role},
+};
+use ic_cdk_macros::update;
+
+const MAX_ROLES: usize = 100;
+const MAX_ROLE_NAME_LENGTH: usize = 32;
+
+#[update]
+fn set_roles(Args { roles }: Args) -> Response {
+    set_roles_int(roles).into()
+}
+
+pub(crate) fn set_roles_int(roles: Vec<Role>) -> Result<(), SetRolesError> {
+    if !is_caller_has_access_right(&Permission::SetAccessRights) {
+        return Err(SetRolesError::PermissionDenied);
+    }
+
+    if is_operation_governed(GovernedOperationType::SetAccessRights) {
+        return Err(SetRolesError::ProposalRequired);
+    }
+
+    apply_roles(get_env().get_ic().get_caller(), roles)
+}
+
+/// Replaces the roles, unless the caller loses the control over the access rights.
+pub(crate) fn apply_roles(caller: Principal, roles: Vec<Role>) -> Result<(), SetRolesError> {
+    check_roles(&roles).map_err(|reason| SetRolesError::InvalidRole { reason })?;
+
+    let env = get_env();
+    let time = env.get_time().get_current_unix_epoch_time_millis();
+
+    let mut access_control = read_state(|state| {
+        state
+            .get_model()
+            .get_access_rights_storage()
+            .get_access_control()
+            .clone()
+    });
+    access_control.set_roles(roles.clone());
+
+    if !access_control.is_access_right(&caller, &Permission::SetAccessRights, time) {
+        return Err(SetRolesError::LoseControlDangerous);
+    }
+
+    log_info!(env, "New roles received for apply: {:?}", roles);
+
+    mutate_state(|state| {
+        let model = state.get_model_mut();
+        model
+            .get_access_rights_storage_mut()
+            .set_access_control(access_control);
+
+        model.get_hub_events_storage_mut().add_hub_event(
+            time,
+            caller,
+            HubEventType::RolesSet { roles },
+        );
+        Ok(())
+    })
+}
+
+/// Each role has a unique name of lowercase latin letters, digits, dashes and underscores.
+pub(crate) fn check_roles(roles: &[Role]) -> Result<(), String> {
+    if roles.len() > MAX_ROLES {
+        return Err(format!("roles count exceeds {MAX_ROLES}"));
+    }
+
+    for (index, role) in roles.iter().enumerate() {
+        let valid_name = !role.name.is_empty()
+            && role.name.len() <= MAX_ROLE_NAME_LENGTH
+            && role
+                .name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid_name {
+            return Err(format!("invalid role name '{}'", role.name));
+        }
+        if roles[..index].iter().any(|other| other.name == role.name) {
+            return Err(format!("duplicate role name '{}'", role.name));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::{get_env, handlers::sponsors::check_sponsorship_settings, log_info, mutate_state};
+use hub_canister_api::{
+    set_sponsorship::*,
+    types::{HubEventType, SponsorshipSettings},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn set_sponsorship(Args { settings }: Args) -> Response {
+    set_sponsorship_int(settings).into()
+}
+
+pub(crate) fn set_sponsorship_int(
+    settings: Option<SponsorshipSettings>,
+) -> Result<(), SetSponsorshipError> {
+    let env = get_env();
+    if env.get_ic().is_caller_anonymous() {
+        return Err(SetSponsorshipError::CallerNotAuthorized);
+    }
+    let sponsor = env.get_ic().get_caller();
+
+    if let Some(settings) = &settings {
+        check_sponsorship_settings(settings)
+            .map_err(|reason| SetSponsorshipError::InvalidSponsorship { reason })?;
+    }
+
+    mutate_state(|state| {
+        let time = env.get_time().get_current_unix_epoch_time_millis();
+
+        let sponsors_storage = state.get_model_mut().get_sponsors_storage_mut();
+        match settings.clone() {
+            Some(settings) => {
+                sponsors_storage.set_sponsorship(sponsor, time, settings);
+            }
+            None => {
+                let sponsorship = sponsors_storage
+                    .get_sponsorship(&sponsor)
+                    .ok_or(SetSponsorshipError::SponsorshipNotFound)?;
+
+                // the in-flight sponsored deployments still have to charge or release the reservations
+                if sponsorship.reserved_amount > 0 {
+                    return Err(SetSponsorshipError::SponsoredDeploymentsInProgress {
+                        reserved_amount: sponsorship.reserved_amount,
+                    });
+                }
+                sponsors_storage.remove_sponsorship(&sponsor);
+            }
+        }
+
+        log_info!(
+            env,
+            "Sponsorship of '{}' set: {settings:?}",
+            sponsor.to_text()
+        );
+
+        state
+            .get_model_mut()
+            .get_hub_events_storage_mut()
+            .add_hub_event(
+                time,
+                sponsor,
+                HubEventType::SponsorshipSet { sponsor, settings },
+            );
+
+        Ok(())
+    })
+}
@@ -1,7 +1,8 @@
 use crate::{
     get_env, handlers::deployments::states::get_config, is_caller_has_access_right, log_info,
-    mutate_state,
+    mutate_state, read_state,
 };
+use candid::Principal;
 use hub_canister_api::{
     set_upload_wasm_grant::*,
     types::{Permission, UploadWasmGrant},
@@ -16,8 +17,9 @@ fn set_upload_wasm_grant(Args { grant }: Args) -> Response {
 pub(crate) fn set_upload_wasm_grant_int(
     grant: Option<UploadWasmGrant>,
 ) -> Result<(), SetUploadWasmGrantError> {
+    let caller = get_env().get_ic().get_caller();
     if !is_caller_has_access_right(&Permission::AddContractTemplate) {
-        return Err(SetUploadWasmGrantError::PermissionDenied);
+        check_publisher_grant(&caller, grant.as_ref())?;
     }
 
     if let Some(UploadWasmGrant {
@@ -41,8 +43,36 @@ pub(crate) fn set_upload_wasm_grant_int(
         state
             .get_model_mut()
             .get_wasm_storage_mut()
-            .set_upload_wasm_grant(grant);
+            .set_upload_wasm_grant(caller, grant);
     });
 
     Ok(())
 }
+
+/// Publisher can not replace the grant of another caller and is limited by own wasm quota.
+fn check_publisher_grant(
+    caller: &Principal,
+    grant: Option<&UploadWasmGrant>,
+) -> Result<(), SetUploadWasmGrantError> {
+    read_state(|state| {
+        let model = state.get_model();
+        let publisher = model
+            .get_publishers_storage()
+            .get_publisher(caller)
+            .ok_or(SetUploadWasmGrantError::PermissionDenied)?;
+
+        if model
+            .get_wasm_storage()
+            .get_upload_wasm_grantor()
+            .is_some_and(|granted_by| granted_by != caller)
+        {
+            return Err(SetUploadWasmGrantError::UploadWasmGrantIsBusy);
+        }
+
+        let available_length = publisher.get_available_wasm_length();
+        if grant.is_some_and(|grant| grant.wasm_length > available_length) {
+            return Err(SetUploadWasmGrantError::WasmQuotaExceeded { available_length });
+        }
+        Ok(())
+    })
+}
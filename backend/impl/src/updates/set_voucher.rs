@@ -0,0 +1,64 @@
+use crate::{
+    handlers::vouchers::{check_voucher_code, check_voucher_definition},
+    is_caller_has_access_right, log_info, mutate_state,
+};
+use hub_canister_api::{
+    set_voucher::*,
+    types::{HubEventType, Permission, VoucherCode, VoucherDefinition},
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn set_voucher(Args { code, definition }: Args) -> Response {
+    set_voucher_int(code, definition).into()
+}
+
+pub(crate) fn set_voucher_int(
+    code: VoucherCode,
+    definition: Option<VoucherDefinition>,
+) -> Result<(), SetVoucherError> {
+    if !is_caller_has_access_right(&Permission::SetVoucher) {
+        return Err(SetVoucherError::PermissionDenied);
+    }
+
+    check_voucher_code(&code).map_err(|reason| SetVoucherError::InvalidVoucherCode { reason })?;
+
+    if let Some(definition) = &definition {
+        check_voucher_definition(definition)
+            .map_err(|reason| SetVoucherError::InvalidVoucherDefinition { reason })?;
+    }
+
+    mutate_state(|state| {
+        let env = state.get_env();
+        let time = env.get_time().get_current_unix_epoch_time_millis();
+
+        let vouchers_storage = state.get_model_mut().get_vouchers_storage_mut();
+        match definition.clone() {
+            Some(definition) => {
+                vouchers_storage.set_voucher(code.clone(), time, definition);
+            }
+            None => {
+                if !vouchers_storage.remove_voucher(&code) {
+                    return Err(SetVoucherError::VoucherNotFound);
+                }
+            }
+        }
+
+        log_info!(
+            env,
+            "Voucher '{code}' set: {definition:?}, by caller '{}'",
+            env.get_ic().get_caller().to_text()
+        );
+
+        state
+            .get_model_mut()
+            .get_hub_events_storage_mut()
+            .add_hub_event(
+                time,
+                env.get_ic().get_caller(),
+                HubEventType::VoucherSet { code, definition },
+            );
+
+        Ok(())
+    })
+}
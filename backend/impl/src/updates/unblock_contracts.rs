@@ -0,0 +1,95 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    is_caller_has_access_right, log_info, mutate_state, read_state,
+    updates::block_contracts::{deduplicate_contract_canister_ids, deduplicate_deployment_ids},
+};
+use candid::Principal;
+use hub_canister_api::{
+    types::{DeploymentId, HubEventType, Permission},
+    unblock_contracts::*,
+};
+use ic_cdk_macros::update;
+
+#[update]
+fn unblock_contracts(
+    Args {
+        deployment_ids,
+        contract_canister_ids,
+        reason,
+    }: Args,
+) -> Response {
+    unblock_contracts_int(deployment_ids, contract_canister_ids, reason).into()
+}
+
+pub(crate) fn unblock_contracts_int(
+    deployment_ids: Vec<DeploymentId>,
+    contract_canister_ids: Vec<Principal>,
+    reason: String,
+) -> Result<(), UnblockContractsError> {
+    if !is_caller_has_access_right(&Permission::BlockContract) {
+        return Err(UnblockContractsError::PermissionDenied);
+    }
+
+    let deduplicated_deployment_ids = deduplicate_deployment_ids(deployment_ids);
+    let deduplicated_contract_ids = deduplicate_contract_canister_ids(contract_canister_ids);
+
+    let unblocked_deployment_ids = read_state(|state| {
+        let model = state.get_model();
+        let deployments_storage = model.get_deployments_storage();
+        let blocked_contracts_storage = model.get_blocked_contracts_storage();
+
+        deduplicated_deployment_ids
+            .into_iter()
+            .chain(
+                deduplicated_contract_ids
+                    .into_iter()
+                    .filter_map(|contract_canister_id| {
+                        deployments_storage
+                            .get_deployment_id_by_contract_canister(&contract_canister_id)
+                    }),
+            )
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter(|deployment_id| {
+                blocked_contracts_storage
+                    .find_deployment_block(deployment_id)
+                    .is_some()
+            })
+            .collect::<Vec<_>>()
+    });
+
+    if unblocked_deployment_ids.is_empty() {
+        return Ok(());
+    }
+
+    mutate_state(|state| {
+        let env = state.get_env();
+        let time = env.get_time().get_current_unix_epoch_time_millis();
+        let caller = env.get_ic().get_caller();
+        let unblocked_deployments_count = unblocked_deployment_ids.len() as u64;
+
+        let model = state.get_model_mut();
+        model
+            .get_blocked_contracts_storage_mut()
+            .remove_contract_blocks(time, reason.clone(), unblocked_deployment_ids);
+
+        model.get_hub_events_storage_mut().add_hub_event(
+            time,
+            caller,
+            HubEventType::ContractUnblocked {
+                deployment_ids_count: unblocked_deployments_count,
+            },
+        );
+
+        log_info!(
+            env,
+            "Contracts unblocked by caller '{}' (count: {}, reason: {})",
+            caller.to_text(),
+            unblocked_deployments_count,
+            reason
+        );
+
+        Ok(())
+    })
+}
@@ -2,7 +2,9 @@ use std::str::FromStr;
 
 use crate::{get_env, read_state};
 use candid::Principal;
+use common_canister_types::Timestamped;
 use common_certification::verify_signed_contract_certificate;
+use common_contract_api::ContractTemplateId;
 use common_contract_c2c_client::get_contract_certificate;
 use hub_canister_api::validate_contract_certificate::*;
 use ic_cdk::management_canister::{canister_info, CanisterInfoArgs};
@@ -15,7 +17,10 @@ async fn validate_contract_certificate(args: Args) -> Response {
 }
 
 async fn validate_contract_certificate_int(
-    Args { contract_reference }: Args,
+    Args {
+        contract_reference,
+        fail_if_blocked,
+    }: Args,
 ) -> Result<ValidateContractCertificateResult, ValidateContractCertificateError> {
     let env = get_env();
     let hub_canister = env.get_ic().get_canister();
@@ -42,6 +47,17 @@ async fn validate_contract_certificate_int(
     verify_signed_contract_certificate(&certificate, env.get_ic().get_root_public_key_raw())
         .map_err(|reason| ValidateContractCertificateError::CertificateWrong { reason })?;
 
+    // the hub revokes the certificates of the blocked contracts
+
+    let (contract_blocked, contract_template_blocked) = get_contract_blocks(
+        &contract_canister,
+        &certificate.contract_certificate.contract_template_id,
+    );
+
+    if fail_if_blocked.unwrap_or(false) {
+        check_contract_blocks(&contract_blocked, &contract_template_blocked)?;
+    }
+
     let contract_info = canister_info(&CanisterInfoArgs {
         canister_id: contract_canister,
         num_requested_changes: None,
@@ -71,9 +87,63 @@ async fn validate_contract_certificate_int(
     Ok(ValidateContractCertificateResult {
         certificate,
         delay_to_expiration_millis,
+        contract_blocked,
+        contract_template_blocked,
     })
 }
 
+/// Returns the blocks of the contract and of its contract template.
+/// The template of the deployment is preferred over the one named by the certificate.
+pub(crate) fn get_contract_blocks(
+    contract_canister: &Principal,
+    contract_template_id: &ContractTemplateId,
+) -> (Option<Timestamped<String>>, Option<Timestamped<String>>) {
+    read_state(|state| {
+        let model = state.get_model();
+        let deployments_storage = model.get_deployments_storage();
+
+        let deployment_id =
+            deployments_storage.get_deployment_id_by_contract_canister(contract_canister);
+
+        let contract_blocked = deployment_id.and_then(|deployment_id| {
+            model
+                .get_blocked_contracts_storage()
+                .find_deployment_block(&deployment_id)
+        });
+
+        let contract_template_id = deployment_id
+            .and_then(|deployment_id| deployments_storage.get_deployment(&deployment_id))
+            .map(|deployment| deployment.contract_template_id)
+            .unwrap_or(*contract_template_id);
+
+        let contract_template_blocked = model
+            .get_contract_templates_storage()
+            .get_contract_template(&contract_template_id)
+            .and_then(|contract_template| contract_template.blocked.clone());
+
+        (contract_blocked, contract_template_blocked)
+    })
+}
+
+pub(crate) fn check_contract_blocks(
+    contract_blocked: &Option<Timestamped<String>>,
+    contract_template_blocked: &Option<Timestamped<String>>,
+) -> Result<(), ValidateContractCertificateError> {
+    if let Some(blocked) = contract_blocked {
+        return Err(ValidateContractCertificateError::ContractBlocked {
+            blocked: blocked.clone(),
+        });
+    }
+
+    if let Some(blocked) = contract_template_blocked {
+        return Err(ValidateContractCertificateError::ContractTemplateBlocked {
+            blocked: blocked.clone(),
+        });
+    }
+
+    Ok(())
+}
+
 fn obtain_contract_canister(
     contract_reference: ContractReference,
 ) -> Result<Principal, ValidateContractCertificateError> {